@@ -0,0 +1,234 @@
+//! Generates the colormaps from the palette tables in `palettes/`.
+//!
+//! Each `.txt` file holds one `r g b` row of fractions per color, the format the Scientific
+//! Colour Maps are distributed in. The file becomes a table constant named after it, a
+//! `Colormap` variant, an entry in `COLORMAP_CHOICES` and arms in the lookups. Tables keep the
+//! file's colors as they are and are resampled when rendering.
+//!
+//! Comments of the form `# key: value` describe the colormap:
+//!
+//! - `category`, required: `sequential`, `multi-sequential`, `diverging` or `cyclic`.
+//! - `name`, the name as its author writes it. Defaults to the file name.
+//! - `perceptually uniform`, `yes` or `no`. Defaults to `yes`.
+//! - `colorblind safe`, `yes` when the authors designed the colormap to stay readable with color
+//!   vision deficiencies. Defaults to `no`.
+//! - `notes`, shown instead of the notes for the category.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+const PALETTE_DIR: &str = "palettes";
+
+struct Colormap {
+    /// File name without the extension, in lowercase.
+    stem: String,
+    name: String,
+    category: &'static str,
+    perceptually_uniform: bool,
+    colorblind_safe: bool,
+    notes: Option<String>,
+    colors: Vec<[u8; 3]>,
+}
+
+impl Colormap {
+    fn variant(&self) -> String {
+        let mut chars = self.stem.chars();
+        let first = chars.next().unwrap().to_ascii_uppercase();
+        std::iter::once(first).chain(chars).collect()
+    }
+
+    fn constant(&self) -> String {
+        self.stem.to_uppercase()
+    }
+}
+
+fn parse_category(value: &str) -> Option<&'static str> {
+    match value {
+        "sequential" => Some("Sequential"),
+        "multi-sequential" => Some("MultiSequential"),
+        "diverging" => Some("Diverging"),
+        "cyclic" => Some("Cyclic"),
+        _ => None,
+    }
+}
+
+fn read_colormap(path: &Path) -> Colormap {
+    let stem = path.file_stem().unwrap().to_str().unwrap().to_string();
+    assert!(
+        stem.chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && stem.starts_with(|c: char| c.is_ascii_lowercase()),
+        "{}: file names must be lowercase letters and digits",
+        path.display()
+    );
+    let text = fs::read_to_string(path).unwrap();
+    let fail = |number: usize, message: &str| -> ! {
+        panic!("{}:{}: {}", path.display(), number + 1, message)
+    };
+
+    let mut colormap = Colormap {
+        name: stem.clone(),
+        stem,
+        category: "",
+        perceptually_uniform: true,
+        colorblind_safe: false,
+        notes: None,
+        colors: Vec::new(),
+    };
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix('#') {
+            let Some((key, value)) = comment.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "name" => colormap.name = value.to_string(),
+                "category" => {
+                    colormap.category =
+                        parse_category(value).unwrap_or_else(|| fail(number, "unknown category"))
+                }
+                "perceptually uniform" => colormap.perceptually_uniform = value == "yes",
+                "colorblind safe" => colormap.colorblind_safe = value == "yes",
+                "notes" => colormap.notes = Some(value.to_string()),
+                _ => {}
+            }
+            continue;
+        }
+
+        let channels: Vec<u8> = line
+            .split_whitespace()
+            .map(|v| {
+                let value: f64 = v.parse().unwrap_or_else(|_| fail(number, "not a number"));
+                (value.clamp(0.0, 1.0) * 255.0).round() as u8
+            })
+            .collect();
+        let [r, g, b] = channels[..] else {
+            fail(number, "expected three channels");
+        };
+        colormap.colors.push([r, g, b]);
+    }
+
+    if colormap.category.is_empty() {
+        panic!("{}: missing a `# category:` line", path.display());
+    }
+    if colormap.colors.is_empty() {
+        panic!("{}: no colors", path.display());
+    }
+
+    colormap
+}
+
+/// Writes a method matching every colormap to an expression.
+fn write_lookup(
+    out: &mut String,
+    signature: &str,
+    colormaps: &[Colormap],
+    arm: impl Fn(&Colormap) -> String,
+) {
+    writeln!(out, "    {} {{", signature).unwrap();
+    writeln!(out, "        match self {{").unwrap();
+    for colormap in colormaps {
+        writeln!(
+            out,
+            "            Colormap::{} => {},",
+            colormap.variant(),
+            arm(colormap)
+        )
+        .unwrap();
+    }
+    writeln!(out, "        }}\n    }}\n").unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", PALETTE_DIR);
+
+    let mut paths: Vec<_> = fs::read_dir(PALETTE_DIR)
+        .expect("palette directory exists")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+        .collect();
+    paths.sort();
+    let colormaps: Vec<Colormap> = paths.iter().map(|path| read_colormap(path)).collect();
+
+    let mut out = String::new();
+
+    for colormap in &colormaps {
+        writeln!(out, "pub const {}: &[u8] = &[", colormap.constant()).unwrap();
+        for [r, g, b] in &colormap.colors {
+            writeln!(out, "    {}, {}, {},", r, g, b).unwrap();
+        }
+        writeln!(out, "];\n").unwrap();
+    }
+
+    writeln!(out, "#[derive(Copy, Clone, PartialEq, Eq, Debug)]").unwrap();
+    writeln!(out, "pub enum Colormap {{").unwrap();
+    for colormap in &colormaps {
+        writeln!(out, "    {},", colormap.variant()).unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "pub const COLORMAP_CHOICES: &[Colormap] = &[").unwrap();
+    for colormap in &colormaps {
+        writeln!(out, "    Colormap::{},", colormap.variant()).unwrap();
+    }
+    writeln!(out, "];\n").unwrap();
+
+    writeln!(out, "impl Colormap {{").unwrap();
+    writeln!(out, "    /// The colormap's name as its author writes it.").unwrap();
+    write_lookup(
+        &mut out,
+        "pub fn name(self) -> &'static str",
+        &colormaps,
+        |c| format!("{:?}", c.name),
+    );
+    write_lookup(
+        &mut out,
+        "pub fn category(self) -> ColormapCategory",
+        &colormaps,
+        |c| format!("ColormapCategory::{}", c.category),
+    );
+    writeln!(
+        out,
+        "    /// Whether equal steps through the colormap look like equal steps in color."
+    )
+    .unwrap();
+    write_lookup(
+        &mut out,
+        "pub fn perceptually_uniform(self) -> bool",
+        &colormaps,
+        |c| c.perceptually_uniform.to_string(),
+    );
+    writeln!(
+        out,
+        "    /// Whether the colormap was designed to stay readable with color vision deficiencies."
+    )
+    .unwrap();
+    write_lookup(
+        &mut out,
+        "pub fn colorblind_safe(self) -> bool",
+        &colormaps,
+        |c| c.colorblind_safe.to_string(),
+    );
+    writeln!(out, "    /// Notes given in the colormap's data file.").unwrap();
+    write_lookup(
+        &mut out,
+        "pub(crate) fn file_notes(self) -> Option<&'static str>",
+        &colormaps,
+        |c| format!("{:?}", c.notes.as_deref()),
+    );
+    write_lookup(
+        &mut out,
+        "pub fn to_colormap(self) -> &'static [u8]",
+        &colormaps,
+        |c| c.constant(),
+    );
+    writeln!(out, "}}").unwrap();
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("colormaps.rs");
+    fs::write(out_path, out).unwrap();
+}
@@ -0,0 +1,171 @@
+use crate::{encode_base64, options::Options, render, resolve, viewport::Viewport, Error};
+use image::RgbaImage;
+use num::complex::Complex64;
+use png::{BitDepth, ColorType, Encoder};
+
+pub const FRAMES_PER_KEYFRAME: u32 = 30;
+pub const FRAME_DELAY_MS: u16 = 40;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Keyframe {
+    pub viewport: Viewport,
+    pub palette_offset: f64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Animation {
+    pub keyframes: Vec<Keyframe>,
+    /// Frames rendered from each keyframe to the next.
+    pub frames_per_keyframe: u32,
+    /// Palette entries the colors cycle by each frame, on top of the keyframes' offsets.
+    pub palette_speed: f64,
+    pub frame_delay_ms: u16,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            frames_per_keyframe: FRAMES_PER_KEYFRAME,
+            palette_speed: 0.0,
+            frame_delay_ms: FRAME_DELAY_MS,
+        }
+    }
+}
+
+/// Interpolates between two keyframes with an exponential zoom.
+///
+/// The center pans so that it moves at a constant speed on screen rather than in the plane,
+/// which keeps the target point in view while zooming.
+pub fn interpolate(a: &Keyframe, b: &Keyframe, t: f64) -> Keyframe {
+    let ratio = b.viewport.scale / a.viewport.scale;
+    let scale = a.viewport.scale * ratio.powf(t);
+
+    let pan = if (ratio - 1.0).abs() < 1e-9 {
+        t
+    } else {
+        (1.0 - ratio.powf(t)) / (1.0 - ratio)
+    };
+    let center: Complex64 = a.viewport.center + (b.viewport.center - a.viewport.center) * pan;
+
+    Keyframe {
+        viewport: Viewport::new(
+            center,
+            scale,
+            crate::lerp(a.viewport.rotation, b.viewport.rotation, t),
+            a.viewport.dimensions,
+        ),
+        palette_offset: crate::lerp(a.palette_offset, b.palette_offset, t),
+    }
+}
+
+impl Animation {
+    /// Every frame of the animation, ending exactly on the last keyframe.
+    ///
+    /// All frames share the first keyframe's dimensions.
+    pub fn frames(&self) -> Vec<Keyframe> {
+        let steps = self.frames_per_keyframe.max(1);
+
+        let mut frames: Vec<Keyframe> = self
+            .keyframes
+            .windows(2)
+            .flat_map(|pair| {
+                (0..steps).map(move |i| interpolate(&pair[0], &pair[1], i as f64 / steps as f64))
+            })
+            .collect();
+        frames.extend(self.keyframes.last());
+
+        let dimensions = self.keyframes.first().map(|k| k.viewport.dimensions);
+        for (i, frame) in frames.iter_mut().enumerate() {
+            frame.palette_offset += self.palette_speed * i as f64;
+            frame.viewport.dimensions = dimensions.unwrap_or(frame.viewport.dimensions);
+        }
+
+        frames
+    }
+}
+
+/// Renders every frame through the same pipeline as a single image, using resolved `options`.
+pub async fn render_frames(
+    options: &Options,
+    palette: &[u8],
+    animation: &Animation,
+) -> Vec<RgbaImage> {
+    let mut images = Vec::new();
+
+    for (i, frame) in animation.frames().into_iter().enumerate() {
+        tracing::info!("Rendering frame {}", i);
+        images.push(render(options, frame.viewport, palette, frame.palette_offset).await);
+    }
+
+    images
+}
+
+/// Encodes frames as a looping animated PNG.
+pub fn encode_apng(frames: &[RgbaImage], frame_delay_ms: u16) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::new();
+
+    let Some(first) = frames.first() else {
+        return bytes;
+    };
+
+    let mut encoder = Encoder::new(&mut bytes, first.width(), first.height());
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(BitDepth::Eight);
+    encoder.set_animated(frames.len() as u32, 0).unwrap();
+    encoder.set_frame_delay(frame_delay_ms, 1000).unwrap();
+
+    let mut writer = encoder.write_header().unwrap();
+    for frame in frames {
+        writer.write_image_data(frame.as_raw()).unwrap();
+    }
+    writer.finish().unwrap();
+
+    tracing::info!("Wrote {} frames to animation", frames.len());
+
+    bytes
+}
+
+/// Renders an animation as a base64 encoded animated PNG.
+pub async fn encode_animation(options: Options, animation: Animation) -> Result<String, Error> {
+    let (resolved, _) = resolve(&options)?;
+    let palette = resolved
+        .palette()
+        .expect("resolved options always have a colormap");
+
+    let frames = render_frames(&resolved, &palette, &animation).await;
+
+    Ok(encode_base64(&encode_apng(
+        &frames,
+        animation.frame_delay_ms,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyframe(re: f64, scale: f64) -> Keyframe {
+        Keyframe {
+            viewport: Viewport::new(Complex64::new(re, 0.0), scale, 0.0, [16, 9]),
+            palette_offset: 0.0,
+        }
+    }
+
+    #[test]
+    fn frames_start_and_end_on_keyframes() {
+        let animation = Animation {
+            keyframes: vec![keyframe(-0.5, 1.0), keyframe(-0.75, 0.01)],
+            frames_per_keyframe: 10,
+            ..Default::default()
+        };
+
+        let frames = animation.frames();
+
+        assert_eq!(frames.len(), 11);
+        assert_eq!(frames[0], animation.keyframes[0]);
+        let last = frames[10].viewport;
+        assert!((last.center.re + 0.75).abs() < 1e-12 && (last.scale - 0.01).abs() < 1e-12);
+        assert!((frames[5].viewport.scale - 0.1).abs() < 1e-12);
+    }
+}
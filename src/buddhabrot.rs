@@ -0,0 +1,352 @@
+use crate::{
+    coloring::PaletteMapping, encode_base64, encode_png, options::Options, rand_range,
+    resolve_colormap, viewport::Viewport, Error,
+};
+use futures::{channel::mpsc, SinkExt, StreamExt};
+use image::{Rgba, RgbaImage};
+use leptos::task;
+use num::complex::Complex64;
+
+pub const DENSITY_SAMPLES: u32 = 200_000;
+pub const NEBULABROT_STEPS: [u32; 3] = [2000, 200, 20];
+
+/// Tasks the samples are split over. Each seeds its own generator from the render seed.
+const DENSITY_TASKS: u32 = 16;
+/// Exponent applied to normalized hit counts, brightening faint orbits.
+const DENSITY_GAMMA: f64 = 0.5;
+/// Region random c values are drawn from, [x min, x max, y min, y max].
+const SAMPLE_REGION: [f64; 4] = [-2.0, 1.0, -1.5, 1.5];
+
+/// Which orbits are accumulated.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DensityMode {
+    /// Orbits of points that escape.
+    Buddhabrot,
+    /// Orbits of points that stay bounded.
+    AntiBuddhabrot,
+}
+
+pub const DENSITY_MODES: &[DensityMode] = &[DensityMode::Buddhabrot, DensityMode::AntiBuddhabrot];
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DensityColor {
+    /// One density channel with the options' step limit, mapped through the colormap.
+    Colormap,
+    /// Red, green and blue densities with their own step limits, for Nebulabrot images.
+    Rgb,
+}
+
+pub const DENSITY_COLORS: &[DensityColor] = &[DensityColor::Colormap, DensityColor::Rgb];
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Buddhabrot {
+    pub mode: DensityMode,
+    pub color: DensityColor,
+    /// Random c values drawn.
+    pub samples: u32,
+    /// Step limits of the red, green and blue channels when coloring by channel.
+    pub channel_steps: [u32; 3],
+    /// Center of the view, or the whole set when `None`.
+    pub center: Option<[f64; 2]>,
+    /// Width of the view in the complex plane, used with `center`.
+    pub view_size: Option<f64>,
+}
+
+impl Default for Buddhabrot {
+    fn default() -> Self {
+        Self {
+            mode: DensityMode::Buddhabrot,
+            color: DensityColor::Colormap,
+            samples: DENSITY_SAMPLES,
+            channel_steps: NEBULABROT_STEPS,
+            center: None,
+            view_size: None,
+        }
+    }
+}
+
+impl Buddhabrot {
+    /// Step limits of every density channel.
+    pub fn limits(&self, options: &Options) -> Vec<u32> {
+        match self.color {
+            DensityColor::Colormap => vec![options.step_limits[1]],
+            DensityColor::Rgb => self.channel_steps.to_vec(),
+        }
+    }
+}
+
+/// Whether `c` is in the main cardioid or the period two bulb, so its orbit never escapes.
+fn in_main_bulbs(c: Complex64) -> bool {
+    let q = (c.re - 0.25).powi(2) + c.im.powi(2);
+    q * (q + c.re - 0.25) < c.im.powi(2) / 4.0 || (c.re + 1.0).powi(2) + c.im.powi(2) < 1.0 / 16.0
+}
+
+/// Iterates the orbit of `c` up to the highest limit and adds its points to every channel whose
+/// limit `mode` plots it for.
+fn plot_orbit(
+    c: Complex64,
+    viewport: &Viewport,
+    mode: DensityMode,
+    limits: &[u32],
+    orbit: &mut Vec<Complex64>,
+    channels: &mut [Vec<u32>],
+) {
+    let [width, height] = viewport.dimensions;
+    let max_steps = limits.iter().copied().max().unwrap_or(0);
+
+    orbit.clear();
+    let mut z = Complex64::new(0.0, 0.0);
+    let mut escaped_at = None;
+    for n in 0..max_steps {
+        z = z * z + c;
+        if z.norm_sqr() > 4.0 {
+            escaped_at = Some(n);
+            break;
+        }
+        orbit.push(z);
+    }
+
+    for (channel, &limit) in channels.iter_mut().zip(limits) {
+        let plotted = match (mode, escaped_at) {
+            (DensityMode::Buddhabrot, Some(n)) if n < limit => &orbit[..],
+            (DensityMode::AntiBuddhabrot, n) if n.is_none_or(|n| n >= limit) => {
+                &orbit[..orbit.len().min(limit as usize)]
+            }
+            _ => continue,
+        };
+
+        for &point in plotted {
+            let (x, y) = viewport.complex_to_pixel(point);
+            let (x, y) = (x.round(), y.round());
+            if x >= 0.0 && y >= 0.0 && x < width as f64 && y < height as f64 {
+                channel[y as usize * width as usize + x as usize] += 1;
+            }
+        }
+    }
+}
+
+/// Iterates `samples` random c values and counts how often their orbits land on each pixel, with
+/// one count per channel limit.
+pub fn accumulate(
+    rng: &mut fastrand::Rng,
+    viewport: &Viewport,
+    mode: DensityMode,
+    limits: &[u32],
+    samples: u32,
+) -> Vec<Vec<u32>> {
+    let [width, height] = viewport.dimensions;
+    let mut channels = vec![vec![0u32; (width * height) as usize]; limits.len()];
+    let max_steps = limits.iter().copied().max().unwrap_or(0);
+    let mut orbit = Vec::with_capacity(max_steps as usize);
+
+    for _ in 0..samples {
+        let c = Complex64::new(
+            rand_range(rng, SAMPLE_REGION[0], SAMPLE_REGION[1]),
+            rand_range(rng, SAMPLE_REGION[2], SAMPLE_REGION[3]),
+        );
+        if mode == DensityMode::Buddhabrot && in_main_bulbs(c) {
+            continue;
+        }
+
+        plot_orbit(c, viewport, mode, limits, &mut orbit, &mut channels);
+    }
+
+    channels
+}
+
+/// Accumulates the density channels over several tasks, each seeded from `seed`.
+pub async fn compute_density(
+    options: &Options,
+    viewport: Viewport,
+    buddhabrot: &Buddhabrot,
+    seed: u64,
+) -> Vec<Vec<u32>> {
+    let limits = buddhabrot.limits(options);
+    let [width, height] = viewport.dimensions;
+    let mut channels = vec![vec![0u32; (width * height) as usize]; limits.len()];
+
+    let (tx, rx) = mpsc::channel(DENSITY_TASKS as usize);
+    // Consecutive render seeds would share all but one task seed if they were offset from it.
+    let mut seeds = fastrand::Rng::with_seed(seed);
+
+    for i in 0..DENSITY_TASKS {
+        let samples =
+            buddhabrot.samples / DENSITY_TASKS + u32::from(i < buddhabrot.samples % DENSITY_TASKS);
+        let (mode, limits) = (buddhabrot.mode, limits.clone());
+        let mut task_tx = tx.clone();
+        let task_seed = seeds.u64(..);
+        task::spawn(async move {
+            let mut rng = fastrand::Rng::with_seed(task_seed);
+            let counts = accumulate(&mut rng, &viewport, mode, &limits, samples);
+            task_tx.send(counts).await.unwrap();
+        })
+    }
+
+    let mut results = rx.take(DENSITY_TASKS as usize);
+    while let Some(counts) = results.next().await {
+        for (channel, counts) in channels.iter_mut().zip(counts) {
+            for (total, count) in channel.iter_mut().zip(counts) {
+                *total += count;
+            }
+        }
+    }
+
+    channels
+}
+
+/// Colors density channels, either one channel through the palette or up to three as RGB.
+pub fn colorize_density(
+    channels: &[Vec<u32>],
+    viewport: &Viewport,
+    palette: &[u8],
+    mapping: &PaletteMapping,
+    color: DensityColor,
+) -> RgbaImage {
+    let normalized: Vec<Vec<f64>> = channels
+        .iter()
+        .map(|channel| {
+            let max = channel.iter().copied().max().unwrap_or(0).max(1) as f64;
+            channel
+                .iter()
+                .map(|&count| (count as f64 / max).powf(DENSITY_GAMMA))
+                .collect()
+        })
+        .collect();
+
+    let entries = palette.len() / 3;
+    let [width, height] = viewport.dimensions;
+
+    RgbaImage::from_fn(width, height, |x, y| {
+        let i = (y * width + x) as usize;
+        match color {
+            DensityColor::Colormap => {
+                mapping.color(palette, normalized[0][i] * (entries - 1) as f64, None)
+            }
+            DensityColor::Rgb => {
+                let channel = |c: usize| {
+                    normalized
+                        .get(c)
+                        .map_or(0, |channel| (channel[i] * 255.0).round() as u8)
+                };
+                Rgba::from([channel(0), channel(1), channel(2), 0xFF])
+            }
+        }
+    })
+}
+
+/// The Buddhabrot's own view, or the whole set when it has none.
+///
+/// The options' view is not used, since it usually holds the last zoomed in image, far smaller
+/// than the region samples are drawn from.
+pub fn density_viewport(options: &Options, buddhabrot: &Buddhabrot) -> Viewport {
+    let (center, scale) = match (buddhabrot.center, buddhabrot.view_size) {
+        (Some([re, im]), Some(size)) => (Complex64::new(re, im), size / 2.0),
+        _ => (Complex64::new(-0.5, 0.0), 1.5),
+    };
+
+    Viewport::new(
+        center,
+        scale,
+        options.rotation.to_radians(),
+        options.dimensions,
+    )
+}
+
+/// Renders a Buddhabrot as a base64 encoded PNG, returning it with the resolved options.
+pub async fn encode_buddhabrot(
+    options: Options,
+    buddhabrot: Buddhabrot,
+) -> Result<(String, Options), Error> {
+    let seed = options.rng_seed.unwrap_or_else(|| fastrand::u64(..));
+    let colormap = resolve_colormap(&options, seed)?;
+    let viewport = density_viewport(&options, &buddhabrot);
+    let resolved = Options {
+        image_center: Some([viewport.center.re, viewport.center.im]),
+        view_size: Some(viewport.scale * 2.0),
+        colormap: Some(colormap),
+        rng_seed: Some(seed),
+        ..options
+    };
+    let palette = resolved
+        .palette()
+        .expect("resolved options always have a colormap");
+
+    let channels = compute_density(&resolved, viewport, &buddhabrot, seed).await;
+    let image = colorize_density(
+        &channels,
+        &viewport,
+        &palette,
+        &PaletteMapping::from(&resolved),
+        buddhabrot.color,
+    );
+
+    Ok((encode_base64(&encode_png(&image)), resolved))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn higher_limits_accumulate_more_escaping_orbits() {
+        let viewport = Viewport::new(Complex64::new(-0.5, 0.0), 1.5, 0.0, [32, 32]);
+        let density = |seed| {
+            let mut rng = fastrand::Rng::with_seed(seed);
+            accumulate(
+                &mut rng,
+                &viewport,
+                DensityMode::Buddhabrot,
+                &[10, 100],
+                2_000,
+            )
+        };
+
+        let channels = density(3);
+        let hits = |c: &Vec<u32>| c.iter().map(|&n| n as u64).sum::<u64>();
+
+        assert_eq!(channels, density(3));
+        assert!(hits(&channels[0]) > 0);
+        assert!(hits(&channels[1]) > hits(&channels[0]));
+    }
+
+    #[test]
+    fn density_view_ignores_the_image_view() {
+        let zoomed = Options {
+            image_center: Some([-0.743, 0.131]),
+            view_size: Some(1e-6),
+            ..Options::default()
+        };
+
+        let whole = density_viewport(&zoomed, &Buddhabrot::default());
+        assert_eq!(whole.center, Complex64::new(-0.5, 0.0));
+        assert_eq!(whole.scale, 1.5);
+
+        let own = Buddhabrot {
+            center: Some([-1.0, 0.0]),
+            view_size: Some(0.5),
+            ..Buddhabrot::default()
+        };
+        let view = density_viewport(&zoomed, &own);
+        assert_eq!(view.center, Complex64::new(-1.0, 0.0));
+        assert_eq!(view.scale, 0.25);
+    }
+
+    #[test]
+    fn anti_buddhabrot_only_plots_bounded_orbits() {
+        let viewport = Viewport::new(Complex64::new(0.0, 0.0), 2.0, 0.0, [32, 32]);
+        let plot = |c: Complex64, mode| {
+            let mut channels = vec![vec![0u32; 32 * 32]];
+            plot_orbit(c, &viewport, mode, &[50], &mut Vec::new(), &mut channels);
+            channels[0].iter().sum::<u32>()
+        };
+
+        // 1 + i escapes on its second step, -1 cycles between -1 and 0 forever.
+        let escaping = Complex64::new(1.0, 1.0);
+        let bounded = Complex64::new(-1.0, 0.0);
+
+        assert!(plot(escaping, DensityMode::Buddhabrot) > 0);
+        assert_eq!(plot(escaping, DensityMode::AntiBuddhabrot), 0);
+        assert_eq!(plot(bounded, DensityMode::Buddhabrot), 0);
+        assert!(plot(bounded, DensityMode::AntiBuddhabrot) > 0);
+    }
+}
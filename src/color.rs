@@ -0,0 +1,289 @@
+use crate::lerp;
+
+/// How colors between two palette entries are found.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Interpolation {
+    /// No blending, the closest entry is used.
+    Nearest,
+    Srgb,
+    /// Blends in OKLab, which keeps steps in lightness and hue even.
+    Oklab,
+    /// Blends in CIELAB under a D65 white point.
+    Cielab,
+}
+
+pub const INTERPOLATIONS: &[Interpolation] = &[
+    Interpolation::Nearest,
+    Interpolation::Srgb,
+    Interpolation::Oklab,
+    Interpolation::Cielab,
+];
+
+/// Color vision deficiencies a palette can be previewed with.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ColorVision {
+    Typical,
+    /// No long wavelength (red) cones.
+    Protanopia,
+    /// No medium wavelength (green) cones.
+    Deuteranopia,
+    /// No short wavelength (blue) cones.
+    Tritanopia,
+}
+
+pub const COLOR_VISIONS: &[ColorVision] = &[
+    ColorVision::Typical,
+    ColorVision::Protanopia,
+    ColorVision::Deuteranopia,
+    ColorVision::Tritanopia,
+];
+
+/// D65 reference white in XYZ.
+const WHITE: [f64; 3] = [0.95047, 1.0, 1.08883];
+
+pub fn srgb_to_linear(channel: u8) -> f64 {
+    let c = channel as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub fn linear_to_srgb(channel: f64) -> u8 {
+    let c = channel.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+fn to_linear(rgb: [u8; 3]) -> [f64; 3] {
+    rgb.map(srgb_to_linear)
+}
+
+fn from_linear(rgb: [f64; 3]) -> [u8; 3] {
+    rgb.map(linear_to_srgb)
+}
+
+pub fn srgb_to_oklab(rgb: [u8; 3]) -> [f64; 3] {
+    let [r, g, b] = to_linear(rgb);
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+pub fn oklab_to_srgb(lab: [f64; 3]) -> [u8; 3] {
+    let [l, a, b] = lab;
+
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    from_linear([
+        4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+        -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+        -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+    ])
+}
+
+pub fn srgb_to_cielab(rgb: [u8; 3]) -> [f64; 3] {
+    let [r, g, b] = to_linear(rgb);
+    let xyz = [
+        0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+        0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+        0.0193339 * r + 0.1191920 * g + 0.9503041 * b,
+    ];
+
+    let f = |t: f64| {
+        if t > (6.0f64 / 29.0).powi(3) {
+            t.cbrt()
+        } else {
+            t / (3.0 * (6.0f64 / 29.0).powi(2)) + 4.0 / 29.0
+        }
+    };
+    let [fx, fy, fz] = [0, 1, 2].map(|i| f(xyz[i] / WHITE[i]));
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+pub fn cielab_to_srgb(lab: [f64; 3]) -> [u8; 3] {
+    let fy = (lab[0] + 16.0) / 116.0;
+    let fx = fy + lab[1] / 500.0;
+    let fz = fy - lab[2] / 200.0;
+
+    let f_inv = |t: f64| {
+        if t > 6.0 / 29.0 {
+            t.powi(3)
+        } else {
+            3.0 * (6.0f64 / 29.0).powi(2) * (t - 4.0 / 29.0)
+        }
+    };
+    let f = [fx, fy, fz];
+    let [x, y, z] = [0, 1, 2].map(|i| f_inv(f[i]) * WHITE[i]);
+
+    from_linear([
+        3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+        -0.9692660 * x + 1.8760108 * y + 0.0415560 * z,
+        0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+    ])
+}
+
+impl Interpolation {
+    /// The color `t` of the way from `a` to `b`.
+    pub fn mix(self, a: [u8; 3], b: [u8; 3], t: f64) -> [u8; 3] {
+        let blend = |a: [f64; 3], b: [f64; 3]| [0, 1, 2].map(|i| lerp(a[i], b[i], t));
+
+        match self {
+            Interpolation::Nearest if t < 0.5 => a,
+            Interpolation::Nearest => b,
+            Interpolation::Srgb => {
+                let mixed = blend(a.map(|c| c as f64), b.map(|c| c as f64));
+                mixed.map(|c| c.round().clamp(0.0, 255.0) as u8)
+            }
+            Interpolation::Oklab => oklab_to_srgb(blend(srgb_to_oklab(a), srgb_to_oklab(b))),
+            Interpolation::Cielab => cielab_to_srgb(blend(srgb_to_cielab(a), srgb_to_cielab(b))),
+        }
+    }
+}
+
+impl ColorVision {
+    /// How `rgb` looks with this color vision, using the full severity matrices of Machado,
+    /// Oliveira and Fernandes (2009) in linear RGB.
+    pub fn simulate(self, rgb: [u8; 3]) -> [u8; 3] {
+        let matrix = match self {
+            ColorVision::Typical => return rgb,
+            ColorVision::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorVision::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorVision::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        };
+
+        let linear = to_linear(rgb);
+        from_linear(matrix.map(|row| (0..3).map(|i| row[i] * linear[i]).sum()))
+    }
+}
+
+/// Distance between two colors in OKLab, where 0.02 is about the smallest visible step.
+pub fn oklab_distance(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let (a, b) = (srgb_to_oklab(a), srgb_to_oklab(b));
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum::<f64>().sqrt()
+}
+
+/// The color at a fractional `position` in an RGB table, blending the entries on either side.
+///
+/// Positions past the last entry blend back towards the first, so wrapped palettes stay smooth.
+pub fn table_color(table: &[u8], position: f64, interpolation: Interpolation) -> [u8; 3] {
+    let entries = table.len() / 3;
+    let index = (position.floor().max(0.0) as usize).min(entries - 1);
+    let next = (index + 1) % entries;
+    let color = |i: usize| [table[3 * i], table[3 * i + 1], table[3 * i + 2]];
+
+    interpolation.mix(color(index), color(next), position - index as f64)
+}
+
+/// Resamples an RGB table to `entries` colors spread from its first to its last color.
+pub fn resample(table: &[u8], entries: usize, interpolation: Interpolation) -> Vec<u8> {
+    let last = (table.len() / 3).saturating_sub(1) as f64;
+    let step = last / (entries as f64 - 1.0).max(1.0);
+
+    (0..entries)
+        .flat_map(|i| {
+            let position = i as f64 * step;
+            if position >= last {
+                let end = table.len() - 3;
+                [table[end], table[end + 1], table[end + 2]]
+            } else {
+                table_color(table, position, interpolation)
+            }
+        })
+        .collect()
+}
+
+/// Blends two RGB tables of any lengths into one with `entries` colors, `t` of the way from `a`
+/// to `b`.
+pub fn blend(a: &[u8], b: &[u8], t: f64, entries: usize, interpolation: Interpolation) -> Vec<u8> {
+    let a = resample(a, entries, interpolation);
+    let b = resample(b, entries, interpolation);
+
+    a.chunks_exact(3)
+        .zip(b.chunks_exact(3))
+        .flat_map(|(a, b)| interpolation.mix([a[0], a[1], a[2]], [b[0], b[1], b[2]], t))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions_round_trip() {
+        for rgb in [[0, 0, 0], [255, 255, 255], [12, 200, 77], [255, 0, 128]] {
+            assert_eq!(oklab_to_srgb(srgb_to_oklab(rgb)), rgb);
+            assert_eq!(cielab_to_srgb(srgb_to_cielab(rgb)), rgb);
+        }
+
+        let white = srgb_to_oklab([255, 255, 255]);
+        assert!((white[0] - 1.0).abs() < 1e-6 && white[1].abs() < 1e-6);
+        assert!((srgb_to_cielab([255, 255, 255])[0] - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn simulation_keeps_grays_gray() {
+        for &vision in COLOR_VISIONS {
+            for gray in [0, 128, 255] {
+                let simulated = vision.simulate([gray; 3]);
+                assert!(
+                    simulated.iter().all(|&c| c.abs_diff(gray) <= 1),
+                    "{:?}",
+                    vision
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn red_and_green_converge_without_green_cones() {
+        let (red, green) = ([200, 60, 40], [60, 160, 40]);
+        let deutan = ColorVision::Deuteranopia;
+
+        assert!(
+            oklab_distance(deutan.simulate(red), deutan.simulate(green))
+                < oklab_distance(red, green) / 2.0
+        );
+    }
+
+    #[test]
+    fn resampling_keeps_the_ends_and_blending_mixes_tables() {
+        let table = [0, 0, 0, 255, 255, 255];
+
+        let resampled = resample(&table, 5, Interpolation::Srgb);
+        assert_eq!(&resampled[..3], &[0, 0, 0]);
+        assert_eq!(&resampled[6..9], &[128, 128, 128]);
+        assert_eq!(&resampled[12..], &[255, 255, 255]);
+
+        let reversed = [255, 255, 255, 0, 0, 0];
+        let blended = blend(&table, &reversed, 0.5, 3, Interpolation::Srgb);
+        assert!(blended.iter().all(|&c| c == 128));
+    }
+}
@@ -0,0 +1,646 @@
+use crate::{
+    color::{table_color, Interpolation},
+    lerp,
+    options::Options,
+    IterationBuffer, Sample,
+};
+use image::{Rgba, RgbaImage};
+
+/// How palette positions past either end of the palette are brought back into range.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PaletteWrap {
+    Clamp,
+    Repeat,
+    Mirror,
+}
+
+pub const PALETTE_WRAPS: &[PaletteWrap] =
+    &[PaletteWrap::Clamp, PaletteWrap::Repeat, PaletteWrap::Mirror];
+
+/// How iteration counts are scaled before being spread over the palette.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PaletteScale {
+    Linear,
+    Logarithmic,
+    /// Spreads the frame's escaped pixels evenly over the palette by ranking their iteration
+    /// counts, so narrow iteration ranges still use every color.
+    Histogram,
+}
+
+/// How points outside the set are colored.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ExteriorColoring {
+    /// Palette colors by the number of steps taken to escape.
+    Iterations,
+    /// Palette colors by the estimated distance to the set, in pixels.
+    Distance,
+    /// Iteration colors darkened towards the boundary of the set.
+    DistanceShaded,
+    /// Black boundary lines on white, traced with the distance estimate.
+    BoundaryLines,
+    /// Palette colors by how close the orbit came to the orbit trap.
+    OrbitTrap,
+    /// Palette colors by the stripe average of the orbit's angles.
+    StripeAverage,
+    /// Palette colors by the triangle inequality average of the orbit.
+    TriangleInequality,
+}
+
+pub const EXTERIOR_COLORINGS: &[ExteriorColoring] = &[
+    ExteriorColoring::Iterations,
+    ExteriorColoring::Distance,
+    ExteriorColoring::DistanceShaded,
+    ExteriorColoring::BoundaryLines,
+    ExteriorColoring::OrbitTrap,
+    ExteriorColoring::StripeAverage,
+    ExteriorColoring::TriangleInequality,
+];
+
+/// How opaque points outside the set are.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ExteriorAlpha {
+    Opaque,
+    /// Fades out the faster a point escapes, so only the glow around the set stays opaque.
+    EscapeSpeed,
+}
+
+pub const EXTERIOR_ALPHAS: &[ExteriorAlpha] = &[ExteriorAlpha::Opaque, ExteriorAlpha::EscapeSpeed];
+
+/// Which value is treated as a height field when lighting the image.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Lighting {
+    Off,
+    /// Relief from the smooth iteration count.
+    Iterations,
+    /// Relief rising with the distance estimate, embossing the boundary of the set.
+    Distance,
+}
+
+pub const LIGHTINGS: &[Lighting] = &[Lighting::Off, Lighting::Iterations, Lighting::Distance];
+
+/// Height per unit of log smooth iteration count, in pixels.
+const ITERATION_RELIEF: f64 = 64.0;
+
+/// Palette entries between distances that differ by a factor of two when coloring by distance
+/// or orbit trap distance.
+const DISTANCE_SPACING: f64 = 16.0;
+
+/// Palette value for an orbit trap distance, rising as the orbit gets closer to the trap.
+fn trap_value(sample: Sample) -> f64 {
+    (-sample.trap_distance.log2()).max(0.0) * DISTANCE_SPACING
+}
+
+/// How points inside the set are colored.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum InteriorColoring {
+    /// The palette color for zero iterations, like a point that escaped immediately.
+    Palette,
+    Solid,
+    Transparent,
+    /// Palette colors by the length of the cycle the orbit settled into.
+    Period,
+    /// Palette colors by the final |z| of the orbit.
+    Magnitude,
+    /// Palette colors by how close the orbit came to the orbit trap.
+    OrbitTrap,
+}
+
+pub const INTERIOR_COLORINGS: &[InteriorColoring] = &[
+    InteriorColoring::Palette,
+    InteriorColoring::Solid,
+    InteriorColoring::Transparent,
+    InteriorColoring::Period,
+    InteriorColoring::Magnitude,
+    InteriorColoring::OrbitTrap,
+];
+
+/// Palette entries between consecutive periods when coloring by period.
+const PERIOD_SPACING: usize = 24;
+
+pub const PALETTE_SCALES: &[PaletteScale] = &[
+    PaletteScale::Linear,
+    PaletteScale::Logarithmic,
+    PaletteScale::Histogram,
+];
+
+/// Cumulative distribution of the escaped iteration counts in a frame.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Histogram {
+    cdf: Vec<f64>,
+}
+
+impl Histogram {
+    pub fn new(buffer: &IterationBuffer) -> Self {
+        let escaped = || buffer.samples.iter().filter(|s| !s.inside);
+        let max = escaped().map(|s| s.steps).max().unwrap_or(0) as usize;
+
+        let mut counts = vec![0u64; max + 1];
+        for sample in escaped() {
+            counts[sample.steps as usize] += 1;
+        }
+
+        let total = counts.iter().sum::<u64>().max(1) as f64;
+        let mut running = 0;
+        let cdf = counts
+            .iter()
+            .map(|&count| {
+                running += count;
+                running as f64 / total
+            })
+            .collect();
+
+        Self { cdf }
+    }
+
+    /// Fraction of escaped pixels that took at most `iteration` steps.
+    pub fn rank(&self, iteration: u32) -> f64 {
+        self.cdf.get(iteration as usize).copied().unwrap_or(1.0)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PaletteMapping {
+    /// Palette entries to shift every color by.
+    pub offset: f64,
+    /// Palette entries advanced per iteration.
+    pub density: f64,
+    pub wrap: PaletteWrap,
+    pub scale: PaletteScale,
+    pub reverse: bool,
+    pub interpolation: Interpolation,
+    pub max_steps: u32,
+    pub exterior: ExteriorColoring,
+    /// Width in pixels of boundary lines and distance shading.
+    pub line_width: f64,
+    pub exterior_alpha: ExteriorAlpha,
+    pub interior: InteriorColoring,
+    pub interior_color: [u8; 3],
+    pub lighting: Lighting,
+    /// Direction the light comes from in the image plane, in degrees counterclockwise from the
+    /// right.
+    pub light_angle: f64,
+    /// Elevation of the light above the image plane, in degrees.
+    pub light_height: f64,
+    /// How much of the lit color is blended over the flat color, from 0 to 1.
+    pub light_strength: f64,
+}
+
+impl From<&Options> for PaletteMapping {
+    fn from(options: &Options) -> Self {
+        Self {
+            offset: options.palette_offset,
+            density: options.palette_density,
+            wrap: options.palette_wrap,
+            scale: options.palette_scale,
+            reverse: options.palette_reverse,
+            interpolation: options.palette_interpolation,
+            max_steps: options.step_limits[1],
+            exterior: options.exterior_coloring,
+            line_width: options.line_width,
+            exterior_alpha: options.exterior_alpha,
+            interior: options.interior_coloring,
+            interior_color: options.interior_color,
+            lighting: options.lighting,
+            light_angle: options.light_angle,
+            light_height: options.light_height,
+            light_strength: options.light_strength,
+        }
+    }
+}
+
+impl PaletteMapping {
+    /// Where a value lands in a palette of `entries` colors before reversal, from 0 up to
+    /// `entries`.
+    ///
+    /// Histogram scaling needs the frame's `histogram`, without one it falls back to linear.
+    fn wrapped_position(&self, value: f64, entries: usize, histogram: Option<&Histogram>) -> f64 {
+        let last = entries as f64 - 1.0;
+
+        let scaled = match (self.scale, histogram) {
+            (PaletteScale::Logarithmic, _) => {
+                let max = self.max_steps.max(1) as f64;
+                value.max(0.0).ln_1p() / max.ln_1p() * max
+            }
+            (PaletteScale::Histogram, Some(histogram)) => histogram.rank(value as u32) * last,
+            _ => value,
+        };
+        let position = scaled * self.density;
+
+        // Clamped palettes still cycle through every color when offset, so palette cycling works
+        // regardless of the wrap mode.
+        match self.wrap {
+            PaletteWrap::Clamp => {
+                (position.clamp(0.0, last) + self.offset).rem_euclid(entries as f64)
+            }
+            PaletteWrap::Repeat => (position + self.offset).rem_euclid(entries as f64),
+            PaletteWrap::Mirror => {
+                let bounce = (position + self.offset).rem_euclid(2.0 * last);
+                if bounce > last {
+                    2.0 * last - bounce
+                } else {
+                    bounce
+                }
+            }
+        }
+    }
+
+    /// The palette entry, out of `entries`, used for an iteration count or other coloring value.
+    pub fn entry(&self, value: f64, entries: usize, histogram: Option<&Histogram>) -> usize {
+        let position = self.wrapped_position(value, entries, histogram);
+        let entry = (position.floor() as usize).min(entries - 1);
+
+        if self.reverse {
+            entries - 1 - entry
+        } else {
+            entry
+        }
+    }
+
+    /// The fractional palette position for a value, for blending between neighbouring entries.
+    pub fn position(&self, value: f64, entries: usize, histogram: Option<&Histogram>) -> f64 {
+        let position = self.wrapped_position(value, entries, histogram);
+
+        if self.reverse {
+            (entries as f64 - 1.0 - position).rem_euclid(entries as f64)
+        } else {
+            position
+        }
+    }
+
+    /// The palette color for a value, blended between entries unless interpolation is off.
+    pub fn color(&self, palette: &[u8], value: f64, histogram: Option<&Histogram>) -> Rgba<u8> {
+        let entries = palette.len() / 3;
+
+        match self.interpolation {
+            Interpolation::Nearest => palette_color(palette, self.entry(value, entries, histogram)),
+            interpolation => {
+                let position = self.position(value, entries, histogram);
+                let [r, g, b] = table_color(palette, position, interpolation);
+                Rgba::from([r, g, b, 0xFF])
+            }
+        }
+    }
+}
+
+fn palette_color(palette: &[u8], entry: usize) -> Rgba<u8> {
+    let sample = &palette[3 * entry..];
+
+    Rgba::from([sample[0], sample[1], sample[2], 0xFF])
+}
+
+fn shade(color: Rgba<u8>, brightness: f64) -> Rgba<u8> {
+    let [r, g, b, a] = color.0;
+    let scale = |channel: u8| (channel as f64 * brightness).round() as u8;
+
+    Rgba::from([scale(r), scale(g), scale(b), a])
+}
+
+/// Opacity of an escaped sample, rising on a log scale from nothing for points that escape at
+/// once to full for points that take the step limit.
+fn escape_alpha(sample: Sample, max_steps: u32) -> u8 {
+    let t = sample.smooth_steps().max(0.0).ln_1p() / (max_steps.max(1) as f64).ln_1p();
+    (t.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Looks up the color for a sample, using the interior coloring for points inside the set.
+///
+/// `pixel_size` is the width of a pixel in the complex plane, used to measure distances.
+pub fn colorize(
+    sample: Sample,
+    palette: &[u8],
+    mapping: &PaletteMapping,
+    histogram: Option<&Histogram>,
+    pixel_size: f64,
+) -> Rgba<u8> {
+    let entries = palette.len() / 3;
+    let solid = Rgba::from([
+        mapping.interior_color[0],
+        mapping.interior_color[1],
+        mapping.interior_color[2],
+        0xFF,
+    ]);
+
+    if !sample.inside {
+        let pixels = sample.distance / pixel_size;
+        // Zero right at the boundary, reaching one a line width away from it.
+        let edge = (pixels / mapping.line_width.max(f64::EPSILON)).clamp(0.0, 1.0);
+        let iterations = || {
+            // Blending between entries only helps when the count is continuous too.
+            let steps = match mapping.interpolation {
+                Interpolation::Nearest => sample.steps as f64,
+                _ => sample.smooth_steps(),
+            };
+            mapping.color(palette, steps, histogram)
+        };
+        let spanning = |t: f64| mapping.color(palette, t * (entries - 1) as f64, None);
+
+        let color = match mapping.exterior {
+            ExteriorColoring::Iterations => iterations(),
+            ExteriorColoring::Distance => {
+                mapping.color(palette, pixels.log2().max(0.0) * DISTANCE_SPACING, None)
+            }
+            ExteriorColoring::DistanceShaded => shade(iterations(), edge.sqrt()),
+            ExteriorColoring::BoundaryLines => {
+                let value = (edge * 255.0).round() as u8;
+                Rgba::from([value, value, value, 0xFF])
+            }
+            ExteriorColoring::OrbitTrap => mapping.color(palette, trap_value(sample), None),
+            // Averages span the palette once, before density and offset are applied.
+            ExteriorColoring::StripeAverage => spanning(sample.stripe),
+            ExteriorColoring::TriangleInequality => spanning(sample.triangle),
+        };
+
+        return match mapping.exterior_alpha {
+            ExteriorAlpha::Opaque => color,
+            ExteriorAlpha::EscapeSpeed => {
+                let [r, g, b, _] = color.0;
+                Rgba::from([r, g, b, escape_alpha(sample, mapping.max_steps)])
+            }
+        };
+    }
+
+    match mapping.interior {
+        InteriorColoring::Palette => mapping.color(palette, 0.0, histogram),
+        InteriorColoring::Solid => solid,
+        InteriorColoring::Transparent => Rgba::from([0, 0, 0, 0]),
+        InteriorColoring::Period if sample.period == 0 => solid,
+        InteriorColoring::Period => palette_color(
+            palette,
+            (sample.period as usize - 1) * PERIOD_SPACING % entries,
+        ),
+        InteriorColoring::Magnitude => {
+            let t = (sample.magnitude / 2.0).clamp(0.0, 1.0);
+            palette_color(palette, (t * (entries - 1) as f64).round() as usize)
+        }
+        InteriorColoring::OrbitTrap => mapping.color(palette, trap_value(sample), None),
+    }
+}
+
+/// Height of a sample when lighting, in pixels. Points inside the set have none.
+fn height(sample: Sample, lighting: Lighting, pixel_size: f64) -> Option<f64> {
+    if sample.inside {
+        return None;
+    }
+
+    match lighting {
+        Lighting::Off => None,
+        Lighting::Iterations => Some(sample.smooth_steps().ln_1p() * ITERATION_RELIEF),
+        Lighting::Distance => Some(sample.distance / pixel_size),
+    }
+}
+
+/// Brightness of each pixel lit from the mapping's light direction, relative to a flat surface.
+///
+/// Normals come from central differences of the height field. Neighbours without a height, such
+/// as points inside the set, are treated as level with the pixel.
+pub fn light_buffer(buffer: &IterationBuffer, mapping: &PaletteMapping) -> Vec<f64> {
+    let heights: Vec<Option<f64>> = buffer
+        .samples
+        .iter()
+        .map(|&s| height(s, mapping.lighting, buffer.pixel_size))
+        .collect();
+
+    let (azimuth, elevation) = (
+        mapping.light_angle.to_radians(),
+        mapping.light_height.to_radians(),
+    );
+    let light = [
+        elevation.cos() * azimuth.cos(),
+        // Image rows run downwards, angles run counterclockwise on screen.
+        -elevation.cos() * azimuth.sin(),
+        elevation.sin(),
+    ];
+    // A flat surface keeps its color, light above the plane brightens slopes facing it.
+    let flat = light[2].max(f64::EPSILON);
+
+    let (width, height) = (buffer.width as i64, buffer.height as i64);
+    let at = |x: i64, y: i64, fallback: f64| {
+        if x < 0 || y < 0 || x >= width || y >= height {
+            return fallback;
+        }
+        heights[(y * width + x) as usize].unwrap_or(fallback)
+    };
+
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let Some(center) = heights[(y * width + x) as usize] else {
+                return 1.0;
+            };
+
+            let dx = (at(x + 1, y, center) - at(x - 1, y, center)) / 2.0;
+            let dy = (at(x, y + 1, center) - at(x, y - 1, center)) / 2.0;
+            let length = (dx * dx + dy * dy + 1.0).sqrt();
+            let diffuse = ((-dx * light[0] - dy * light[1] + light[2]) / length).max(0.0);
+
+            lerp(1.0, diffuse / flat, mapping.light_strength.clamp(0.0, 1.0)).clamp(0.0, 2.0)
+        })
+        .collect()
+}
+
+pub fn colorize_buffer(
+    buffer: &IterationBuffer,
+    palette: &[u8],
+    mapping: &PaletteMapping,
+) -> RgbaImage {
+    let histogram = (mapping.scale == PaletteScale::Histogram).then(|| Histogram::new(buffer));
+    let brightness = (mapping.lighting != Lighting::Off).then(|| light_buffer(buffer, mapping));
+
+    RgbaImage::from_fn(buffer.width, buffer.height, |x, y| {
+        let color = colorize(
+            buffer.get(x, y),
+            palette,
+            mapping,
+            histogram.as_ref(),
+            buffer.pixel_size,
+        );
+
+        match &brightness {
+            Some(brightness) => shade(color, brightness[(y * buffer.width + x) as usize]),
+            None => color,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(wrap: PaletteWrap) -> PaletteMapping {
+        PaletteMapping {
+            offset: 0.0,
+            density: 1.0,
+            wrap,
+            scale: PaletteScale::Linear,
+            reverse: false,
+            interpolation: Interpolation::Nearest,
+            max_steps: 1024,
+            exterior: ExteriorColoring::Iterations,
+            line_width: 1.0,
+            exterior_alpha: ExteriorAlpha::Opaque,
+            interior: InteriorColoring::Palette,
+            interior_color: [0, 0, 0],
+            lighting: Lighting::Off,
+            light_angle: 45.0,
+            light_height: 45.0,
+            light_strength: 1.0,
+        }
+    }
+
+    #[test]
+    fn wrap_modes_bring_positions_back_into_range() {
+        assert_eq!(mapping(PaletteWrap::Clamp).entry(300.0, 256, None), 255);
+        assert_eq!(mapping(PaletteWrap::Repeat).entry(300.0, 256, None), 44);
+        assert_eq!(mapping(PaletteWrap::Mirror).entry(300.0, 256, None), 210);
+    }
+
+    #[test]
+    fn reverse_and_offset_apply_after_scaling() {
+        let shifted = PaletteMapping {
+            offset: 10.0,
+            reverse: true,
+            ..mapping(PaletteWrap::Clamp)
+        };
+
+        assert_eq!(shifted.entry(0.0, 256, None), 245);
+        assert_eq!(shifted.entry(300.0, 256, None), 246);
+    }
+
+    #[test]
+    fn histogram_spreads_narrow_ranges_over_the_palette() {
+        let buffer = IterationBuffer {
+            width: 4,
+            height: 1,
+            pixel_size: 1.0,
+            samples: [200, 201, 202, 203]
+                .iter()
+                .map(|&steps| Sample {
+                    steps,
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        let histogram = Histogram::new(&buffer);
+        let mapping = PaletteMapping {
+            scale: PaletteScale::Histogram,
+            ..mapping(PaletteWrap::Clamp)
+        };
+
+        let entries: Vec<usize> = buffer
+            .samples
+            .iter()
+            .map(|s| mapping.entry(s.steps as f64, 256, Some(&histogram)))
+            .collect();
+
+        assert_eq!(entries, vec![63, 127, 191, 255]);
+    }
+
+    #[test]
+    fn boundary_lines_darken_towards_the_set() {
+        let lines = PaletteMapping {
+            exterior: ExteriorColoring::BoundaryLines,
+            line_width: 2.0,
+            ..mapping(PaletteWrap::Clamp)
+        };
+        let at = |distance: f64| {
+            let sample = Sample {
+                steps: 10,
+                distance,
+                ..Default::default()
+            };
+            colorize(sample, &[0; 768], &lines, None, 0.5)[0]
+        };
+
+        assert_eq!(at(0.0), 0);
+        assert_eq!(at(0.5), 128);
+        assert_eq!(at(4.0), 255);
+    }
+
+    #[test]
+    fn lighting_brightens_slopes_facing_the_light() {
+        // Distance rising to the right, lit from the left, right and straight above.
+        let buffer = IterationBuffer {
+            width: 3,
+            height: 1,
+            pixel_size: 1.0,
+            samples: [1.0, 2.0, 3.0]
+                .iter()
+                .map(|&distance| Sample {
+                    steps: 10,
+                    distance,
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        let lit = |light_angle: f64, light_height: f64| {
+            let mapping = PaletteMapping {
+                lighting: Lighting::Distance,
+                light_angle,
+                light_height,
+                ..mapping(PaletteWrap::Clamp)
+            };
+            light_buffer(&buffer, &mapping)[1]
+        };
+
+        assert!(lit(180.0, 45.0) > 1.0);
+        assert!(lit(0.0, 45.0) < 1.0);
+        assert!((lit(0.0, 90.0) - 0.5f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn interpolated_lookups_blend_neighbouring_entries() {
+        let palette = [0, 0, 0, 200, 200, 200];
+        let blended = PaletteMapping {
+            interpolation: Interpolation::Srgb,
+            ..mapping(PaletteWrap::Clamp)
+        };
+
+        assert_eq!(
+            blended.color(&palette, 0.25, None),
+            Rgba::from([50, 50, 50, 0xFF])
+        );
+        assert_eq!(
+            mapping(PaletteWrap::Clamp).color(&palette, 0.75, None),
+            Rgba::from([0, 0, 0, 0xFF])
+        );
+
+        let reversed = PaletteMapping {
+            reverse: true,
+            ..blended
+        };
+        assert_eq!(
+            reversed.color(&palette, 0.25, None),
+            Rgba::from([150, 150, 150, 0xFF])
+        );
+    }
+
+    #[test]
+    fn escape_speed_alpha_fades_fast_escapes() {
+        let faded = PaletteMapping {
+            exterior_alpha: ExteriorAlpha::EscapeSpeed,
+            ..mapping(PaletteWrap::Clamp)
+        };
+        let palette = [255, 255, 255];
+        let escaped = |steps| Sample {
+            steps,
+            inside: false,
+            magnitude: 1e6,
+            ..Sample::default()
+        };
+        let alpha = |steps| colorize(escaped(steps), &palette, &faded, None, 1.0).0[3];
+
+        assert!(alpha(2) < alpha(50));
+        assert!(alpha(50) < alpha(1000));
+        assert_eq!(
+            colorize(
+                escaped(2),
+                &palette,
+                &mapping(PaletteWrap::Clamp),
+                None,
+                1.0
+            )
+            .0[3],
+            0xFF
+        );
+    }
+}
@@ -19,2194 +19,9 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-pub const ACTON: &[u8] = &[
-    46, 33, 77, 46, 33, 77, 47, 34, 77, 47, 34, 77, 48, 35, 78, 48, 35, 78, 49, 36, 79, 49, 36, 79,
-    50, 37, 80, 50, 37, 80, 51, 38, 81, 51, 38, 81, 52, 38, 82, 52, 38, 82, 53, 39, 83, 53, 39, 83,
-    54, 40, 84, 54, 40, 84, 55, 41, 85, 55, 41, 85, 56, 42, 86, 56, 42, 86, 57, 43, 87, 57, 43, 87,
-    58, 44, 88, 58, 44, 88, 59, 45, 88, 59, 45, 88, 60, 46, 89, 60, 46, 89, 61, 47, 90, 61, 47, 90,
-    62, 48, 91, 62, 48, 91, 63, 48, 92, 63, 48, 92, 65, 49, 93, 65, 49, 93, 66, 50, 94, 66, 50, 94,
-    67, 51, 95, 67, 51, 95, 68, 52, 96, 68, 52, 96, 69, 53, 97, 69, 53, 97, 70, 54, 98, 70, 54, 98,
-    71, 55, 99, 71, 55, 99, 72, 56, 100, 72, 56, 100, 73, 57, 100, 73, 57, 100, 74, 58, 101, 74,
-    58, 101, 75, 59, 102, 75, 59, 102, 76, 60, 103, 76, 60, 103, 77, 61, 104, 77, 61, 104, 79, 61,
-    105, 79, 61, 105, 80, 62, 106, 80, 62, 106, 81, 63, 107, 81, 63, 107, 82, 64, 108, 82, 64, 108,
-    83, 65, 109, 83, 65, 109, 84, 66, 110, 84, 66, 110, 85, 67, 111, 85, 67, 111, 86, 68, 112, 86,
-    68, 112, 88, 69, 113, 88, 69, 113, 89, 70, 114, 89, 70, 114, 90, 71, 114, 90, 71, 114, 91, 72,
-    115, 91, 72, 115, 92, 73, 116, 92, 73, 116, 94, 73, 117, 94, 73, 117, 95, 74, 118, 95, 74, 118,
-    96, 75, 119, 96, 75, 119, 97, 76, 120, 97, 76, 120, 99, 77, 121, 99, 77, 121, 100, 78, 122,
-    100, 78, 122, 101, 79, 123, 101, 79, 123, 102, 80, 123, 102, 80, 123, 104, 80, 124, 104, 80,
-    124, 105, 81, 125, 105, 81, 125, 106, 82, 126, 106, 82, 126, 107, 83, 127, 107, 83, 127, 109,
-    84, 128, 109, 84, 128, 110, 84, 128, 110, 84, 128, 111, 85, 129, 111, 85, 129, 113, 86, 130,
-    113, 86, 130, 114, 87, 131, 114, 87, 131, 115, 87, 131, 115, 87, 131, 117, 88, 132, 117, 88,
-    132, 118, 89, 133, 118, 89, 133, 119, 90, 134, 119, 90, 134, 121, 90, 134, 121, 90, 134, 122,
-    91, 135, 122, 91, 135, 123, 91, 136, 123, 91, 136, 125, 92, 136, 125, 92, 136, 126, 93, 137,
-    126, 93, 137, 127, 93, 137, 127, 93, 137, 129, 94, 138, 129, 94, 138, 130, 94, 139, 130, 94,
-    139, 131, 95, 139, 131, 95, 139, 133, 95, 140, 133, 95, 140, 134, 96, 140, 134, 96, 140, 135,
-    96, 141, 135, 96, 141, 136, 97, 141, 136, 97, 141, 138, 97, 142, 138, 97, 142, 139, 97, 142,
-    139, 97, 142, 140, 98, 142, 140, 98, 142, 141, 98, 143, 141, 98, 143, 143, 98, 143, 143, 98,
-    143, 144, 99, 144, 144, 99, 144, 145, 99, 144, 145, 99, 144, 146, 99, 144, 146, 99, 144, 148,
-    100, 144, 148, 100, 144, 149, 100, 145, 149, 100, 145, 150, 100, 145, 150, 100, 145, 151, 100,
-    145, 151, 100, 145, 152, 100, 145, 152, 100, 145, 153, 101, 146, 153, 101, 146, 155, 101, 146,
-    155, 101, 146, 156, 101, 146, 156, 101, 146, 157, 101, 146, 157, 101, 146, 158, 101, 146, 158,
-    101, 146, 159, 101, 147, 159, 101, 147, 160, 101, 147, 160, 101, 147, 161, 101, 147, 161, 101,
-    147, 162, 102, 147, 162, 102, 147, 164, 102, 147, 164, 102, 147, 165, 102, 147, 165, 102, 147,
-    166, 102, 148, 166, 102, 148, 167, 102, 148, 167, 102, 148, 168, 102, 148, 168, 102, 148, 169,
-    102, 148, 169, 102, 148, 170, 102, 148, 170, 102, 148, 171, 102, 148, 171, 102, 148, 172, 102,
-    148, 172, 102, 148, 173, 103, 149, 173, 103, 149, 175, 103, 149, 175, 103, 149, 176, 103, 149,
-    176, 103, 149, 177, 103, 149, 177, 103, 149, 178, 103, 149, 178, 103, 149, 179, 103, 149, 179,
-    103, 149, 180, 104, 150, 180, 104, 150, 181, 104, 150, 181, 104, 150, 183, 104, 150, 183, 104,
-    150, 184, 105, 150, 184, 105, 150, 185, 105, 151, 185, 105, 151, 186, 105, 151, 186, 105, 151,
-    187, 106, 152, 187, 106, 152, 189, 106, 152, 189, 106, 152, 190, 107, 152, 190, 107, 152, 191,
-    107, 153, 191, 107, 153, 192, 108, 153, 192, 108, 153, 193, 109, 154, 193, 109, 154, 195, 109,
-    154, 195, 109, 154, 196, 110, 155, 196, 110, 155, 197, 111, 156, 197, 111, 156, 198, 112, 156,
-    198, 112, 156, 199, 112, 157, 199, 112, 157, 200, 113, 158, 200, 113, 158, 201, 114, 158, 201,
-    114, 158, 202, 115, 159, 202, 115, 159, 203, 116, 160, 203, 116, 160, 204, 117, 161, 204, 117,
-    161, 205, 118, 161, 205, 118, 161, 206, 119, 162, 206, 119, 162, 207, 120, 163, 207, 120, 163,
-    208, 121, 164, 208, 121, 164, 208, 122, 164, 208, 122, 164, 209, 123, 165, 209, 123, 165, 209,
-    124, 166, 209, 124, 166, 210, 125, 167, 210, 125, 167, 210, 126, 167, 210, 126, 167, 211, 127,
-    168, 211, 127, 168, 211, 128, 169, 211, 128, 169, 211, 129, 170, 211, 129, 170, 212, 130, 170,
-    212, 130, 170, 212, 131, 171, 212, 131, 171, 212, 132, 172, 212, 132, 172, 212, 133, 172, 212,
-    133, 172, 212, 134, 173, 212, 134, 173, 213, 135, 174, 213, 135, 174, 213, 136, 174, 213, 136,
-    174, 213, 137, 175, 213, 137, 175, 213, 138, 176, 213, 138, 176, 213, 139, 176, 213, 139, 176,
-    213, 140, 177, 213, 140, 177, 213, 140, 178, 213, 140, 178, 213, 141, 178, 213, 141, 178, 213,
-    142, 179, 213, 142, 179, 213, 143, 179, 213, 143, 179, 213, 144, 180, 213, 144, 180, 212, 145,
-    181, 212, 145, 181, 212, 145, 181, 212, 145, 181, 212, 146, 182, 212, 146, 182, 212, 147, 182,
-    212, 147, 182, 212, 148, 183, 212, 148, 183, 212, 149, 184, 212, 149, 184, 212, 149, 184, 212,
-    149, 184, 212, 150, 185, 212, 150, 185, 212, 151, 185, 212, 151, 185, 212, 152, 186, 212, 152,
-    186, 212, 153, 187, 212, 153, 187, 212, 154, 187, 212, 154, 187, 212, 154, 188, 212, 154, 188,
-    212, 155, 188, 212, 155, 188, 212, 156, 189, 212, 156, 189, 211, 157, 189, 211, 157, 189, 211,
-    158, 190, 211, 158, 190, 211, 159, 191, 211, 159, 191, 211, 159, 191, 211, 159, 191, 211, 160,
-    192, 211, 160, 192, 211, 161, 193, 211, 161, 193, 211, 162, 193, 211, 162, 193, 211, 163, 194,
-    211, 163, 194, 211, 164, 194, 211, 164, 194, 211, 165, 195, 211, 165, 195, 211, 166, 196, 211,
-    166, 196, 212, 166, 196, 212, 166, 196, 212, 167, 197, 212, 167, 197, 212, 168, 197, 212, 168,
-    197, 212, 169, 198, 212, 169, 198, 212, 170, 199, 212, 170, 199, 212, 171, 199, 212, 171, 199,
-    212, 172, 200, 212, 172, 200, 212, 173, 201, 212, 173, 201, 212, 174, 201, 212, 174, 201, 212,
-    175, 202, 212, 175, 202, 213, 175, 203, 213, 175, 203, 213, 176, 203, 213, 176, 203, 213, 177,
-    204, 213, 177, 204, 213, 178, 205, 213, 178, 205, 213, 179, 205, 213, 179, 205, 213, 180, 206,
-    213, 180, 206, 214, 181, 206, 214, 181, 206, 214, 182, 207, 214, 182, 207, 214, 183, 208, 214,
-    183, 208, 214, 184, 208, 214, 184, 208, 214, 185, 209, 214, 185, 209, 215, 186, 210, 215, 186,
-    210, 215, 187, 210, 215, 187, 210, 215, 188, 211, 215, 188, 211, 215, 189, 212, 215, 189, 212,
-    216, 190, 213, 216, 190, 213, 216, 191, 213, 216, 191, 213, 216, 192, 214, 216, 192, 214, 217,
-    193, 215, 217, 193, 215, 217, 194, 215, 217, 194, 215, 217, 195, 216, 217, 195, 216, 218, 196,
-    217, 218, 196, 217, 218, 197, 217, 218, 197, 217, 218, 198, 218, 218, 198, 218, 218, 199, 219,
-    218, 199, 219, 219, 200, 219, 219, 200, 219, 219, 201, 220, 219, 201, 220, 219, 202, 221, 219,
-    202, 221, 220, 203, 221, 220, 203, 221, 220, 204, 222, 220, 204, 222, 221, 205, 223, 221, 205,
-    223, 221, 206, 224, 221, 206, 224, 221, 207, 224, 221, 207, 224, 222, 208, 225, 222, 208, 225,
-    222, 209, 226, 222, 209, 226, 222, 210, 226, 222, 210, 226, 223, 211, 227, 223, 211, 227, 223,
-    212, 228, 223, 212, 228, 223, 213, 228, 223, 213, 228, 224, 214, 229, 224, 214, 229, 224, 215,
-    230, 224, 215, 230, 225, 216, 231, 225, 216, 231, 225, 217, 231, 225, 217, 231, 225, 218, 232,
-    225, 218, 232, 226, 219, 233, 226, 219, 233, 226, 220, 233, 226, 220, 233, 227, 221, 234, 227,
-    221, 234, 227, 222, 235, 227, 222, 235, 227, 223, 235, 227, 223, 235, 228, 224, 236, 228, 224,
-    236, 228, 225, 237, 228, 225, 237, 228, 226, 238, 228, 226, 238, 229, 227, 238, 229, 227, 238,
-    229, 228, 239, 229, 228, 239, 230, 230, 240, 230, 230, 240,
-];
+//! Colormap tables and the `Colormap` enum, generated by `build.rs` from the files in
+//! `palettes/`.
 
-pub const BAMAKO: &[u8] = &[
-    0, 64, 77, 0, 64, 77, 1, 64, 76, 1, 64, 76, 2, 65, 76, 2, 65, 76, 2, 65, 75, 2, 65, 75, 3, 65,
-    75, 3, 65, 75, 4, 66, 75, 4, 66, 75, 4, 66, 74, 4, 66, 74, 5, 66, 74, 5, 66, 74, 6, 67, 74, 6,
-    67, 74, 6, 67, 73, 6, 67, 73, 7, 68, 73, 7, 68, 73, 8, 68, 73, 8, 68, 73, 9, 68, 72, 9, 68, 72,
-    9, 69, 72, 9, 69, 72, 10, 69, 71, 10, 69, 71, 11, 70, 71, 11, 70, 71, 11, 70, 71, 11, 70, 71,
-    12, 70, 70, 12, 70, 70, 13, 71, 70, 13, 71, 70, 13, 71, 70, 13, 71, 70, 14, 72, 69, 14, 72, 69,
-    15, 72, 69, 15, 72, 69, 15, 72, 68, 15, 72, 68, 16, 73, 68, 16, 73, 68, 17, 73, 68, 17, 73, 68,
-    17, 74, 67, 17, 74, 67, 18, 74, 67, 18, 74, 67, 18, 74, 67, 18, 74, 67, 19, 75, 66, 19, 75, 66,
-    20, 75, 66, 20, 75, 66, 20, 76, 65, 20, 76, 65, 21, 76, 65, 21, 76, 65, 21, 76, 65, 21, 76, 65,
-    22, 77, 64, 22, 77, 64, 23, 77, 64, 23, 77, 64, 23, 78, 64, 23, 78, 64, 24, 78, 63, 24, 78, 63,
-    25, 79, 63, 25, 79, 63, 25, 79, 62, 25, 79, 62, 26, 79, 62, 26, 79, 62, 26, 80, 62, 26, 80, 62,
-    27, 80, 61, 27, 80, 61, 28, 81, 61, 28, 81, 61, 28, 81, 60, 28, 81, 60, 29, 82, 60, 29, 82, 60,
-    30, 82, 60, 30, 82, 60, 30, 82, 59, 30, 82, 59, 31, 83, 59, 31, 83, 59, 32, 83, 58, 32, 83, 58,
-    32, 84, 58, 32, 84, 58, 33, 84, 58, 33, 84, 58, 34, 85, 57, 34, 85, 57, 34, 85, 57, 34, 85, 57,
-    35, 85, 56, 35, 85, 56, 36, 86, 56, 36, 86, 56, 36, 86, 55, 36, 86, 55, 37, 87, 55, 37, 87, 55,
-    38, 87, 55, 38, 87, 55, 38, 88, 54, 38, 88, 54, 39, 88, 54, 39, 88, 54, 40, 89, 53, 40, 89, 53,
-    40, 89, 53, 40, 89, 53, 41, 90, 52, 41, 90, 52, 42, 90, 52, 42, 90, 52, 43, 90, 52, 43, 90, 52,
-    43, 91, 51, 43, 91, 51, 44, 91, 51, 44, 91, 51, 45, 92, 50, 45, 92, 50, 45, 92, 50, 45, 92, 50,
-    46, 93, 49, 46, 93, 49, 47, 93, 49, 47, 93, 49, 48, 94, 49, 48, 94, 49, 48, 94, 48, 48, 94, 48,
-    49, 95, 48, 49, 95, 48, 50, 95, 47, 50, 95, 47, 51, 96, 47, 51, 96, 47, 51, 96, 46, 51, 96, 46,
-    52, 97, 46, 52, 97, 46, 53, 97, 45, 53, 97, 45, 54, 98, 45, 54, 98, 45, 54, 98, 45, 54, 98, 45,
-    55, 99, 44, 55, 99, 44, 56, 99, 44, 56, 99, 44, 57, 100, 43, 57, 100, 43, 57, 100, 43, 57, 100,
-    43, 58, 101, 42, 58, 101, 42, 59, 101, 42, 59, 101, 42, 60, 102, 41, 60, 102, 41, 61, 102, 41,
-    61, 102, 41, 61, 103, 40, 61, 103, 40, 62, 103, 40, 62, 103, 40, 63, 104, 39, 63, 104, 39, 64,
-    104, 39, 64, 104, 39, 65, 105, 39, 65, 105, 39, 65, 105, 38, 65, 105, 38, 66, 106, 38, 66, 106,
-    38, 67, 106, 37, 67, 106, 37, 68, 107, 37, 68, 107, 37, 69, 107, 36, 69, 107, 36, 70, 108, 36,
-    70, 108, 36, 70, 109, 35, 70, 109, 35, 71, 109, 35, 71, 109, 35, 72, 110, 34, 72, 110, 34, 73,
-    110, 34, 73, 110, 34, 74, 111, 33, 74, 111, 33, 75, 111, 33, 75, 111, 33, 76, 112, 32, 76, 112,
-    32, 76, 113, 32, 76, 113, 32, 77, 113, 31, 77, 113, 31, 78, 114, 31, 78, 114, 31, 79, 114, 30,
-    79, 114, 30, 80, 115, 29, 80, 115, 29, 81, 115, 29, 81, 115, 29, 82, 116, 28, 82, 116, 28, 83,
-    117, 28, 83, 117, 28, 84, 117, 27, 84, 117, 27, 85, 118, 27, 85, 118, 27, 86, 119, 26, 86, 119,
-    26, 87, 119, 26, 87, 119, 26, 88, 120, 25, 88, 120, 25, 89, 120, 25, 89, 120, 25, 90, 121, 24,
-    90, 121, 24, 91, 122, 23, 91, 122, 23, 92, 122, 23, 92, 122, 23, 93, 123, 22, 93, 123, 22, 94,
-    124, 22, 94, 124, 22, 95, 124, 21, 95, 124, 21, 96, 125, 21, 96, 125, 21, 97, 126, 20, 97, 126,
-    20, 98, 126, 19, 98, 126, 19, 99, 127, 19, 99, 127, 19, 100, 128, 18, 100, 128, 18, 101, 128,
-    18, 101, 128, 18, 103, 129, 17, 103, 129, 17, 104, 130, 16, 104, 130, 16, 105, 130, 16, 105,
-    130, 16, 106, 131, 15, 106, 131, 15, 107, 132, 14, 107, 132, 14, 108, 132, 14, 108, 132, 14,
-    110, 133, 13, 110, 133, 13, 111, 134, 12, 111, 134, 12, 112, 134, 12, 112, 134, 12, 113, 135,
-    11, 113, 135, 11, 115, 136, 10, 115, 136, 10, 116, 136, 9, 116, 136, 9, 117, 137, 9, 117, 137,
-    9, 118, 137, 8, 118, 137, 8, 120, 138, 7, 120, 138, 7, 121, 138, 7, 121, 138, 7, 122, 139, 6,
-    122, 139, 6, 124, 139, 6, 124, 139, 6, 125, 140, 5, 125, 140, 5, 126, 140, 5, 126, 140, 5, 128,
-    141, 4, 128, 141, 4, 129, 141, 4, 129, 141, 4, 130, 141, 4, 130, 141, 4, 131, 142, 3, 131, 142,
-    3, 133, 142, 3, 133, 142, 3, 134, 142, 3, 134, 142, 3, 135, 142, 3, 135, 142, 3, 137, 143, 3,
-    137, 143, 3, 138, 143, 3, 138, 143, 3, 139, 143, 3, 139, 143, 3, 141, 143, 3, 141, 143, 3, 142,
-    144, 3, 142, 144, 3, 143, 144, 3, 143, 144, 3, 145, 144, 4, 145, 144, 4, 146, 145, 4, 146, 145,
-    4, 147, 145, 5, 147, 145, 5, 149, 145, 6, 149, 145, 6, 150, 146, 6, 150, 146, 6, 152, 146, 7,
-    152, 146, 7, 153, 147, 8, 153, 147, 8, 155, 147, 9, 155, 147, 9, 156, 148, 11, 156, 148, 11,
-    158, 149, 12, 158, 149, 12, 160, 149, 13, 160, 149, 13, 161, 150, 15, 161, 150, 15, 163, 151,
-    16, 163, 151, 16, 165, 152, 17, 165, 152, 17, 166, 153, 19, 166, 153, 19, 168, 154, 20, 168,
-    154, 20, 170, 155, 22, 170, 155, 22, 171, 156, 24, 171, 156, 24, 173, 157, 25, 173, 157, 25,
-    175, 158, 27, 175, 158, 27, 176, 159, 28, 176, 159, 28, 178, 160, 30, 178, 160, 30, 180, 161,
-    32, 180, 161, 32, 182, 163, 33, 182, 163, 33, 183, 164, 35, 183, 164, 35, 185, 165, 37, 185,
-    165, 37, 187, 166, 39, 187, 166, 39, 188, 168, 41, 188, 168, 41, 190, 169, 42, 190, 169, 42,
-    192, 170, 44, 192, 170, 44, 193, 171, 46, 193, 171, 46, 195, 173, 48, 195, 173, 48, 197, 174,
-    50, 197, 174, 50, 198, 175, 52, 198, 175, 52, 200, 176, 54, 200, 176, 54, 201, 178, 56, 201,
-    178, 56, 203, 179, 58, 203, 179, 58, 204, 180, 59, 204, 180, 59, 205, 181, 61, 205, 181, 61,
-    207, 183, 63, 207, 183, 63, 208, 184, 65, 208, 184, 65, 209, 185, 67, 209, 185, 67, 211, 186,
-    69, 211, 186, 69, 212, 187, 71, 212, 187, 71, 213, 188, 73, 213, 188, 73, 214, 189, 75, 214,
-    189, 75, 216, 190, 77, 216, 190, 77, 217, 191, 79, 217, 191, 79, 218, 192, 81, 218, 192, 81,
-    219, 193, 82, 219, 193, 82, 220, 194, 84, 220, 194, 84, 221, 195, 86, 221, 195, 86, 222, 196,
-    88, 222, 196, 88, 223, 197, 90, 223, 197, 90, 224, 198, 92, 224, 198, 92, 225, 199, 94, 225,
-    199, 94, 226, 200, 95, 226, 200, 95, 227, 201, 97, 227, 201, 97, 228, 202, 99, 228, 202, 99,
-    229, 203, 101, 229, 203, 101, 230, 204, 103, 230, 204, 103, 231, 205, 104, 231, 205, 104, 231,
-    206, 106, 231, 206, 106, 232, 207, 108, 232, 207, 108, 233, 208, 110, 233, 208, 110, 234, 208,
-    111, 234, 208, 111, 235, 209, 113, 235, 209, 113, 236, 210, 115, 236, 210, 115, 237, 211, 117,
-    237, 211, 117, 238, 212, 118, 238, 212, 118, 239, 213, 120, 239, 213, 120, 239, 214, 122, 239,
-    214, 122, 240, 215, 124, 240, 215, 124, 241, 216, 125, 241, 216, 125, 242, 216, 127, 242, 216,
-    127, 243, 217, 129, 243, 217, 129, 244, 218, 131, 244, 218, 131, 245, 219, 132, 245, 219, 132,
-    246, 220, 134, 246, 220, 134, 246, 221, 136, 246, 221, 136, 247, 222, 137, 247, 222, 137, 248,
-    223, 139, 248, 223, 139, 249, 223, 141, 249, 223, 141, 250, 224, 143, 250, 224, 143, 251, 225,
-    144, 251, 225, 144, 252, 226, 146, 252, 226, 146, 252, 227, 148, 252, 227, 148, 253, 228, 150,
-    253, 228, 150, 254, 229, 151, 254, 229, 151, 255, 229, 153, 255, 229, 153,
-];
+use crate::options::ColormapCategory;
 
-pub const BATLOW: &[u8] = &[
-    1, 25, 89, 1, 25, 89, 2, 27, 89, 2, 27, 89, 3, 28, 90, 3, 28, 90, 4, 30, 90, 4, 30, 90, 5, 31,
-    90, 5, 31, 90, 6, 33, 91, 6, 33, 91, 7, 34, 91, 7, 34, 91, 7, 36, 91, 7, 36, 91, 8, 37, 91, 8,
-    37, 91, 9, 39, 92, 9, 39, 92, 10, 40, 92, 10, 40, 92, 10, 42, 92, 10, 42, 92, 11, 43, 92, 11,
-    43, 92, 11, 45, 93, 11, 45, 93, 12, 46, 93, 12, 46, 93, 12, 47, 93, 12, 47, 93, 13, 49, 93, 13,
-    49, 93, 13, 50, 94, 13, 50, 94, 13, 51, 94, 13, 51, 94, 14, 53, 94, 14, 53, 94, 14, 54, 94, 14,
-    54, 94, 14, 55, 94, 14, 55, 94, 15, 56, 95, 15, 56, 95, 15, 57, 95, 15, 57, 95, 15, 59, 95, 15,
-    59, 95, 15, 60, 95, 15, 60, 95, 16, 61, 95, 16, 61, 95, 16, 62, 95, 16, 62, 95, 16, 63, 96, 16,
-    63, 96, 16, 64, 96, 16, 64, 96, 17, 65, 96, 17, 65, 96, 17, 66, 96, 17, 66, 96, 17, 67, 96, 17,
-    67, 96, 17, 68, 96, 17, 68, 96, 18, 69, 97, 18, 69, 97, 18, 70, 97, 18, 70, 97, 18, 71, 97, 18,
-    71, 97, 18, 72, 97, 18, 72, 97, 19, 73, 97, 19, 73, 97, 19, 74, 97, 19, 74, 97, 19, 75, 97, 19,
-    75, 97, 20, 76, 98, 20, 76, 98, 20, 77, 98, 20, 77, 98, 20, 78, 98, 20, 78, 98, 21, 79, 98, 21,
-    79, 98, 21, 79, 98, 21, 79, 98, 22, 80, 98, 22, 80, 98, 22, 81, 98, 22, 81, 98, 23, 82, 98, 23,
-    82, 98, 23, 83, 98, 23, 83, 98, 24, 84, 98, 24, 84, 98, 24, 85, 98, 24, 85, 98, 25, 86, 98, 25,
-    86, 98, 25, 87, 98, 25, 87, 98, 26, 87, 98, 26, 87, 98, 27, 88, 98, 27, 88, 98, 27, 89, 98, 27,
-    89, 98, 28, 90, 98, 28, 90, 98, 29, 91, 98, 29, 91, 98, 30, 92, 98, 30, 92, 98, 30, 93, 98, 30,
-    93, 98, 31, 93, 97, 31, 93, 97, 32, 94, 97, 32, 94, 97, 33, 95, 97, 33, 95, 97, 34, 96, 97, 34,
-    96, 97, 35, 96, 96, 35, 96, 96, 36, 97, 96, 36, 97, 96, 37, 98, 96, 37, 98, 96, 38, 99, 95, 38,
-    99, 95, 39, 99, 95, 39, 99, 95, 40, 100, 95, 40, 100, 95, 42, 101, 94, 42, 101, 94, 43, 101,
-    94, 43, 101, 94, 44, 102, 93, 44, 102, 93, 45, 103, 93, 45, 103, 93, 47, 103, 92, 47, 103, 92,
-    48, 104, 92, 48, 104, 92, 49, 105, 91, 49, 105, 91, 51, 105, 90, 51, 105, 90, 52, 106, 90, 52,
-    106, 90, 53, 106, 89, 53, 106, 89, 55, 107, 88, 55, 107, 88, 56, 108, 88, 56, 108, 88, 58, 108,
-    87, 58, 108, 87, 59, 109, 86, 59, 109, 86, 60, 109, 86, 60, 109, 86, 62, 110, 85, 62, 110, 85,
-    63, 110, 84, 63, 110, 84, 65, 111, 83, 65, 111, 83, 66, 111, 82, 66, 111, 82, 68, 112, 82, 68,
-    112, 82, 69, 112, 81, 69, 112, 81, 71, 113, 80, 71, 113, 80, 72, 113, 79, 72, 113, 79, 74, 114,
-    78, 74, 114, 78, 76, 114, 77, 76, 114, 77, 77, 115, 77, 77, 115, 77, 79, 115, 76, 79, 115, 76,
-    80, 116, 75, 80, 116, 75, 82, 116, 74, 82, 116, 74, 83, 117, 73, 83, 117, 73, 85, 117, 72, 85,
-    117, 72, 87, 118, 71, 87, 118, 71, 88, 118, 70, 88, 118, 70, 90, 119, 69, 90, 119, 69, 91, 119,
-    69, 91, 119, 69, 93, 120, 68, 93, 120, 68, 95, 120, 67, 95, 120, 67, 96, 121, 66, 96, 121, 66,
-    98, 121, 65, 98, 121, 65, 99, 122, 64, 99, 122, 64, 101, 122, 63, 101, 122, 63, 103, 123, 62,
-    103, 123, 62, 104, 123, 62, 104, 123, 62, 106, 123, 61, 106, 123, 61, 108, 124, 60, 108, 124,
-    60, 109, 124, 59, 109, 124, 59, 111, 125, 58, 111, 125, 58, 113, 125, 57, 113, 125, 57, 115,
-    126, 56, 115, 126, 56, 116, 126, 56, 116, 126, 56, 118, 127, 55, 118, 127, 55, 120, 127, 54,
-    120, 127, 54, 121, 128, 53, 121, 128, 53, 123, 128, 52, 123, 128, 52, 125, 129, 52, 125, 129,
-    52, 127, 129, 51, 127, 129, 51, 129, 130, 50, 129, 130, 50, 130, 130, 49, 130, 130, 49, 132,
-    131, 49, 132, 131, 49, 134, 131, 48, 134, 131, 48, 136, 132, 47, 136, 132, 47, 138, 132, 47,
-    138, 132, 47, 140, 133, 46, 140, 133, 46, 142, 133, 46, 142, 133, 46, 143, 134, 45, 143, 134,
-    45, 145, 134, 45, 145, 134, 45, 147, 135, 44, 147, 135, 44, 149, 135, 44, 149, 135, 44, 151,
-    136, 44, 151, 136, 44, 153, 136, 44, 153, 136, 44, 155, 137, 43, 155, 137, 43, 157, 137, 43,
-    157, 137, 43, 159, 137, 43, 159, 137, 43, 161, 138, 43, 161, 138, 43, 163, 138, 44, 163, 138,
-    44, 165, 139, 44, 165, 139, 44, 167, 139, 44, 167, 139, 44, 169, 140, 44, 169, 140, 44, 171,
-    140, 45, 171, 140, 45, 173, 140, 45, 173, 140, 45, 175, 141, 46, 175, 141, 46, 177, 141, 47,
-    177, 141, 47, 179, 142, 47, 179, 142, 47, 181, 142, 48, 181, 142, 48, 183, 142, 49, 183, 142,
-    49, 185, 143, 50, 185, 143, 50, 187, 143, 51, 187, 143, 51, 189, 143, 52, 189, 143, 52, 190,
-    144, 53, 190, 144, 53, 192, 144, 54, 192, 144, 54, 194, 144, 55, 194, 144, 55, 196, 145, 56,
-    196, 145, 56, 198, 145, 58, 198, 145, 58, 200, 145, 59, 200, 145, 59, 202, 146, 60, 202, 146,
-    60, 203, 146, 62, 203, 146, 62, 205, 146, 63, 205, 146, 63, 207, 147, 64, 207, 147, 64, 209,
-    147, 66, 209, 147, 66, 210, 147, 67, 210, 147, 67, 212, 148, 69, 212, 148, 69, 214, 148, 70,
-    214, 148, 70, 216, 148, 72, 216, 148, 72, 217, 149, 74, 217, 149, 74, 219, 149, 75, 219, 149,
-    75, 221, 149, 77, 221, 149, 77, 222, 150, 79, 222, 150, 79, 224, 150, 81, 224, 150, 81, 225,
-    151, 82, 225, 151, 82, 227, 151, 84, 227, 151, 84, 228, 151, 86, 228, 151, 86, 230, 152, 88,
-    230, 152, 88, 231, 152, 90, 231, 152, 90, 233, 153, 92, 233, 153, 92, 234, 153, 94, 234, 153,
-    94, 235, 154, 96, 235, 154, 96, 237, 154, 98, 237, 154, 98, 238, 155, 100, 238, 155, 100, 239,
-    155, 103, 239, 155, 103, 240, 156, 105, 240, 156, 105, 241, 157, 107, 241, 157, 107, 242, 157,
-    109, 242, 157, 109, 243, 158, 112, 243, 158, 112, 244, 159, 114, 244, 159, 114, 245, 159, 116,
-    245, 159, 116, 246, 160, 119, 246, 160, 119, 247, 161, 121, 247, 161, 121, 248, 161, 123, 248,
-    161, 123, 248, 162, 126, 248, 162, 126, 249, 163, 128, 249, 163, 128, 249, 163, 130, 249, 163,
-    130, 250, 164, 133, 250, 164, 133, 250, 165, 135, 250, 165, 135, 251, 166, 137, 251, 166, 137,
-    251, 166, 140, 251, 166, 140, 252, 167, 142, 252, 167, 142, 252, 168, 144, 252, 168, 144, 252,
-    169, 147, 252, 169, 147, 252, 169, 149, 252, 169, 149, 253, 170, 151, 253, 170, 151, 253, 171,
-    154, 253, 171, 154, 253, 172, 156, 253, 172, 156, 253, 172, 158, 253, 172, 158, 253, 173, 160,
-    253, 173, 160, 253, 174, 162, 253, 174, 162, 253, 175, 165, 253, 175, 165, 253, 175, 167, 253,
-    175, 167, 253, 176, 169, 253, 176, 169, 253, 177, 171, 253, 177, 171, 253, 178, 173, 253, 178,
-    173, 253, 178, 175, 253, 178, 175, 253, 179, 177, 253, 179, 177, 253, 180, 180, 253, 180, 180,
-    253, 180, 182, 253, 180, 182, 253, 181, 184, 253, 181, 184, 253, 182, 186, 253, 182, 186, 253,
-    183, 188, 253, 183, 188, 253, 183, 190, 253, 183, 190, 253, 184, 192, 253, 184, 192, 253, 185,
-    194, 253, 185, 194, 253, 186, 196, 253, 186, 196, 253, 186, 199, 253, 186, 199, 253, 187, 201,
-    253, 187, 201, 253, 188, 203, 253, 188, 203, 253, 188, 205, 253, 188, 205, 252, 189, 207, 252,
-    189, 207, 252, 190, 209, 252, 190, 209, 252, 191, 211, 252, 191, 211, 252, 191, 214, 252, 191,
-    214, 252, 192, 216, 252, 192, 216, 252, 193, 218, 252, 193, 218, 252, 194, 220, 252, 194, 220,
-    252, 195, 223, 252, 195, 223, 252, 195, 225, 252, 195, 225, 252, 196, 227, 252, 196, 227, 252,
-    197, 229, 252, 197, 229, 251, 198, 232, 251, 198, 232, 251, 198, 234, 251, 198, 234, 251, 199,
-    236, 251, 199, 236, 251, 200, 239, 251, 200, 239, 251, 201, 241, 251, 201, 241, 251, 202, 243,
-    251, 202, 243, 251, 202, 246, 251, 202, 246, 250, 203, 248, 250, 203, 248, 250, 204, 250, 250,
-    204, 250,
-];
-
-pub const BERLIN: &[u8] = &[
-    158, 176, 255, 158, 176, 255, 156, 176, 254, 156, 176, 254, 154, 176, 253, 154, 176, 253, 152,
-    175, 252, 152, 175, 252, 149, 175, 251, 149, 175, 251, 147, 175, 250, 147, 175, 250, 145, 174,
-    249, 145, 174, 249, 142, 174, 247, 142, 174, 247, 140, 174, 246, 140, 174, 246, 138, 174, 245,
-    138, 174, 245, 135, 173, 244, 135, 173, 244, 133, 173, 243, 133, 173, 243, 130, 173, 242, 130,
-    173, 242, 128, 172, 241, 128, 172, 241, 126, 172, 240, 126, 172, 240, 123, 172, 238, 123, 172,
-    238, 121, 171, 237, 121, 171, 237, 118, 171, 236, 118, 171, 236, 116, 170, 235, 116, 170, 235,
-    113, 170, 233, 113, 170, 233, 111, 169, 232, 111, 169, 232, 108, 169, 230, 108, 169, 230, 106,
-    168, 229, 106, 168, 229, 103, 168, 227, 103, 168, 227, 101, 167, 226, 101, 167, 226, 98, 166,
-    224, 98, 166, 224, 96, 165, 223, 96, 165, 223, 93, 165, 221, 93, 165, 221, 91, 164, 219, 91,
-    164, 219, 88, 163, 217, 88, 163, 217, 86, 162, 215, 86, 162, 215, 84, 160, 213, 84, 160, 213,
-    81, 159, 211, 81, 159, 211, 79, 158, 209, 79, 158, 209, 77, 157, 207, 77, 157, 207, 75, 155,
-    205, 75, 155, 205, 72, 154, 202, 72, 154, 202, 70, 152, 200, 70, 152, 200, 68, 151, 198, 68,
-    151, 198, 67, 149, 195, 67, 149, 195, 65, 148, 193, 65, 148, 193, 63, 146, 190, 63, 146, 190,
-    62, 144, 188, 62, 144, 188, 60, 142, 185, 60, 142, 185, 59, 141, 183, 59, 141, 183, 57, 139,
-    180, 57, 139, 180, 56, 137, 178, 56, 137, 178, 55, 135, 175, 55, 135, 175, 54, 133, 173, 54,
-    133, 173, 53, 132, 170, 53, 132, 170, 51, 130, 168, 51, 130, 168, 50, 128, 166, 50, 128, 166,
-    50, 126, 163, 50, 126, 163, 49, 124, 161, 49, 124, 161, 48, 122, 158, 48, 122, 158, 47, 120,
-    156, 47, 120, 156, 46, 118, 153, 46, 118, 153, 45, 117, 151, 45, 117, 151, 44, 115, 148, 44,
-    115, 148, 44, 113, 146, 44, 113, 146, 43, 111, 143, 43, 111, 143, 42, 109, 141, 42, 109, 141,
-    41, 107, 139, 41, 107, 139, 41, 105, 136, 41, 105, 136, 40, 104, 134, 40, 104, 134, 39, 102,
-    131, 39, 102, 131, 39, 100, 129, 39, 100, 129, 38, 98, 127, 38, 98, 127, 37, 96, 124, 37, 96,
-    124, 36, 94, 122, 36, 94, 122, 36, 93, 120, 36, 93, 120, 35, 91, 117, 35, 91, 117, 34, 89, 115,
-    34, 89, 115, 34, 87, 113, 34, 87, 113, 33, 85, 110, 33, 85, 110, 32, 84, 108, 32, 84, 108, 32,
-    82, 106, 32, 82, 106, 31, 80, 104, 31, 80, 104, 30, 78, 101, 30, 78, 101, 30, 77, 99, 30, 77,
-    99, 29, 75, 97, 29, 75, 97, 28, 73, 95, 28, 73, 95, 28, 71, 92, 28, 71, 92, 27, 70, 90, 27, 70,
-    90, 26, 68, 88, 26, 68, 88, 26, 66, 86, 26, 66, 86, 25, 65, 83, 25, 65, 83, 25, 63, 81, 25, 63,
-    81, 24, 61, 79, 24, 61, 79, 23, 60, 77, 23, 60, 77, 23, 58, 75, 23, 58, 75, 22, 56, 73, 22, 56,
-    73, 22, 55, 71, 22, 55, 71, 21, 53, 68, 21, 53, 68, 21, 51, 66, 21, 51, 66, 20, 50, 64, 20, 50,
-    64, 20, 48, 62, 20, 48, 62, 19, 47, 60, 19, 47, 60, 19, 45, 58, 19, 45, 58, 18, 44, 56, 18, 44,
-    56, 18, 42, 54, 18, 42, 54, 18, 41, 52, 18, 41, 52, 17, 39, 50, 17, 39, 50, 17, 38, 48, 17, 38,
-    48, 17, 36, 46, 17, 36, 46, 17, 35, 44, 17, 35, 44, 17, 33, 42, 17, 33, 42, 17, 32, 40, 17, 32,
-    40, 16, 31, 38, 16, 31, 38, 16, 29, 37, 16, 29, 37, 16, 28, 35, 16, 28, 35, 17, 27, 33, 17, 27,
-    33, 17, 26, 32, 17, 26, 32, 17, 25, 30, 17, 25, 30, 17, 24, 28, 17, 24, 28, 17, 22, 27, 17, 22,
-    27, 17, 21, 25, 17, 21, 25, 17, 20, 24, 17, 20, 24, 17, 19, 23, 17, 19, 23, 18, 18, 21, 18, 18,
-    21, 18, 18, 20, 18, 18, 20, 19, 17, 18, 19, 17, 18, 20, 16, 17, 20, 16, 17, 20, 15, 16, 20, 15,
-    16, 21, 14, 14, 21, 14, 14, 22, 14, 13, 22, 14, 13, 23, 13, 11, 23, 13, 11, 24, 12, 10, 24, 12,
-    10, 25, 12, 9, 25, 12, 9, 26, 12, 8, 26, 12, 8, 27, 11, 7, 27, 11, 7, 28, 11, 6, 28, 11, 6, 29,
-    11, 5, 29, 11, 5, 30, 11, 4, 30, 11, 4, 32, 11, 4, 32, 11, 4, 33, 11, 3, 33, 11, 3, 34, 12, 2,
-    34, 12, 2, 35, 12, 2, 35, 12, 2, 36, 12, 2, 36, 12, 2, 37, 12, 1, 37, 12, 1, 38, 13, 1, 38, 13,
-    1, 39, 13, 1, 39, 13, 1, 40, 13, 1, 40, 13, 1, 42, 14, 1, 42, 14, 1, 43, 14, 1, 43, 14, 1, 44,
-    14, 0, 44, 14, 0, 45, 14, 0, 45, 14, 0, 47, 14, 0, 47, 14, 0, 48, 15, 0, 48, 15, 0, 49, 15, 0,
-    49, 15, 0, 51, 15, 0, 51, 15, 0, 52, 15, 0, 52, 15, 0, 53, 16, 0, 53, 16, 0, 55, 16, 0, 55, 16,
-    0, 56, 16, 0, 56, 16, 0, 57, 17, 0, 57, 17, 0, 59, 17, 0, 59, 17, 0, 60, 17, 1, 60, 17, 1, 62,
-    18, 1, 62, 18, 1, 63, 18, 1, 63, 18, 1, 65, 18, 1, 65, 18, 1, 66, 19, 1, 66, 19, 1, 68, 19, 1,
-    68, 19, 1, 69, 20, 1, 69, 20, 1, 71, 20, 1, 71, 20, 1, 72, 21, 2, 72, 21, 2, 74, 21, 2, 74, 21,
-    2, 75, 22, 2, 75, 22, 2, 77, 22, 2, 77, 22, 2, 79, 23, 3, 79, 23, 3, 80, 24, 3, 80, 24, 3, 82,
-    24, 4, 82, 24, 4, 84, 25, 5, 84, 25, 5, 86, 26, 5, 86, 26, 5, 87, 27, 6, 87, 27, 6, 89, 28, 7,
-    89, 28, 7, 91, 29, 8, 91, 29, 8, 93, 30, 9, 93, 30, 9, 95, 31, 10, 95, 31, 10, 97, 32, 11, 97,
-    32, 11, 99, 33, 12, 99, 33, 12, 101, 35, 14, 101, 35, 14, 104, 36, 15, 104, 36, 15, 106, 37,
-    16, 106, 37, 16, 108, 39, 17, 108, 39, 17, 110, 40, 19, 110, 40, 19, 112, 42, 20, 112, 42, 20,
-    115, 43, 22, 115, 43, 22, 117, 45, 23, 117, 45, 23, 119, 47, 25, 119, 47, 25, 121, 48, 27, 121,
-    48, 27, 123, 50, 28, 123, 50, 28, 125, 52, 30, 125, 52, 30, 128, 54, 32, 128, 54, 32, 130, 55,
-    34, 130, 55, 34, 132, 57, 36, 132, 57, 36, 134, 59, 38, 134, 59, 38, 136, 61, 40, 136, 61, 40,
-    138, 63, 42, 138, 63, 42, 140, 64, 44, 140, 64, 44, 142, 66, 46, 142, 66, 46, 144, 68, 48, 144,
-    68, 48, 146, 70, 50, 146, 70, 50, 148, 72, 52, 148, 72, 52, 150, 74, 54, 150, 74, 54, 152, 76,
-    57, 152, 76, 57, 154, 77, 59, 154, 77, 59, 156, 79, 61, 156, 79, 61, 158, 81, 63, 158, 81, 63,
-    160, 83, 65, 160, 83, 65, 162, 85, 68, 162, 85, 68, 164, 87, 70, 164, 87, 70, 166, 89, 72, 166,
-    89, 72, 168, 90, 74, 168, 90, 74, 170, 92, 76, 170, 92, 76, 172, 94, 79, 172, 94, 79, 174, 96,
-    81, 174, 96, 81, 176, 98, 83, 176, 98, 83, 178, 100, 85, 178, 100, 85, 180, 102, 88, 180, 102,
-    88, 182, 104, 90, 182, 104, 90, 184, 106, 92, 184, 106, 92, 186, 107, 95, 186, 107, 95, 188,
-    109, 97, 188, 109, 97, 190, 111, 99, 190, 111, 99, 192, 113, 101, 192, 113, 101, 194, 115, 104,
-    194, 115, 104, 196, 117, 106, 196, 117, 106, 198, 119, 108, 198, 119, 108, 200, 121, 111, 200,
-    121, 111, 202, 123, 113, 202, 123, 113, 204, 125, 115, 204, 125, 115, 206, 127, 118, 206, 127,
-    118, 208, 129, 120, 208, 129, 120, 210, 131, 122, 210, 131, 122, 213, 133, 125, 213, 133, 125,
-    215, 135, 127, 215, 135, 127, 217, 137, 130, 217, 137, 130, 219, 139, 132, 219, 139, 132, 221,
-    141, 134, 221, 141, 134, 223, 143, 137, 223, 143, 137, 225, 145, 139, 225, 145, 139, 227, 147,
-    142, 227, 147, 142, 229, 149, 144, 229, 149, 144, 231, 151, 146, 231, 151, 146, 234, 153, 149,
-    234, 153, 149, 236, 155, 151, 236, 155, 151, 238, 157, 154, 238, 157, 154, 240, 159, 156, 240,
-    159, 156, 242, 161, 159, 242, 161, 159, 244, 163, 161, 244, 163, 161, 246, 165, 163, 246, 165,
-    163, 249, 167, 166, 249, 167, 166, 251, 169, 168, 251, 169, 168, 253, 171, 171, 253, 171, 171,
-    255, 173, 173, 255, 173, 173,
-];
-
-pub const BILBAO: &[u8] = &[
-    255, 255, 255, 255, 255, 255, 254, 254, 254, 254, 254, 254, 252, 252, 252, 252, 252, 252, 251,
-    251, 251, 251, 251, 251, 250, 250, 250, 250, 250, 250, 248, 248, 248, 248, 248, 248, 247, 247,
-    247, 247, 247, 247, 246, 246, 246, 246, 246, 246, 245, 245, 244, 245, 245, 244, 243, 243, 243,
-    243, 243, 243, 242, 242, 242, 242, 242, 242, 241, 241, 240, 241, 241, 240, 239, 239, 239, 239,
-    239, 239, 238, 238, 238, 238, 238, 238, 237, 237, 236, 237, 237, 236, 236, 236, 235, 236, 236,
-    235, 234, 234, 234, 234, 234, 234, 233, 233, 232, 233, 233, 232, 232, 232, 231, 232, 232, 231,
-    231, 230, 230, 231, 230, 230, 229, 229, 228, 229, 229, 228, 228, 228, 227, 228, 228, 227, 227,
-    227, 225, 227, 227, 225, 226, 225, 224, 226, 225, 224, 225, 224, 223, 225, 224, 223, 223, 223,
-    221, 223, 223, 221, 222, 222, 220, 222, 222, 220, 221, 220, 218, 221, 220, 218, 220, 219, 217,
-    220, 219, 217, 219, 218, 216, 219, 218, 216, 218, 217, 214, 218, 217, 214, 217, 216, 213, 217,
-    216, 213, 215, 215, 211, 215, 215, 211, 214, 213, 210, 214, 213, 210, 213, 212, 208, 213, 212,
-    208, 212, 211, 207, 212, 211, 207, 211, 210, 205, 211, 210, 205, 210, 209, 204, 210, 209, 204,
-    210, 208, 202, 210, 208, 202, 209, 207, 201, 209, 207, 201, 208, 206, 199, 208, 206, 199, 207,
-    205, 198, 207, 205, 198, 206, 204, 196, 206, 204, 196, 205, 203, 195, 205, 203, 195, 205, 202,
-    194, 205, 202, 194, 204, 201, 192, 204, 201, 192, 203, 200, 191, 203, 200, 191, 202, 200, 189,
-    202, 200, 189, 202, 199, 188, 202, 199, 188, 201, 198, 186, 201, 198, 186, 201, 197, 185, 201,
-    197, 185, 200, 196, 184, 200, 196, 184, 199, 196, 182, 199, 196, 182, 199, 195, 181, 199, 195,
-    181, 198, 194, 179, 198, 194, 179, 198, 194, 178, 198, 194, 178, 197, 193, 177, 197, 193, 177,
-    197, 192, 175, 197, 192, 175, 196, 192, 174, 196, 192, 174, 196, 191, 173, 196, 191, 173, 195,
-    190, 171, 195, 190, 171, 195, 190, 170, 195, 190, 170, 194, 189, 169, 194, 189, 169, 194, 188,
-    167, 194, 188, 167, 194, 188, 166, 194, 188, 166, 193, 187, 165, 193, 187, 165, 193, 187, 163,
-    193, 187, 163, 192, 186, 162, 192, 186, 162, 192, 185, 161, 192, 185, 161, 192, 185, 159, 192,
-    185, 159, 191, 184, 158, 191, 184, 158, 191, 184, 157, 191, 184, 157, 190, 183, 156, 190, 183,
-    156, 190, 183, 154, 190, 183, 154, 190, 182, 153, 190, 182, 153, 189, 181, 152, 189, 181, 152,
-    189, 181, 150, 189, 181, 150, 189, 180, 149, 189, 180, 149, 188, 179, 148, 188, 179, 148, 188,
-    179, 146, 188, 179, 146, 187, 178, 145, 187, 178, 145, 187, 177, 144, 187, 177, 144, 187, 177,
-    143, 187, 177, 143, 186, 176, 141, 186, 176, 141, 186, 175, 140, 186, 175, 140, 185, 175, 139,
-    185, 175, 139, 185, 174, 137, 185, 174, 137, 185, 173, 136, 185, 173, 136, 184, 172, 135, 184,
-    172, 135, 184, 171, 133, 184, 171, 133, 183, 171, 132, 183, 171, 132, 183, 170, 131, 183, 170,
-    131, 182, 169, 129, 182, 169, 129, 182, 168, 128, 182, 168, 128, 181, 167, 127, 181, 167, 127,
-    181, 166, 126, 181, 166, 126, 181, 165, 124, 181, 165, 124, 180, 164, 123, 180, 164, 123, 180,
-    163, 122, 180, 163, 122, 179, 162, 121, 179, 162, 121, 179, 161, 120, 179, 161, 120, 178, 160,
-    119, 178, 160, 119, 178, 159, 118, 178, 159, 118, 178, 158, 117, 178, 158, 117, 177, 157, 116,
-    177, 157, 116, 177, 156, 115, 177, 156, 115, 177, 155, 114, 177, 155, 114, 176, 154, 113, 176,
-    154, 113, 176, 153, 112, 176, 153, 112, 175, 152, 112, 175, 152, 112, 175, 151, 111, 175, 151,
-    111, 175, 150, 110, 175, 150, 110, 174, 149, 109, 174, 149, 109, 174, 148, 109, 174, 148, 109,
-    174, 147, 108, 174, 147, 108, 173, 146, 108, 173, 146, 108, 173, 145, 107, 173, 145, 107, 173,
-    144, 106, 173, 144, 106, 172, 144, 106, 172, 144, 106, 172, 143, 105, 172, 143, 105, 172, 142,
-    105, 172, 142, 105, 172, 141, 104, 172, 141, 104, 171, 140, 104, 171, 140, 104, 171, 139, 104,
-    171, 139, 104, 171, 138, 103, 171, 138, 103, 171, 137, 103, 171, 137, 103, 170, 136, 102, 170,
-    136, 102, 170, 135, 102, 170, 135, 102, 170, 134, 101, 170, 134, 101, 169, 134, 101, 169, 134,
-    101, 169, 133, 101, 169, 133, 101, 169, 132, 100, 169, 132, 100, 169, 131, 100, 169, 131, 100,
-    168, 130, 99, 168, 130, 99, 168, 129, 99, 168, 129, 99, 168, 128, 99, 168, 128, 99, 168, 127,
-    98, 168, 127, 98, 167, 126, 98, 167, 126, 98, 167, 126, 98, 167, 126, 98, 167, 125, 97, 167,
-    125, 97, 167, 124, 97, 167, 124, 97, 166, 123, 96, 166, 123, 96, 166, 122, 96, 166, 122, 96,
-    166, 121, 96, 166, 121, 96, 166, 120, 95, 166, 120, 95, 165, 119, 95, 165, 119, 95, 165, 119,
-    94, 165, 119, 94, 165, 118, 94, 165, 118, 94, 165, 117, 94, 165, 117, 94, 164, 116, 93, 164,
-    116, 93, 164, 115, 93, 164, 115, 93, 164, 114, 93, 164, 114, 93, 164, 113, 92, 164, 113, 92,
-    163, 112, 92, 163, 112, 92, 163, 112, 91, 163, 112, 91, 163, 111, 91, 163, 111, 91, 163, 110,
-    91, 163, 110, 91, 162, 109, 90, 162, 109, 90, 162, 108, 90, 162, 108, 90, 162, 107, 89, 162,
-    107, 89, 162, 106, 89, 162, 106, 89, 161, 105, 89, 161, 105, 89, 161, 104, 88, 161, 104, 88,
-    161, 104, 88, 161, 104, 88, 160, 103, 87, 160, 103, 87, 160, 102, 87, 160, 102, 87, 160, 101,
-    87, 160, 101, 87, 159, 100, 86, 159, 100, 86, 159, 99, 86, 159, 99, 86, 159, 98, 85, 159, 98,
-    85, 158, 97, 85, 158, 97, 85, 158, 96, 84, 158, 96, 84, 158, 95, 84, 158, 95, 84, 157, 94, 83,
-    157, 94, 83, 157, 93, 83, 157, 93, 83, 157, 92, 82, 157, 92, 82, 156, 91, 82, 156, 91, 82, 156,
-    90, 81, 156, 90, 81, 155, 89, 81, 155, 89, 81, 155, 88, 80, 155, 88, 80, 154, 87, 79, 154, 87,
-    79, 154, 86, 79, 154, 86, 79, 153, 85, 78, 153, 85, 78, 153, 84, 77, 153, 84, 77, 152, 83, 77,
-    152, 83, 77, 151, 82, 76, 151, 82, 76, 151, 81, 75, 151, 81, 75, 150, 80, 75, 150, 80, 75, 150,
-    79, 74, 150, 79, 74, 149, 78, 73, 149, 78, 73, 148, 77, 72, 148, 77, 72, 147, 75, 71, 147, 75,
-    71, 147, 74, 71, 147, 74, 71, 146, 73, 70, 146, 73, 70, 145, 72, 69, 145, 72, 69, 144, 71, 68,
-    144, 71, 68, 143, 70, 67, 143, 70, 67, 142, 69, 66, 142, 69, 66, 141, 67, 65, 141, 67, 65, 140,
-    66, 64, 140, 66, 64, 139, 65, 63, 139, 65, 63, 139, 64, 62, 139, 64, 62, 138, 63, 61, 138, 63,
-    61, 137, 62, 60, 137, 62, 60, 136, 60, 59, 136, 60, 59, 135, 59, 58, 135, 59, 58, 133, 58, 57,
-    133, 58, 57, 132, 57, 56, 132, 57, 56, 131, 56, 55, 131, 56, 55, 130, 55, 54, 130, 55, 54, 129,
-    54, 52, 129, 54, 52, 128, 52, 51, 128, 52, 51, 127, 51, 50, 127, 51, 50, 126, 50, 49, 126, 50,
-    49, 125, 49, 48, 125, 49, 48, 124, 48, 47, 124, 48, 47, 123, 47, 46, 123, 47, 46, 122, 46, 45,
-    122, 46, 45, 120, 44, 44, 120, 44, 44, 119, 43, 43, 119, 43, 43, 118, 42, 42, 118, 42, 42, 117,
-    41, 40, 117, 41, 40, 116, 40, 39, 116, 40, 39, 115, 39, 38, 115, 39, 38, 114, 38, 37, 114, 38,
-    37, 112, 37, 36, 112, 37, 36, 111, 35, 35, 111, 35, 35, 110, 34, 34, 110, 34, 34, 109, 33, 33,
-    109, 33, 33, 108, 32, 32, 108, 32, 32, 107, 31, 31, 107, 31, 31, 105, 30, 30, 105, 30, 30, 104,
-    29, 29, 104, 29, 29, 103, 28, 28, 103, 28, 28, 102, 26, 27, 102, 26, 27, 101, 25, 26, 101, 25,
-    26, 100, 24, 25, 100, 24, 25, 98, 23, 24, 98, 23, 24, 97, 22, 23, 97, 22, 23, 96, 21, 22, 96,
-    21, 22, 95, 19, 21, 95, 19, 21, 93, 18, 20, 93, 18, 20, 92, 17, 19, 92, 17, 19, 91, 16, 18, 91,
-    16, 18, 90, 14, 17, 90, 14, 17, 89, 13, 16, 89, 13, 16, 87, 12, 15, 87, 12, 15, 86, 10, 13, 86,
-    10, 13, 85, 9, 12, 85, 9, 12, 84, 7, 11, 84, 7, 11, 83, 6, 9, 83, 6, 9, 81, 5, 8, 81, 5, 8, 80,
-    4, 6, 80, 4, 6, 79, 3, 4, 79, 3, 4, 78, 1, 3, 78, 1, 3, 77, 0, 1, 77, 0, 1,
-];
-
-pub const BROC: &[u8] = &[
-    44, 26, 76, 44, 26, 76, 44, 27, 78, 44, 27, 78, 44, 29, 79, 44, 29, 79, 43, 30, 81, 43, 30, 81,
-    43, 32, 82, 43, 32, 82, 43, 33, 84, 43, 33, 84, 43, 35, 85, 43, 35, 85, 43, 36, 87, 43, 36, 87,
-    43, 38, 88, 43, 38, 88, 43, 39, 90, 43, 39, 90, 43, 41, 91, 43, 41, 91, 43, 42, 93, 43, 42, 93,
-    42, 44, 94, 42, 44, 94, 42, 45, 96, 42, 45, 96, 42, 46, 97, 42, 46, 97, 42, 48, 99, 42, 48, 99,
-    42, 49, 100, 42, 49, 100, 42, 51, 102, 42, 51, 102, 41, 53, 103, 41, 53, 103, 41, 54, 105, 41,
-    54, 105, 41, 56, 106, 41, 56, 106, 41, 57, 108, 41, 57, 108, 41, 59, 109, 41, 59, 109, 41, 60,
-    111, 41, 60, 111, 41, 62, 113, 41, 62, 113, 40, 63, 114, 40, 63, 114, 40, 65, 116, 40, 65, 116,
-    40, 67, 117, 40, 67, 117, 40, 68, 119, 40, 68, 119, 40, 70, 120, 40, 70, 120, 40, 71, 122, 40,
-    71, 122, 41, 73, 124, 41, 73, 124, 41, 75, 125, 41, 75, 125, 41, 76, 127, 41, 76, 127, 41, 78,
-    128, 41, 78, 128, 42, 80, 130, 42, 80, 130, 42, 81, 131, 42, 81, 131, 43, 83, 133, 43, 83, 133,
-    44, 85, 134, 44, 85, 134, 45, 87, 136, 45, 87, 136, 46, 88, 137, 46, 88, 137, 47, 90, 139, 47,
-    90, 139, 48, 92, 140, 48, 92, 140, 49, 94, 142, 49, 94, 142, 51, 95, 143, 51, 95, 143, 52, 97,
-    145, 52, 97, 145, 54, 99, 146, 54, 99, 146, 55, 101, 147, 55, 101, 147, 57, 102, 149, 57, 102,
-    149, 59, 104, 150, 59, 104, 150, 61, 106, 151, 61, 106, 151, 63, 107, 153, 63, 107, 153, 65,
-    109, 154, 65, 109, 154, 67, 111, 155, 67, 111, 155, 69, 113, 156, 69, 113, 156, 71, 114, 158,
-    71, 114, 158, 73, 116, 159, 73, 116, 159, 75, 118, 160, 75, 118, 160, 77, 120, 161, 77, 120,
-    161, 80, 121, 163, 80, 121, 163, 82, 123, 164, 82, 123, 164, 84, 125, 165, 84, 125, 165, 86,
-    127, 166, 86, 127, 166, 88, 128, 167, 88, 128, 167, 91, 130, 169, 91, 130, 169, 93, 132, 170,
-    93, 132, 170, 95, 133, 171, 95, 133, 171, 97, 135, 172, 97, 135, 172, 100, 137, 173, 100, 137,
-    173, 102, 139, 175, 102, 139, 175, 104, 140, 176, 104, 140, 176, 107, 142, 177, 107, 142, 177,
-    109, 144, 178, 109, 144, 178, 111, 146, 179, 111, 146, 179, 113, 147, 181, 113, 147, 181, 116,
-    149, 182, 116, 149, 182, 118, 151, 183, 118, 151, 183, 120, 153, 184, 120, 153, 184, 123, 155,
-    186, 123, 155, 186, 125, 156, 187, 125, 156, 187, 127, 158, 188, 127, 158, 188, 130, 160, 189,
-    130, 160, 189, 132, 162, 190, 132, 162, 190, 134, 163, 192, 134, 163, 192, 137, 165, 193, 137,
-    165, 193, 139, 167, 194, 139, 167, 194, 141, 169, 195, 141, 169, 195, 144, 170, 197, 144, 170,
-    197, 146, 172, 198, 146, 172, 198, 148, 174, 199, 148, 174, 199, 151, 176, 200, 151, 176, 200,
-    153, 178, 202, 153, 178, 202, 155, 179, 203, 155, 179, 203, 158, 181, 204, 158, 181, 204, 160,
-    183, 205, 160, 183, 205, 162, 185, 206, 162, 185, 206, 165, 187, 208, 165, 187, 208, 167, 188,
-    209, 167, 188, 209, 170, 190, 210, 170, 190, 210, 172, 192, 211, 172, 192, 211, 174, 194, 213,
-    174, 194, 213, 177, 196, 214, 177, 196, 214, 179, 197, 215, 179, 197, 215, 181, 199, 216, 181,
-    199, 216, 184, 201, 218, 184, 201, 218, 186, 203, 219, 186, 203, 219, 189, 205, 220, 189, 205,
-    220, 191, 207, 221, 191, 207, 221, 194, 208, 223, 194, 208, 223, 196, 210, 224, 196, 210, 224,
-    198, 212, 225, 198, 212, 225, 201, 214, 226, 201, 214, 226, 203, 216, 228, 203, 216, 228, 206,
-    217, 229, 206, 217, 229, 208, 219, 230, 208, 219, 230, 210, 221, 231, 210, 221, 231, 213, 223,
-    232, 213, 223, 232, 215, 224, 233, 215, 224, 233, 217, 226, 234, 217, 226, 234, 220, 228, 235,
-    220, 228, 235, 222, 229, 236, 222, 229, 236, 224, 231, 236, 224, 231, 236, 226, 232, 237, 226,
-    232, 237, 228, 234, 237, 228, 234, 237, 230, 235, 237, 230, 235, 237, 231, 236, 237, 231, 236,
-    237, 233, 237, 237, 233, 237, 237, 234, 238, 236, 234, 238, 236, 235, 238, 236, 235, 238, 236,
-    236, 239, 235, 236, 239, 235, 237, 239, 233, 237, 239, 233, 237, 239, 232, 237, 239, 232, 238,
-    239, 231, 238, 239, 231, 238, 239, 229, 238, 239, 229, 238, 239, 227, 238, 239, 227, 237, 238,
-    225, 237, 238, 225, 237, 238, 223, 237, 238, 223, 236, 237, 221, 236, 237, 221, 236, 236, 219,
-    236, 236, 219, 235, 235, 217, 235, 235, 217, 234, 234, 214, 234, 234, 214, 233, 233, 212, 233,
-    233, 212, 232, 232, 210, 232, 232, 210, 231, 231, 207, 231, 231, 207, 230, 230, 205, 230, 230,
-    205, 229, 229, 203, 229, 229, 203, 228, 228, 200, 228, 228, 200, 227, 226, 198, 227, 226, 198,
-    225, 225, 196, 225, 225, 196, 224, 224, 193, 224, 224, 193, 223, 223, 191, 223, 223, 191, 222,
-    222, 189, 222, 222, 189, 221, 221, 186, 221, 221, 186, 220, 219, 184, 220, 219, 184, 218, 218,
-    181, 218, 218, 181, 217, 217, 179, 217, 217, 179, 216, 216, 177, 216, 216, 177, 215, 215, 174,
-    215, 215, 174, 213, 213, 172, 213, 213, 172, 212, 212, 170, 212, 212, 170, 211, 211, 167, 211,
-    211, 167, 210, 210, 165, 210, 210, 165, 208, 208, 162, 208, 208, 162, 207, 207, 160, 207, 207,
-    160, 206, 206, 158, 206, 206, 158, 204, 204, 155, 204, 204, 155, 203, 203, 153, 203, 203, 153,
-    201, 201, 150, 201, 201, 150, 200, 200, 148, 200, 200, 148, 198, 198, 145, 198, 198, 145, 197,
-    197, 143, 197, 197, 143, 195, 195, 140, 195, 195, 140, 193, 193, 138, 193, 193, 138, 192, 191,
-    135, 192, 191, 135, 190, 190, 133, 190, 190, 133, 188, 188, 131, 188, 188, 131, 186, 186, 128,
-    186, 186, 128, 184, 184, 126, 184, 184, 126, 182, 182, 124, 182, 182, 124, 180, 180, 121, 180,
-    180, 121, 178, 178, 119, 178, 178, 119, 176, 176, 117, 176, 176, 117, 174, 174, 115, 174, 174,
-    115, 172, 172, 113, 172, 172, 113, 170, 170, 111, 170, 170, 111, 168, 168, 109, 168, 168, 109,
-    166, 166, 107, 166, 166, 107, 163, 163, 105, 163, 163, 105, 161, 161, 103, 161, 161, 103, 159,
-    159, 101, 159, 159, 101, 157, 157, 99, 157, 157, 99, 155, 155, 98, 155, 155, 98, 153, 153, 96,
-    153, 153, 96, 151, 151, 94, 151, 151, 94, 149, 149, 92, 149, 149, 92, 147, 147, 91, 147, 147,
-    91, 145, 145, 89, 145, 145, 89, 143, 143, 87, 143, 143, 87, 141, 141, 86, 141, 141, 86, 139,
-    139, 84, 139, 139, 84, 137, 137, 82, 137, 137, 82, 135, 135, 81, 135, 135, 81, 133, 133, 79,
-    133, 133, 79, 131, 131, 77, 131, 131, 77, 129, 129, 76, 129, 129, 76, 127, 127, 74, 127, 127,
-    74, 125, 125, 72, 125, 125, 72, 123, 123, 71, 123, 123, 71, 121, 121, 69, 121, 121, 69, 119,
-    119, 68, 119, 119, 68, 117, 117, 66, 117, 117, 66, 116, 116, 64, 116, 116, 64, 114, 114, 63,
-    114, 114, 63, 112, 112, 61, 112, 112, 61, 110, 110, 60, 110, 110, 60, 108, 108, 58, 108, 108,
-    58, 106, 106, 56, 106, 106, 56, 104, 104, 55, 104, 104, 55, 102, 102, 53, 102, 102, 53, 100,
-    100, 52, 100, 100, 52, 98, 98, 50, 98, 98, 50, 97, 96, 49, 97, 96, 49, 95, 95, 47, 95, 95, 47,
-    93, 93, 45, 93, 93, 45, 91, 91, 44, 91, 91, 44, 89, 89, 42, 89, 89, 42, 87, 87, 41, 87, 87, 41,
-    85, 85, 39, 85, 85, 39, 84, 84, 38, 84, 84, 38, 82, 82, 36, 82, 82, 36, 80, 80, 35, 80, 80, 35,
-    78, 78, 33, 78, 78, 33, 76, 76, 32, 76, 76, 32, 75, 75, 31, 75, 75, 31, 73, 73, 29, 73, 73, 29,
-    71, 71, 28, 71, 71, 28, 69, 69, 26, 69, 69, 26, 67, 67, 25, 67, 67, 25, 66, 66, 24, 66, 66, 24,
-    64, 64, 22, 64, 64, 22, 62, 62, 21, 62, 62, 21, 60, 61, 20, 60, 61, 20, 59, 59, 18, 59, 59, 18,
-    57, 57, 17, 57, 57, 17, 55, 56, 16, 55, 56, 16, 54, 54, 15, 54, 54, 15, 52, 52, 14, 52, 52, 14,
-    50, 51, 12, 50, 51, 12, 49, 49, 11, 49, 49, 11, 47, 48, 10, 47, 48, 10, 46, 46, 8, 46, 46, 8,
-    44, 44, 7, 44, 44, 7, 43, 43, 5, 43, 43, 5, 41, 41, 4, 41, 41, 4, 40, 40, 2, 40, 40, 2, 38, 38,
-    0, 38, 38, 0,
-];
-
-pub const BROCO: &[u8] = &[
-    55, 47, 56, 55, 47, 56, 54, 47, 57, 54, 47, 57, 54, 47, 58, 54, 47, 58, 54, 47, 59, 54, 47, 59,
-    54, 47, 60, 54, 47, 60, 54, 48, 61, 54, 48, 61, 54, 48, 63, 54, 48, 63, 54, 48, 64, 54, 48, 64,
-    54, 48, 65, 54, 48, 65, 54, 49, 67, 54, 49, 67, 54, 49, 68, 54, 49, 68, 54, 50, 70, 54, 50, 70,
-    54, 50, 71, 54, 50, 71, 54, 51, 73, 54, 51, 73, 54, 51, 74, 54, 51, 74, 54, 52, 76, 54, 52, 76,
-    54, 53, 78, 54, 53, 78, 54, 53, 80, 54, 53, 80, 54, 54, 81, 54, 54, 81, 54, 55, 83, 54, 55, 83,
-    54, 56, 85, 54, 56, 85, 54, 57, 87, 54, 57, 87, 55, 58, 89, 55, 58, 89, 55, 59, 91, 55, 59, 91,
-    55, 60, 92, 55, 60, 92, 55, 61, 94, 55, 61, 94, 55, 63, 96, 55, 63, 96, 56, 64, 98, 56, 64, 98,
-    56, 65, 100, 56, 65, 100, 56, 67, 102, 56, 67, 102, 57, 68, 104, 57, 68, 104, 57, 69, 106, 57,
-    69, 106, 58, 71, 108, 58, 71, 108, 58, 72, 111, 58, 72, 111, 59, 74, 113, 59, 74, 113, 59, 76,
-    115, 59, 76, 115, 60, 77, 117, 60, 77, 117, 61, 79, 119, 61, 79, 119, 62, 81, 121, 62, 81, 121,
-    62, 82, 123, 62, 82, 123, 63, 84, 124, 63, 84, 124, 64, 86, 126, 64, 86, 126, 65, 87, 128, 65,
-    87, 128, 66, 89, 130, 66, 89, 130, 67, 91, 132, 67, 91, 132, 68, 93, 134, 68, 93, 134, 69, 94,
-    136, 69, 94, 136, 71, 96, 138, 71, 96, 138, 72, 98, 139, 72, 98, 139, 73, 100, 141, 73, 100,
-    141, 74, 102, 143, 74, 102, 143, 76, 103, 144, 76, 103, 144, 77, 105, 146, 77, 105, 146, 79,
-    107, 148, 79, 107, 148, 80, 109, 149, 80, 109, 149, 82, 111, 151, 82, 111, 151, 83, 112, 153,
-    83, 112, 153, 85, 114, 154, 85, 114, 154, 86, 116, 156, 86, 116, 156, 88, 118, 157, 88, 118,
-    157, 90, 120, 159, 90, 120, 159, 91, 121, 160, 91, 121, 160, 93, 123, 162, 93, 123, 162, 95,
-    125, 163, 95, 125, 163, 97, 127, 165, 97, 127, 165, 99, 129, 166, 99, 129, 166, 100, 130, 167,
-    100, 130, 167, 102, 132, 169, 102, 132, 169, 104, 134, 170, 104, 134, 170, 106, 136, 171, 106,
-    136, 171, 108, 137, 173, 108, 137, 173, 110, 139, 174, 110, 139, 174, 112, 141, 175, 112, 141,
-    175, 114, 143, 177, 114, 143, 177, 116, 144, 178, 116, 144, 178, 118, 146, 179, 118, 146, 179,
-    120, 148, 180, 120, 148, 180, 122, 150, 182, 122, 150, 182, 124, 151, 183, 124, 151, 183, 126,
-    153, 184, 126, 153, 184, 128, 155, 185, 128, 155, 185, 130, 157, 186, 130, 157, 186, 132, 158,
-    187, 132, 158, 187, 134, 160, 189, 134, 160, 189, 136, 162, 190, 136, 162, 190, 138, 163, 191,
-    138, 163, 191, 140, 165, 192, 140, 165, 192, 142, 167, 193, 142, 167, 193, 145, 168, 194, 145,
-    168, 194, 147, 170, 195, 147, 170, 195, 149, 172, 196, 149, 172, 196, 151, 173, 197, 151, 173,
-    197, 153, 175, 198, 153, 175, 198, 155, 177, 199, 155, 177, 199, 157, 178, 200, 157, 178, 200,
-    159, 180, 200, 159, 180, 200, 161, 181, 201, 161, 181, 201, 163, 183, 202, 163, 183, 202, 165,
-    184, 203, 165, 184, 203, 167, 186, 204, 167, 186, 204, 169, 187, 204, 169, 187, 204, 171, 189,
-    205, 171, 189, 205, 173, 190, 205, 173, 190, 205, 175, 192, 206, 175, 192, 206, 177, 193, 206,
-    177, 193, 206, 179, 194, 207, 179, 194, 207, 181, 196, 207, 181, 196, 207, 183, 197, 208, 183,
-    197, 208, 184, 198, 208, 184, 198, 208, 186, 200, 208, 186, 200, 208, 188, 201, 208, 188, 201,
-    208, 189, 202, 208, 189, 202, 208, 191, 203, 208, 191, 203, 208, 193, 204, 208, 193, 204, 208,
-    194, 205, 208, 194, 205, 208, 196, 206, 208, 196, 206, 208, 197, 207, 207, 197, 207, 207, 198,
-    207, 207, 198, 207, 207, 199, 208, 206, 199, 208, 206, 201, 209, 206, 201, 209, 206, 202, 209,
-    205, 202, 209, 205, 203, 210, 204, 203, 210, 204, 203, 210, 203, 203, 210, 203, 204, 211, 203,
-    204, 211, 203, 205, 211, 202, 205, 211, 202, 206, 211, 200, 206, 211, 200, 206, 211, 199, 206,
-    211, 199, 207, 211, 198, 207, 211, 198, 207, 211, 197, 207, 211, 197, 207, 211, 195, 207, 211,
-    195, 207, 211, 194, 207, 211, 194, 207, 211, 192, 207, 211, 192, 207, 211, 190, 207, 211, 190,
-    207, 210, 189, 207, 210, 189, 207, 210, 187, 207, 210, 187, 207, 209, 185, 207, 209, 185, 206,
-    208, 183, 206, 208, 183, 206, 208, 181, 206, 208, 181, 205, 207, 179, 205, 207, 179, 205, 206,
-    177, 205, 206, 177, 204, 205, 175, 204, 205, 175, 203, 204, 173, 203, 204, 173, 202, 203, 171,
-    202, 203, 171, 201, 202, 169, 201, 202, 169, 200, 201, 166, 200, 201, 166, 199, 200, 164, 199,
-    200, 164, 198, 198, 162, 198, 198, 162, 197, 197, 160, 197, 197, 160, 196, 196, 157, 196, 196,
-    157, 194, 194, 155, 194, 194, 155, 193, 193, 153, 193, 193, 153, 191, 191, 150, 191, 191, 150,
-    190, 190, 148, 190, 190, 148, 188, 188, 146, 188, 188, 146, 187, 187, 144, 187, 187, 144, 185,
-    185, 141, 185, 185, 141, 184, 183, 139, 184, 183, 139, 182, 182, 137, 182, 182, 137, 180, 180,
-    134, 180, 180, 134, 179, 178, 132, 179, 178, 132, 177, 176, 130, 177, 176, 130, 175, 175, 127,
-    175, 175, 127, 173, 173, 125, 173, 173, 125, 171, 171, 123, 171, 171, 123, 170, 169, 121, 170,
-    169, 121, 168, 167, 119, 168, 167, 119, 166, 165, 116, 166, 165, 116, 164, 164, 114, 164, 164,
-    114, 162, 162, 112, 162, 162, 112, 160, 160, 110, 160, 160, 110, 158, 158, 108, 158, 158, 108,
-    157, 156, 106, 157, 156, 106, 155, 154, 104, 155, 154, 104, 153, 152, 102, 153, 152, 102, 151,
-    150, 100, 151, 150, 100, 149, 148, 98, 149, 148, 98, 147, 146, 96, 147, 146, 96, 145, 145, 94,
-    145, 145, 94, 143, 143, 92, 143, 143, 92, 141, 141, 90, 141, 141, 90, 139, 139, 89, 139, 139,
-    89, 137, 137, 87, 137, 137, 87, 136, 135, 85, 136, 135, 85, 134, 133, 83, 134, 133, 83, 132,
-    131, 81, 132, 131, 81, 130, 129, 80, 130, 129, 80, 128, 127, 78, 128, 127, 78, 126, 126, 76,
-    126, 126, 76, 124, 124, 75, 124, 124, 75, 123, 122, 73, 123, 122, 73, 121, 120, 72, 121, 120,
-    72, 119, 118, 70, 119, 118, 70, 117, 116, 69, 117, 116, 69, 115, 114, 67, 115, 114, 67, 114,
-    113, 66, 114, 113, 66, 112, 111, 65, 112, 111, 65, 110, 109, 63, 110, 109, 63, 108, 107, 62,
-    108, 107, 62, 107, 105, 61, 107, 105, 61, 105, 104, 59, 105, 104, 59, 103, 102, 58, 103, 102,
-    58, 102, 100, 57, 102, 100, 57, 100, 98, 56, 100, 98, 56, 98, 97, 55, 98, 97, 55, 97, 95, 54,
-    97, 95, 54, 95, 93, 53, 95, 93, 53, 94, 92, 52, 94, 92, 52, 92, 90, 51, 92, 90, 51, 91, 89, 50,
-    91, 89, 50, 89, 87, 49, 89, 87, 49, 88, 85, 48, 88, 85, 48, 86, 84, 47, 86, 84, 47, 85, 82, 46,
-    85, 82, 46, 84, 81, 46, 84, 81, 46, 82, 79, 45, 82, 79, 45, 81, 78, 45, 81, 78, 45, 80, 77, 44,
-    80, 77, 44, 79, 75, 43, 79, 75, 43, 77, 74, 43, 77, 74, 43, 76, 72, 43, 76, 72, 43, 75, 71, 42,
-    75, 71, 42, 74, 70, 42, 74, 70, 42, 73, 69, 42, 73, 69, 42, 72, 67, 41, 72, 67, 41, 71, 66, 41,
-    71, 66, 41, 70, 65, 41, 70, 65, 41, 69, 64, 41, 69, 64, 41, 68, 63, 41, 68, 63, 41, 67, 62, 41,
-    67, 62, 41, 66, 61, 41, 66, 61, 41, 66, 60, 41, 66, 60, 41, 65, 59, 41, 65, 59, 41, 64, 58, 41,
-    64, 58, 41, 63, 57, 41, 63, 57, 41, 63, 56, 41, 63, 56, 41, 62, 56, 42, 62, 56, 42, 62, 55, 42,
-    62, 55, 42, 61, 54, 42, 61, 54, 42, 60, 53, 43, 60, 53, 43, 60, 53, 43, 60, 53, 43, 59, 52, 43,
-    59, 52, 43, 59, 52, 44, 59, 52, 44, 59, 51, 44, 59, 51, 44, 58, 51, 45, 58, 51, 45, 58, 50, 46,
-    58, 50, 46, 57, 50, 46, 57, 50, 46, 57, 49, 47, 57, 49, 47, 57, 49, 48, 57, 49, 48, 56, 48, 48,
-    56, 48, 48, 56, 48, 49, 56, 48, 49, 56, 48, 50, 56, 48, 50, 56, 48, 51, 56, 48, 51, 55, 47, 52,
-    55, 47, 52, 55, 47, 53, 55, 47, 53, 55, 47, 54, 55, 47, 54, 55, 47, 55, 55, 47, 55,
-];
-
-pub const BUDA: &[u8] = &[
-    179, 1, 179, 179, 1, 179, 179, 3, 178, 179, 3, 178, 179, 5, 177, 179, 5, 177, 179, 7, 176, 179,
-    7, 176, 179, 9, 175, 179, 9, 175, 179, 11, 174, 179, 11, 174, 179, 13, 174, 179, 13, 174, 179,
-    15, 173, 179, 15, 173, 179, 17, 172, 179, 17, 172, 179, 18, 171, 179, 18, 171, 179, 20, 170,
-    179, 20, 170, 179, 22, 169, 179, 22, 169, 179, 23, 169, 179, 23, 169, 179, 24, 168, 179, 24,
-    168, 179, 26, 167, 179, 26, 167, 179, 27, 166, 179, 27, 166, 179, 28, 166, 179, 28, 166, 179,
-    30, 165, 179, 30, 165, 179, 31, 164, 179, 31, 164, 179, 32, 164, 179, 32, 164, 179, 33, 163,
-    179, 33, 163, 179, 35, 162, 179, 35, 162, 179, 36, 162, 179, 36, 162, 179, 37, 161, 179, 37,
-    161, 179, 38, 160, 179, 38, 160, 179, 39, 160, 179, 39, 160, 179, 40, 159, 179, 40, 159, 179,
-    41, 159, 179, 41, 159, 179, 43, 158, 179, 43, 158, 179, 44, 158, 179, 44, 158, 179, 45, 157,
-    179, 45, 157, 179, 46, 157, 179, 46, 157, 179, 47, 156, 179, 47, 156, 179, 48, 156, 179, 48,
-    156, 180, 49, 155, 180, 49, 155, 180, 50, 155, 180, 50, 155, 180, 51, 154, 180, 51, 154, 180,
-    52, 154, 180, 52, 154, 180, 53, 153, 180, 53, 153, 180, 54, 153, 180, 54, 153, 181, 55, 152,
-    181, 55, 152, 181, 56, 152, 181, 56, 152, 181, 57, 152, 181, 57, 152, 181, 58, 151, 181, 58,
-    151, 182, 59, 151, 182, 59, 151, 182, 60, 150, 182, 60, 150, 182, 61, 150, 182, 61, 150, 182,
-    62, 150, 182, 62, 150, 183, 63, 149, 183, 63, 149, 183, 64, 149, 183, 64, 149, 183, 65, 149,
-    183, 65, 149, 184, 66, 148, 184, 66, 148, 184, 67, 148, 184, 67, 148, 184, 68, 148, 184, 68,
-    148, 184, 69, 147, 184, 69, 147, 185, 70, 147, 185, 70, 147, 185, 71, 147, 185, 71, 147, 185,
-    72, 146, 185, 72, 146, 186, 73, 146, 186, 73, 146, 186, 74, 146, 186, 74, 146, 186, 75, 145,
-    186, 75, 145, 187, 75, 145, 187, 75, 145, 187, 76, 145, 187, 76, 145, 187, 77, 144, 187, 77,
-    144, 188, 78, 144, 188, 78, 144, 188, 79, 144, 188, 79, 144, 188, 80, 143, 188, 80, 143, 188,
-    81, 143, 188, 81, 143, 189, 82, 143, 189, 82, 143, 189, 83, 143, 189, 83, 143, 189, 84, 142,
-    189, 84, 142, 190, 85, 142, 190, 85, 142, 190, 85, 142, 190, 85, 142, 190, 86, 141, 190, 86,
-    141, 191, 87, 141, 191, 87, 141, 191, 88, 141, 191, 88, 141, 191, 89, 140, 191, 89, 140, 191,
-    90, 140, 191, 90, 140, 192, 91, 140, 192, 91, 140, 192, 92, 140, 192, 92, 140, 192, 92, 139,
-    192, 92, 139, 193, 93, 139, 193, 93, 139, 193, 94, 139, 193, 94, 139, 193, 95, 138, 193, 95,
-    138, 194, 96, 138, 194, 96, 138, 194, 97, 138, 194, 97, 138, 194, 98, 138, 194, 98, 138, 194,
-    99, 137, 194, 99, 137, 195, 99, 137, 195, 99, 137, 195, 100, 137, 195, 100, 137, 195, 101, 136,
-    195, 101, 136, 196, 102, 136, 196, 102, 136, 196, 103, 136, 196, 103, 136, 196, 104, 136, 196,
-    104, 136, 196, 105, 135, 196, 105, 135, 197, 105, 135, 197, 105, 135, 197, 106, 135, 197, 106,
-    135, 197, 107, 134, 197, 107, 134, 198, 108, 134, 198, 108, 134, 198, 109, 134, 198, 109, 134,
-    198, 110, 134, 198, 110, 134, 198, 110, 133, 198, 110, 133, 199, 111, 133, 199, 111, 133, 199,
-    112, 133, 199, 112, 133, 199, 113, 132, 199, 113, 132, 199, 114, 132, 199, 114, 132, 200, 115,
-    132, 200, 115, 132, 200, 115, 132, 200, 115, 132, 200, 116, 131, 200, 116, 131, 201, 117, 131,
-    201, 117, 131, 201, 118, 131, 201, 118, 131, 201, 119, 131, 201, 119, 131, 201, 120, 130, 201,
-    120, 130, 202, 121, 130, 202, 121, 130, 202, 121, 130, 202, 121, 130, 202, 122, 129, 202, 122,
-    129, 202, 123, 129, 202, 123, 129, 203, 124, 129, 203, 124, 129, 203, 125, 129, 203, 125, 129,
-    203, 126, 128, 203, 126, 128, 203, 126, 128, 203, 126, 128, 204, 127, 128, 204, 127, 128, 204,
-    128, 128, 204, 128, 128, 204, 129, 127, 204, 129, 127, 204, 130, 127, 204, 130, 127, 205, 131,
-    127, 205, 131, 127, 205, 132, 127, 205, 132, 127, 205, 132, 126, 205, 132, 126, 205, 133, 126,
-    205, 133, 126, 206, 134, 126, 206, 134, 126, 206, 135, 126, 206, 135, 126, 206, 136, 126, 206,
-    136, 126, 206, 137, 125, 206, 137, 125, 207, 138, 125, 207, 138, 125, 207, 138, 125, 207, 138,
-    125, 207, 139, 125, 207, 139, 125, 207, 140, 124, 207, 140, 124, 207, 141, 124, 207, 141, 124,
-    208, 142, 124, 208, 142, 124, 208, 143, 124, 208, 143, 124, 208, 144, 124, 208, 144, 124, 208,
-    145, 123, 208, 145, 123, 209, 145, 123, 209, 145, 123, 209, 146, 123, 209, 146, 123, 209, 147,
-    123, 209, 147, 123, 209, 148, 122, 209, 148, 122, 209, 149, 122, 209, 149, 122, 210, 150, 122,
-    210, 150, 122, 210, 151, 122, 210, 151, 122, 210, 151, 122, 210, 151, 122, 210, 152, 121, 210,
-    152, 121, 210, 153, 121, 210, 153, 121, 211, 154, 121, 211, 154, 121, 211, 155, 121, 211, 155,
-    121, 211, 156, 120, 211, 156, 120, 211, 157, 120, 211, 157, 120, 212, 158, 120, 212, 158, 120,
-    212, 158, 120, 212, 158, 120, 212, 159, 120, 212, 159, 120, 212, 160, 119, 212, 160, 119, 212,
-    161, 119, 212, 161, 119, 213, 162, 119, 213, 162, 119, 213, 163, 119, 213, 163, 119, 213, 164,
-    119, 213, 164, 119, 213, 165, 118, 213, 165, 118, 214, 165, 118, 214, 165, 118, 214, 166, 118,
-    214, 166, 118, 214, 167, 118, 214, 167, 118, 214, 168, 117, 214, 168, 117, 214, 169, 117, 214,
-    169, 117, 215, 170, 117, 215, 170, 117, 215, 171, 117, 215, 171, 117, 215, 172, 117, 215, 172,
-    117, 215, 173, 116, 215, 173, 116, 216, 173, 116, 216, 173, 116, 216, 174, 116, 216, 174, 116,
-    216, 175, 116, 216, 175, 116, 216, 176, 115, 216, 176, 115, 216, 177, 115, 216, 177, 115, 217,
-    178, 115, 217, 178, 115, 217, 179, 115, 217, 179, 115, 217, 180, 115, 217, 180, 115, 217, 181,
-    114, 217, 181, 114, 218, 181, 114, 218, 181, 114, 218, 182, 114, 218, 182, 114, 218, 183, 114,
-    218, 183, 114, 218, 184, 113, 218, 184, 113, 218, 185, 113, 218, 185, 113, 219, 186, 113, 219,
-    186, 113, 219, 187, 113, 219, 187, 113, 219, 188, 113, 219, 188, 113, 219, 189, 112, 219, 189,
-    112, 220, 190, 112, 220, 190, 112, 220, 191, 112, 220, 191, 112, 220, 191, 112, 220, 191, 112,
-    220, 192, 111, 220, 192, 111, 221, 193, 111, 221, 193, 111, 221, 194, 111, 221, 194, 111, 221,
-    195, 111, 221, 195, 111, 221, 196, 110, 221, 196, 110, 221, 197, 110, 221, 197, 110, 222, 198,
-    110, 222, 198, 110, 222, 199, 110, 222, 199, 110, 222, 200, 110, 222, 200, 110, 222, 201, 109,
-    222, 201, 109, 223, 202, 109, 223, 202, 109, 223, 202, 109, 223, 202, 109, 223, 203, 109, 223,
-    203, 109, 223, 204, 108, 223, 204, 108, 224, 205, 108, 224, 205, 108, 224, 206, 108, 224, 206,
-    108, 224, 207, 108, 224, 207, 108, 224, 208, 107, 224, 208, 107, 224, 209, 107, 224, 209, 107,
-    225, 210, 107, 225, 210, 107, 225, 211, 107, 225, 211, 107, 225, 212, 107, 225, 212, 107, 226,
-    213, 106, 226, 213, 106, 226, 214, 106, 226, 214, 106, 226, 215, 106, 226, 215, 106, 226, 216,
-    106, 226, 216, 106, 227, 217, 105, 227, 217, 105, 227, 218, 105, 227, 218, 105, 227, 219, 105,
-    227, 219, 105, 228, 220, 105, 228, 220, 105, 228, 221, 105, 228, 221, 105, 229, 222, 104, 229,
-    222, 104, 229, 223, 104, 229, 223, 104, 229, 224, 104, 229, 224, 104, 230, 225, 104, 230, 225,
-    104, 231, 226, 104, 231, 226, 104, 231, 227, 104, 231, 227, 104, 232, 228, 103, 232, 228, 103,
-    232, 229, 103, 232, 229, 103, 233, 230, 103, 233, 230, 103, 234, 231, 103, 234, 231, 103, 235,
-    232, 103, 235, 232, 103, 235, 233, 103, 235, 233, 103, 236, 235, 103, 236, 235, 103, 237, 236,
-    103, 237, 236, 103, 238, 237, 103, 238, 237, 103, 239, 238, 102, 239, 238, 102, 240, 239, 102,
-    240, 239, 102, 241, 240, 102, 241, 240, 102, 242, 242, 102, 242, 242, 102, 243, 243, 102, 243,
-    243, 102, 244, 244, 102, 244, 244, 102, 246, 245, 102, 246, 245, 102, 247, 246, 102, 247, 246,
-    102, 248, 248, 102, 248, 248, 102, 249, 249, 102, 249, 249, 102, 250, 250, 102, 250, 250, 102,
-    251, 251, 102, 251, 251, 102, 253, 253, 102, 253, 253, 102, 254, 254, 102, 254, 254, 102, 255,
-    255, 102, 255, 255, 102,
-];
-
-pub const CORK: &[u8] = &[
-    44, 26, 76, 44, 26, 76, 44, 27, 78, 44, 27, 78, 44, 29, 79, 44, 29, 79, 44, 30, 81, 44, 30, 81,
-    44, 31, 82, 44, 31, 82, 44, 33, 83, 44, 33, 83, 44, 34, 85, 44, 34, 85, 43, 36, 86, 43, 36, 86,
-    43, 37, 88, 43, 37, 88, 43, 39, 89, 43, 39, 89, 43, 40, 91, 43, 40, 91, 43, 41, 92, 43, 41, 92,
-    43, 43, 93, 43, 43, 93, 43, 44, 95, 43, 44, 95, 43, 46, 96, 43, 46, 96, 43, 47, 98, 43, 47, 98,
-    42, 49, 99, 42, 49, 99, 42, 50, 101, 42, 50, 101, 42, 52, 102, 42, 52, 102, 42, 53, 104, 42,
-    53, 104, 42, 54, 105, 42, 54, 105, 42, 56, 107, 42, 56, 107, 42, 57, 108, 42, 57, 108, 42, 59,
-    110, 42, 59, 110, 42, 60, 111, 42, 60, 111, 42, 62, 113, 42, 62, 113, 42, 63, 114, 42, 63, 114,
-    42, 65, 116, 42, 65, 116, 42, 67, 117, 42, 67, 117, 42, 68, 119, 42, 68, 119, 42, 70, 120, 42,
-    70, 120, 42, 71, 122, 42, 71, 122, 42, 73, 123, 42, 73, 123, 43, 74, 125, 43, 74, 125, 43, 76,
-    126, 43, 76, 126, 43, 78, 128, 43, 78, 128, 44, 79, 129, 44, 79, 129, 45, 81, 131, 45, 81, 131,
-    45, 82, 132, 45, 82, 132, 46, 84, 133, 46, 84, 133, 47, 86, 135, 47, 86, 135, 48, 87, 136, 48,
-    87, 136, 49, 89, 138, 49, 89, 138, 50, 91, 139, 50, 91, 139, 51, 92, 141, 51, 92, 141, 52, 94,
-    142, 52, 94, 142, 54, 96, 143, 54, 96, 143, 55, 98, 145, 55, 98, 145, 57, 99, 146, 57, 99, 146,
-    58, 101, 147, 58, 101, 147, 60, 103, 149, 60, 103, 149, 61, 104, 150, 61, 104, 150, 63, 106,
-    151, 63, 106, 151, 65, 108, 153, 65, 108, 153, 67, 109, 154, 67, 109, 154, 69, 111, 155, 69,
-    111, 155, 70, 113, 156, 70, 113, 156, 72, 114, 158, 72, 114, 158, 74, 116, 159, 74, 116, 159,
-    76, 118, 160, 76, 118, 160, 78, 119, 161, 78, 119, 161, 80, 121, 162, 80, 121, 162, 82, 123,
-    164, 82, 123, 164, 84, 124, 165, 84, 124, 165, 87, 126, 166, 87, 126, 166, 89, 128, 167, 89,
-    128, 167, 91, 129, 168, 91, 129, 168, 93, 131, 169, 93, 131, 169, 95, 133, 171, 95, 133, 171,
-    97, 135, 172, 97, 135, 172, 99, 136, 173, 99, 136, 173, 101, 138, 174, 101, 138, 174, 103, 140,
-    175, 103, 140, 175, 106, 141, 176, 106, 141, 176, 108, 143, 178, 108, 143, 178, 110, 145, 179,
-    110, 145, 179, 112, 146, 180, 112, 146, 180, 114, 148, 181, 114, 148, 181, 117, 150, 182, 117,
-    150, 182, 119, 151, 183, 119, 151, 183, 121, 153, 185, 121, 153, 185, 123, 155, 186, 123, 155,
-    186, 125, 156, 187, 125, 156, 187, 128, 158, 188, 128, 158, 188, 130, 160, 189, 130, 160, 189,
-    132, 161, 190, 132, 161, 190, 134, 163, 192, 134, 163, 192, 136, 165, 193, 136, 165, 193, 139,
-    167, 194, 139, 167, 194, 141, 168, 195, 141, 168, 195, 143, 170, 196, 143, 170, 196, 145, 172,
-    197, 145, 172, 197, 148, 173, 199, 148, 173, 199, 150, 175, 200, 150, 175, 200, 152, 177, 201,
-    152, 177, 201, 154, 179, 202, 154, 179, 202, 157, 180, 203, 157, 180, 203, 159, 182, 205, 159,
-    182, 205, 161, 184, 206, 161, 184, 206, 163, 185, 207, 163, 185, 207, 166, 187, 208, 166, 187,
-    208, 168, 189, 209, 168, 189, 209, 170, 191, 211, 170, 191, 211, 172, 192, 212, 172, 192, 212,
-    175, 194, 213, 175, 194, 213, 177, 196, 214, 177, 196, 214, 179, 198, 215, 179, 198, 215, 182,
-    199, 216, 182, 199, 216, 184, 201, 218, 184, 201, 218, 186, 203, 219, 186, 203, 219, 188, 204,
-    220, 188, 204, 220, 191, 206, 221, 191, 206, 221, 193, 208, 222, 193, 208, 222, 195, 210, 223,
-    195, 210, 223, 197, 211, 224, 197, 211, 224, 199, 213, 225, 199, 213, 225, 201, 215, 226, 201,
-    215, 226, 203, 216, 227, 203, 216, 227, 205, 218, 228, 205, 218, 228, 207, 220, 228, 207, 220,
-    228, 209, 221, 229, 209, 221, 229, 211, 223, 229, 211, 223, 229, 213, 224, 230, 213, 224, 230,
-    214, 225, 230, 214, 225, 230, 215, 226, 230, 215, 226, 230, 216, 228, 230, 216, 228, 230, 217,
-    229, 230, 217, 229, 230, 218, 229, 229, 218, 229, 229, 219, 230, 229, 219, 230, 229, 219, 231,
-    228, 219, 231, 228, 219, 231, 227, 219, 231, 227, 219, 231, 226, 219, 231, 226, 218, 231, 225,
-    218, 231, 225, 218, 231, 223, 218, 231, 223, 217, 231, 222, 217, 231, 222, 216, 231, 220, 216,
-    231, 220, 214, 230, 219, 214, 230, 219, 213, 230, 217, 213, 230, 217, 212, 229, 215, 212, 229,
-    215, 210, 228, 213, 210, 228, 213, 208, 227, 211, 208, 227, 211, 207, 226, 209, 207, 226, 209,
-    205, 225, 207, 205, 225, 207, 203, 224, 206, 203, 224, 206, 201, 223, 204, 201, 223, 204, 199,
-    222, 202, 199, 222, 202, 197, 221, 200, 197, 221, 200, 195, 220, 198, 195, 220, 198, 193, 219,
-    196, 193, 219, 196, 191, 218, 194, 191, 218, 194, 189, 216, 192, 189, 216, 192, 187, 215, 190,
-    187, 215, 190, 185, 214, 188, 185, 214, 188, 183, 213, 186, 183, 213, 186, 181, 212, 184, 181,
-    212, 184, 179, 211, 182, 179, 211, 182, 177, 209, 180, 177, 209, 180, 175, 208, 178, 175, 208,
-    178, 173, 207, 176, 173, 207, 176, 171, 206, 174, 171, 206, 174, 169, 205, 172, 169, 205, 172,
-    167, 203, 170, 167, 203, 170, 165, 202, 168, 165, 202, 168, 163, 201, 166, 163, 201, 166, 161,
-    200, 164, 161, 200, 164, 159, 199, 162, 159, 199, 162, 157, 198, 160, 157, 198, 160, 155, 196,
-    158, 155, 196, 158, 153, 195, 156, 153, 195, 156, 151, 194, 154, 151, 194, 154, 149, 193, 153,
-    149, 193, 153, 147, 192, 151, 147, 192, 151, 145, 190, 149, 145, 190, 149, 143, 189, 147, 143,
-    189, 147, 141, 188, 145, 141, 188, 145, 139, 187, 143, 139, 187, 143, 137, 186, 141, 137, 186,
-    141, 135, 185, 139, 135, 185, 139, 133, 183, 137, 133, 183, 137, 131, 182, 135, 131, 182, 135,
-    129, 181, 133, 129, 181, 133, 127, 180, 131, 127, 180, 131, 125, 179, 129, 125, 179, 129, 123,
-    178, 127, 123, 178, 127, 121, 176, 125, 121, 176, 125, 119, 175, 124, 119, 175, 124, 117, 174,
-    122, 117, 174, 122, 115, 173, 120, 115, 173, 120, 113, 172, 118, 113, 172, 118, 111, 170, 116,
-    111, 170, 116, 109, 169, 114, 109, 169, 114, 107, 168, 112, 107, 168, 112, 105, 167, 110, 105,
-    167, 110, 103, 165, 108, 103, 165, 108, 101, 164, 106, 101, 164, 106, 99, 163, 104, 99, 163,
-    104, 97, 161, 102, 97, 161, 102, 96, 160, 100, 96, 160, 100, 94, 159, 98, 94, 159, 98, 92, 157,
-    96, 92, 157, 96, 90, 156, 93, 90, 156, 93, 88, 155, 91, 88, 155, 91, 86, 153, 89, 86, 153, 89,
-    85, 152, 87, 85, 152, 87, 83, 150, 85, 83, 150, 85, 81, 148, 83, 81, 148, 83, 80, 147, 80, 80,
-    147, 80, 78, 145, 78, 78, 145, 78, 77, 144, 76, 77, 144, 76, 75, 142, 74, 75, 142, 74, 74, 140,
-    72, 74, 140, 72, 73, 139, 69, 73, 139, 69, 72, 137, 67, 72, 137, 67, 71, 135, 65, 71, 135, 65,
-    70, 133, 63, 70, 133, 63, 69, 132, 61, 69, 132, 61, 68, 130, 59, 68, 130, 59, 67, 128, 57, 67,
-    128, 57, 67, 127, 55, 67, 127, 55, 66, 125, 53, 66, 125, 53, 66, 123, 51, 66, 123, 51, 65, 122,
-    49, 65, 122, 49, 65, 120, 48, 65, 120, 48, 65, 118, 46, 65, 118, 46, 65, 117, 44, 65, 117, 44,
-    65, 115, 43, 65, 115, 43, 65, 114, 41, 65, 114, 41, 64, 112, 39, 64, 112, 39, 64, 111, 38, 64,
-    111, 38, 64, 109, 36, 64, 109, 36, 64, 108, 35, 64, 108, 35, 65, 107, 34, 65, 107, 34, 65, 105,
-    32, 65, 105, 32, 65, 104, 31, 65, 104, 31, 65, 102, 29, 65, 102, 29, 65, 101, 28, 65, 101, 28,
-    65, 100, 27, 65, 100, 27, 65, 99, 25, 65, 99, 25, 65, 97, 24, 65, 97, 24, 65, 96, 23, 65, 96,
-    23, 65, 95, 22, 65, 95, 22, 65, 93, 20, 65, 93, 20, 66, 92, 19, 66, 92, 19, 66, 91, 18, 66, 91,
-    18, 66, 90, 16, 66, 90, 16, 66, 88, 15, 66, 88, 15, 66, 87, 14, 66, 87, 14, 66, 86, 12, 66, 86,
-    12, 66, 85, 11, 66, 85, 11, 66, 84, 10, 66, 84, 10, 66, 82, 8, 66, 82, 8, 66, 81, 7, 66, 81, 7,
-    66, 80, 6, 66, 80, 6, 66, 79, 5, 66, 79, 5, 66, 78, 4, 66, 78, 4, 66, 77, 3, 66, 77, 3,
-];
-
-pub const CORKO: &[u8] = &[
-    63, 62, 58, 63, 62, 58, 63, 62, 59, 63, 62, 59, 63, 62, 60, 63, 62, 60, 63, 61, 61, 63, 61, 61,
-    62, 61, 62, 62, 61, 62, 62, 61, 63, 62, 61, 63, 62, 61, 64, 62, 61, 64, 62, 61, 65, 62, 61, 65,
-    62, 61, 66, 62, 61, 66, 62, 61, 67, 62, 61, 67, 62, 61, 68, 62, 61, 68, 62, 61, 69, 62, 61, 69,
-    62, 61, 70, 62, 61, 70, 62, 61, 71, 62, 61, 71, 62, 61, 72, 62, 61, 72, 62, 61, 73, 62, 61, 73,
-    62, 61, 75, 62, 61, 75, 62, 61, 76, 62, 61, 76, 62, 62, 77, 62, 62, 77, 62, 62, 79, 62, 62, 79,
-    62, 63, 80, 62, 63, 80, 62, 63, 82, 62, 63, 82, 62, 64, 83, 62, 64, 83, 62, 64, 85, 62, 64, 85,
-    62, 65, 86, 62, 65, 86, 62, 65, 88, 62, 65, 88, 62, 66, 90, 62, 66, 90, 62, 67, 91, 62, 67, 91,
-    62, 68, 93, 62, 68, 93, 62, 69, 95, 62, 69, 95, 63, 70, 97, 63, 70, 97, 63, 71, 98, 63, 71, 98,
-    63, 72, 100, 63, 72, 100, 64, 73, 102, 64, 73, 102, 64, 74, 104, 64, 74, 104, 64, 75, 106, 64,
-    75, 106, 65, 76, 108, 65, 76, 108, 65, 78, 110, 65, 78, 110, 66, 79, 112, 66, 79, 112, 66, 80,
-    114, 66, 80, 114, 67, 82, 116, 67, 82, 116, 68, 83, 118, 68, 83, 118, 68, 85, 120, 68, 85, 120,
-    69, 86, 122, 69, 86, 122, 70, 88, 124, 70, 88, 124, 71, 89, 126, 71, 89, 126, 71, 91, 128, 71,
-    91, 128, 72, 93, 130, 72, 93, 130, 73, 94, 132, 73, 94, 132, 74, 96, 134, 74, 96, 134, 75, 98,
-    135, 75, 98, 135, 77, 99, 137, 77, 99, 137, 78, 101, 139, 78, 101, 139, 79, 103, 141, 79, 103,
-    141, 80, 105, 143, 80, 105, 143, 81, 106, 145, 81, 106, 145, 83, 108, 146, 83, 108, 146, 84,
-    110, 148, 84, 110, 148, 86, 112, 150, 86, 112, 150, 87, 113, 151, 87, 113, 151, 88, 115, 153,
-    88, 115, 153, 90, 117, 155, 90, 117, 155, 92, 119, 156, 92, 119, 156, 93, 120, 158, 93, 120,
-    158, 95, 122, 159, 95, 122, 159, 96, 124, 161, 96, 124, 161, 98, 126, 162, 98, 126, 162, 100,
-    127, 164, 100, 127, 164, 101, 129, 165, 101, 129, 165, 103, 131, 166, 103, 131, 166, 105, 133,
-    168, 105, 133, 168, 106, 134, 169, 106, 134, 169, 108, 136, 171, 108, 136, 171, 110, 138, 172,
-    110, 138, 172, 112, 140, 173, 112, 140, 173, 114, 141, 174, 114, 141, 174, 115, 143, 176, 115,
-    143, 176, 117, 145, 177, 117, 145, 177, 119, 146, 178, 119, 146, 178, 121, 148, 179, 121, 148,
-    179, 123, 150, 180, 123, 150, 180, 124, 151, 182, 124, 151, 182, 126, 153, 183, 126, 153, 183,
-    128, 155, 184, 128, 155, 184, 130, 156, 185, 130, 156, 185, 132, 158, 186, 132, 158, 186, 134,
-    160, 187, 134, 160, 187, 135, 161, 188, 135, 161, 188, 137, 163, 189, 137, 163, 189, 139, 164,
-    190, 139, 164, 190, 141, 166, 191, 141, 166, 191, 143, 167, 192, 143, 167, 192, 144, 169, 192,
-    144, 169, 192, 146, 171, 193, 146, 171, 193, 148, 172, 194, 148, 172, 194, 150, 174, 195, 150,
-    174, 195, 151, 175, 195, 151, 175, 195, 153, 177, 196, 153, 177, 196, 154, 178, 197, 154, 178,
-    197, 156, 179, 197, 156, 179, 197, 158, 181, 198, 158, 181, 198, 159, 182, 198, 159, 182, 198,
-    161, 184, 199, 161, 184, 199, 162, 185, 199, 162, 185, 199, 163, 186, 199, 163, 186, 199, 165,
-    187, 200, 165, 187, 200, 166, 189, 200, 166, 189, 200, 167, 190, 200, 167, 190, 200, 168, 191,
-    200, 168, 191, 200, 169, 192, 200, 169, 192, 200, 170, 193, 200, 170, 193, 200, 171, 194, 200,
-    171, 194, 200, 172, 195, 200, 172, 195, 200, 173, 196, 200, 173, 196, 200, 174, 197, 199, 174,
-    197, 199, 174, 198, 199, 174, 198, 199, 175, 198, 199, 175, 198, 199, 175, 199, 198, 175, 199,
-    198, 176, 200, 197, 176, 200, 197, 176, 200, 197, 176, 200, 197, 176, 201, 196, 176, 201, 196,
-    176, 201, 195, 176, 201, 195, 176, 202, 194, 176, 202, 194, 176, 202, 193, 176, 202, 193, 176,
-    202, 192, 176, 202, 192, 176, 202, 191, 176, 202, 191, 176, 202, 190, 176, 202, 190, 175, 203,
-    189, 175, 203, 189, 175, 203, 188, 175, 203, 188, 174, 202, 187, 174, 202, 187, 173, 202, 185,
-    173, 202, 185, 173, 202, 184, 173, 202, 184, 172, 202, 182, 172, 202, 182, 171, 202, 181, 171,
-    202, 181, 170, 201, 179, 170, 201, 179, 169, 201, 178, 169, 201, 178, 168, 200, 176, 168, 200,
-    176, 167, 200, 175, 167, 200, 175, 166, 199, 173, 166, 199, 173, 165, 199, 171, 165, 199, 171,
-    163, 198, 170, 163, 198, 170, 162, 197, 168, 162, 197, 168, 161, 196, 166, 161, 196, 166, 159,
-    196, 164, 159, 196, 164, 158, 195, 163, 158, 195, 163, 156, 194, 161, 156, 194, 161, 155, 193,
-    159, 155, 193, 159, 153, 192, 157, 153, 192, 157, 152, 191, 155, 152, 191, 155, 150, 190, 153,
-    150, 190, 153, 149, 189, 151, 149, 189, 151, 147, 188, 149, 147, 188, 149, 145, 187, 147, 145,
-    187, 147, 144, 186, 145, 144, 186, 145, 142, 185, 143, 142, 185, 143, 140, 183, 141, 140, 183,
-    141, 139, 182, 139, 139, 182, 139, 137, 181, 137, 137, 181, 137, 135, 180, 135, 135, 180, 135,
-    134, 178, 133, 134, 178, 133, 132, 177, 131, 132, 177, 131, 130, 176, 129, 130, 176, 129, 128,
-    174, 127, 128, 174, 127, 127, 173, 125, 127, 173, 125, 125, 172, 123, 125, 172, 123, 123, 170,
-    121, 123, 170, 121, 122, 169, 119, 122, 169, 119, 120, 167, 117, 120, 167, 117, 118, 166, 115,
-    118, 166, 115, 117, 164, 113, 117, 164, 113, 115, 163, 111, 115, 163, 111, 113, 161, 108, 113,
-    161, 108, 112, 159, 106, 112, 159, 106, 110, 158, 104, 110, 158, 104, 108, 156, 102, 108, 156,
-    102, 107, 154, 100, 107, 154, 100, 105, 153, 98, 105, 153, 98, 104, 151, 96, 104, 151, 96, 102,
-    149, 94, 102, 149, 94, 101, 148, 92, 101, 148, 92, 99, 146, 90, 99, 146, 90, 98, 144, 88, 98,
-    144, 88, 96, 142, 86, 96, 142, 86, 95, 141, 84, 95, 141, 84, 94, 139, 82, 94, 139, 82, 92, 137,
-    80, 92, 137, 80, 91, 135, 78, 91, 135, 78, 90, 134, 76, 90, 134, 76, 89, 132, 74, 89, 132, 74,
-    87, 130, 73, 87, 130, 73, 86, 128, 71, 86, 128, 71, 85, 127, 69, 85, 127, 69, 84, 125, 68, 84,
-    125, 68, 83, 123, 66, 83, 123, 66, 82, 121, 65, 82, 121, 65, 81, 120, 63, 81, 120, 63, 80, 118,
-    62, 80, 118, 62, 79, 116, 60, 79, 116, 60, 79, 115, 59, 79, 115, 59, 78, 113, 58, 78, 113, 58,
-    77, 111, 56, 77, 111, 56, 76, 110, 55, 76, 110, 55, 76, 108, 54, 76, 108, 54, 75, 107, 53, 75,
-    107, 53, 74, 105, 52, 74, 105, 52, 74, 104, 51, 74, 104, 51, 73, 102, 50, 73, 102, 50, 73, 101,
-    50, 73, 101, 50, 72, 100, 49, 72, 100, 49, 72, 98, 48, 72, 98, 48, 71, 97, 48, 71, 97, 48, 71,
-    96, 47, 71, 96, 47, 70, 94, 47, 70, 94, 47, 70, 93, 46, 70, 93, 46, 70, 92, 46, 70, 92, 46, 69,
-    91, 45, 69, 91, 45, 69, 89, 45, 69, 89, 45, 69, 88, 45, 69, 88, 45, 69, 87, 45, 69, 87, 45, 68,
-    86, 44, 68, 86, 44, 68, 85, 44, 68, 85, 44, 68, 84, 44, 68, 84, 44, 68, 83, 44, 68, 83, 44, 67,
-    82, 44, 67, 82, 44, 67, 81, 44, 67, 81, 44, 67, 80, 44, 67, 80, 44, 67, 79, 44, 67, 79, 44, 67,
-    79, 44, 67, 79, 44, 66, 78, 45, 66, 78, 45, 66, 77, 45, 66, 77, 45, 66, 76, 45, 66, 76, 45, 66,
-    75, 45, 66, 75, 45, 66, 75, 46, 66, 75, 46, 66, 74, 46, 66, 74, 46, 66, 73, 46, 66, 73, 46, 65,
-    72, 47, 65, 72, 47, 65, 72, 47, 65, 72, 47, 65, 71, 47, 65, 71, 47, 65, 71, 48, 65, 71, 48, 65,
-    70, 48, 65, 70, 48, 65, 69, 49, 65, 69, 49, 65, 69, 49, 65, 69, 49, 65, 68, 50, 65, 68, 50, 64,
-    68, 50, 64, 68, 50, 64, 67, 51, 64, 67, 51, 64, 67, 51, 64, 67, 51, 64, 66, 52, 64, 66, 52, 64,
-    66, 52, 64, 66, 52, 64, 65, 53, 64, 65, 53, 64, 65, 54, 64, 65, 54, 64, 64, 54, 64, 64, 54, 63,
-    64, 55, 63, 64, 55, 63, 63, 56, 63, 63, 56, 63, 63, 56, 63, 63, 56, 63, 63, 57, 63, 63, 57, 63,
-    62, 58, 63, 62, 58,
-];
-
-pub const DAVOS: &[u8] = &[
-    0, 5, 74, 0, 5, 74, 0, 7, 76, 0, 7, 76, 0, 9, 77, 0, 9, 77, 0, 11, 79, 0, 11, 79, 1, 12, 80, 1,
-    12, 80, 1, 14, 81, 1, 14, 81, 2, 15, 83, 2, 15, 83, 2, 16, 84, 2, 16, 84, 3, 18, 86, 3, 18, 86,
-    4, 19, 87, 4, 19, 87, 4, 20, 89, 4, 20, 89, 5, 22, 90, 5, 22, 90, 5, 23, 91, 5, 23, 91, 6, 24,
-    93, 6, 24, 93, 7, 26, 94, 7, 26, 94, 7, 27, 96, 7, 27, 96, 8, 28, 97, 8, 28, 97, 9, 30, 98, 9,
-    30, 98, 10, 31, 100, 10, 31, 100, 11, 32, 101, 11, 32, 101, 11, 34, 103, 11, 34, 103, 12, 35,
-    104, 12, 35, 104, 13, 36, 105, 13, 36, 105, 14, 38, 107, 14, 38, 107, 14, 39, 108, 14, 39, 108,
-    15, 40, 109, 15, 40, 109, 16, 42, 111, 16, 42, 111, 17, 43, 112, 17, 43, 112, 17, 44, 113, 17,
-    44, 113, 18, 46, 115, 18, 46, 115, 19, 47, 116, 19, 47, 116, 19, 48, 117, 19, 48, 117, 20, 50,
-    119, 20, 50, 119, 21, 51, 120, 21, 51, 120, 22, 52, 121, 22, 52, 121, 23, 54, 122, 23, 54, 122,
-    23, 55, 124, 23, 55, 124, 24, 56, 125, 24, 56, 125, 25, 58, 126, 25, 58, 126, 26, 59, 127, 26,
-    59, 127, 26, 60, 128, 26, 60, 128, 27, 62, 130, 27, 62, 130, 28, 63, 131, 28, 63, 131, 29, 64,
-    132, 29, 64, 132, 30, 65, 133, 30, 65, 133, 30, 67, 134, 30, 67, 134, 31, 68, 135, 31, 68, 135,
-    32, 69, 136, 32, 69, 136, 33, 71, 137, 33, 71, 137, 34, 72, 138, 34, 72, 138, 35, 73, 139, 35,
-    73, 139, 35, 74, 140, 35, 74, 140, 36, 76, 141, 36, 76, 141, 37, 77, 142, 37, 77, 142, 38, 78,
-    143, 38, 78, 143, 39, 79, 144, 39, 79, 144, 40, 81, 144, 40, 81, 144, 41, 82, 145, 41, 82, 145,
-    42, 83, 146, 42, 83, 146, 42, 84, 147, 42, 84, 147, 43, 86, 147, 43, 86, 147, 44, 87, 148, 44,
-    87, 148, 45, 88, 149, 45, 88, 149, 46, 89, 149, 46, 89, 149, 47, 90, 150, 47, 90, 150, 48, 91,
-    151, 48, 91, 151, 49, 92, 151, 49, 92, 151, 50, 94, 152, 50, 94, 152, 51, 95, 152, 51, 95, 152,
-    52, 96, 153, 52, 96, 153, 53, 97, 153, 53, 97, 153, 54, 98, 154, 54, 98, 154, 55, 99, 154, 55,
-    99, 154, 56, 100, 154, 56, 100, 154, 57, 101, 155, 57, 101, 155, 58, 102, 155, 58, 102, 155,
-    58, 103, 155, 58, 103, 155, 59, 104, 156, 59, 104, 156, 60, 105, 156, 60, 105, 156, 61, 106,
-    156, 61, 106, 156, 62, 107, 156, 62, 107, 156, 63, 108, 157, 63, 108, 157, 64, 109, 157, 64,
-    109, 157, 65, 110, 157, 65, 110, 157, 66, 111, 157, 66, 111, 157, 67, 112, 157, 67, 112, 157,
-    68, 112, 157, 68, 112, 157, 69, 113, 157, 69, 113, 157, 70, 114, 157, 70, 114, 157, 71, 115,
-    157, 71, 115, 157, 72, 116, 157, 72, 116, 157, 73, 117, 157, 73, 117, 157, 74, 118, 157, 74,
-    118, 157, 75, 118, 157, 75, 118, 157, 76, 119, 157, 76, 119, 157, 77, 120, 157, 77, 120, 157,
-    78, 121, 157, 78, 121, 157, 79, 121, 157, 79, 121, 157, 80, 122, 156, 80, 122, 156, 81, 123,
-    156, 81, 123, 156, 82, 124, 156, 82, 124, 156, 83, 124, 156, 83, 124, 156, 84, 125, 156, 84,
-    125, 156, 85, 126, 155, 85, 126, 155, 86, 127, 155, 86, 127, 155, 86, 127, 155, 86, 127, 155,
-    87, 128, 155, 87, 128, 155, 88, 129, 154, 88, 129, 154, 89, 129, 154, 89, 129, 154, 90, 130,
-    154, 90, 130, 154, 91, 131, 153, 91, 131, 153, 92, 131, 153, 92, 131, 153, 93, 132, 153, 93,
-    132, 153, 94, 133, 152, 94, 133, 152, 95, 133, 152, 95, 133, 152, 96, 134, 152, 96, 134, 152,
-    97, 134, 151, 97, 134, 151, 98, 135, 151, 98, 135, 151, 99, 136, 151, 99, 136, 151, 100, 136,
-    150, 100, 136, 150, 101, 137, 150, 101, 137, 150, 101, 138, 149, 101, 138, 149, 102, 138, 149,
-    102, 138, 149, 103, 139, 149, 103, 139, 149, 104, 139, 148, 104, 139, 148, 105, 140, 148, 105,
-    140, 148, 106, 141, 147, 106, 141, 147, 107, 141, 147, 107, 141, 147, 108, 142, 147, 108, 142,
-    147, 109, 142, 146, 109, 142, 146, 110, 143, 146, 110, 143, 146, 111, 144, 145, 111, 144, 145,
-    112, 144, 145, 112, 144, 145, 113, 145, 145, 113, 145, 145, 114, 145, 144, 114, 145, 144, 115,
-    146, 144, 115, 146, 144, 116, 147, 143, 116, 147, 143, 116, 147, 143, 116, 147, 143, 117, 148,
-    143, 117, 148, 143, 118, 149, 142, 118, 149, 142, 119, 149, 142, 119, 149, 142, 120, 150, 141,
-    120, 150, 141, 121, 150, 141, 121, 150, 141, 122, 151, 141, 122, 151, 141, 123, 152, 140, 123,
-    152, 140, 124, 152, 140, 124, 152, 140, 125, 153, 140, 125, 153, 140, 126, 154, 139, 126, 154,
-    139, 127, 154, 139, 127, 154, 139, 128, 155, 139, 128, 155, 139, 129, 156, 138, 129, 156, 138,
-    130, 157, 138, 130, 157, 138, 131, 157, 138, 131, 157, 138, 132, 158, 137, 132, 158, 137, 134,
-    159, 137, 134, 159, 137, 135, 160, 137, 135, 160, 137, 136, 160, 137, 136, 160, 137, 137, 161,
-    136, 137, 161, 136, 138, 162, 136, 138, 162, 136, 139, 163, 136, 139, 163, 136, 140, 163, 136,
-    140, 163, 136, 141, 164, 136, 141, 164, 136, 143, 165, 136, 143, 165, 136, 144, 166, 135, 144,
-    166, 135, 145, 167, 135, 145, 167, 135, 146, 168, 135, 146, 168, 135, 147, 169, 135, 147, 169,
-    135, 149, 170, 135, 149, 170, 135, 150, 171, 135, 150, 171, 135, 151, 172, 135, 151, 172, 135,
-    153, 173, 136, 153, 173, 136, 154, 174, 136, 154, 174, 136, 155, 175, 136, 155, 175, 136, 157,
-    176, 136, 157, 176, 136, 158, 177, 136, 158, 177, 136, 160, 178, 137, 160, 178, 137, 161, 179,
-    137, 161, 179, 137, 163, 180, 137, 163, 180, 137, 164, 182, 138, 164, 182, 138, 166, 183, 138,
-    166, 183, 138, 168, 184, 139, 168, 184, 139, 169, 185, 139, 169, 185, 139, 171, 187, 140, 171,
-    187, 140, 173, 188, 140, 173, 188, 140, 174, 189, 141, 174, 189, 141, 176, 191, 142, 176, 191,
-    142, 178, 192, 143, 178, 192, 143, 180, 193, 144, 180, 193, 144, 182, 195, 145, 182, 195, 145,
-    184, 196, 146, 184, 196, 146, 185, 198, 147, 185, 198, 147, 187, 199, 148, 187, 199, 148, 189,
-    201, 149, 189, 201, 149, 191, 202, 150, 191, 202, 150, 193, 204, 152, 193, 204, 152, 195, 205,
-    153, 195, 205, 153, 197, 207, 155, 197, 207, 155, 199, 208, 156, 199, 208, 156, 201, 210, 158,
-    201, 210, 158, 203, 211, 159, 203, 211, 159, 205, 213, 161, 205, 213, 161, 207, 214, 162, 207,
-    214, 162, 208, 216, 164, 208, 216, 164, 210, 217, 166, 210, 217, 166, 212, 219, 168, 212, 219,
-    168, 214, 220, 169, 214, 220, 169, 216, 222, 171, 216, 222, 171, 217, 223, 173, 217, 223, 173,
-    219, 224, 175, 219, 224, 175, 221, 226, 177, 221, 226, 177, 222, 227, 179, 222, 227, 179, 224,
-    228, 181, 224, 228, 181, 225, 229, 182, 225, 229, 182, 227, 231, 184, 227, 231, 184, 228, 232,
-    186, 228, 232, 186, 230, 233, 188, 230, 233, 188, 231, 234, 190, 231, 234, 190, 232, 235, 192,
-    232, 235, 192, 234, 236, 194, 234, 236, 194, 235, 237, 196, 235, 237, 196, 236, 238, 198, 236,
-    238, 198, 237, 239, 199, 237, 239, 199, 238, 239, 201, 238, 239, 201, 239, 240, 203, 239, 240,
-    203, 240, 241, 205, 240, 241, 205, 241, 242, 207, 241, 242, 207, 242, 242, 208, 242, 242, 208,
-    243, 243, 210, 243, 243, 210, 243, 244, 212, 243, 244, 212, 244, 244, 214, 244, 244, 214, 245,
-    245, 215, 245, 245, 215, 245, 246, 217, 245, 246, 217, 246, 246, 219, 246, 246, 219, 247, 247,
-    221, 247, 247, 221, 247, 247, 222, 247, 247, 222, 248, 248, 224, 248, 248, 224, 248, 248, 225,
-    248, 248, 225, 249, 249, 227, 249, 249, 227, 249, 249, 229, 249, 249, 229, 250, 249, 230, 250,
-    249, 230, 250, 250, 232, 250, 250, 232, 251, 250, 233, 251, 250, 233, 251, 251, 235, 251, 251,
-    235, 251, 251, 236, 251, 251, 236, 252, 251, 238, 252, 251, 238, 252, 252, 240, 252, 252, 240,
-    252, 252, 241, 252, 252, 241, 252, 252, 243, 252, 252, 243, 253, 253, 244, 253, 253, 244, 253,
-    253, 246, 253, 253, 246, 253, 253, 247, 253, 253, 247, 254, 253, 249, 254, 253, 249, 254, 254,
-    250, 254, 254, 250, 254, 254, 252, 254, 254, 252, 254, 254, 253, 254, 254, 253, 254, 254, 254,
-    254, 254, 254,
-];
-
-pub const DEVON: &[u8] = &[
-    44, 26, 76, 44, 26, 76, 44, 27, 77, 44, 27, 77, 44, 28, 78, 44, 28, 78, 43, 28, 79, 43, 28, 79,
-    43, 29, 80, 43, 29, 80, 43, 30, 81, 43, 30, 81, 43, 31, 82, 43, 31, 82, 43, 32, 83, 43, 32, 83,
-    43, 33, 84, 43, 33, 84, 43, 34, 85, 43, 34, 85, 43, 35, 86, 43, 35, 86, 43, 36, 87, 43, 36, 87,
-    43, 37, 88, 43, 37, 88, 43, 38, 88, 43, 38, 88, 43, 39, 89, 43, 39, 89, 42, 40, 90, 42, 40, 90,
-    42, 41, 91, 42, 41, 91, 42, 41, 92, 42, 41, 92, 42, 42, 93, 42, 42, 93, 42, 43, 94, 42, 43, 94,
-    42, 44, 95, 42, 44, 95, 42, 45, 96, 42, 45, 96, 42, 46, 97, 42, 46, 97, 42, 47, 98, 42, 47, 98,
-    41, 48, 99, 41, 48, 99, 41, 49, 100, 41, 49, 100, 41, 50, 101, 41, 50, 101, 41, 51, 102, 41,
-    51, 102, 41, 52, 103, 41, 52, 103, 41, 53, 104, 41, 53, 104, 41, 54, 105, 41, 54, 105, 41, 55,
-    106, 41, 55, 106, 41, 56, 106, 41, 56, 106, 40, 57, 107, 40, 57, 107, 40, 57, 108, 40, 57, 108,
-    40, 58, 109, 40, 58, 109, 40, 59, 110, 40, 59, 110, 40, 60, 111, 40, 60, 111, 40, 61, 112, 40,
-    61, 112, 40, 62, 113, 40, 62, 113, 40, 63, 114, 40, 63, 114, 40, 64, 115, 40, 64, 115, 39, 65,
-    116, 39, 65, 116, 39, 66, 117, 39, 66, 117, 39, 67, 118, 39, 67, 118, 39, 68, 120, 39, 68, 120,
-    39, 69, 121, 39, 69, 121, 39, 70, 122, 39, 70, 122, 39, 71, 123, 39, 71, 123, 39, 72, 124, 39,
-    72, 124, 39, 73, 125, 39, 73, 125, 39, 74, 126, 39, 74, 126, 39, 76, 127, 39, 76, 127, 39, 77,
-    129, 39, 77, 129, 39, 78, 130, 39, 78, 130, 39, 79, 131, 39, 79, 131, 39, 80, 132, 39, 80, 132,
-    39, 81, 134, 39, 81, 134, 39, 82, 135, 39, 82, 135, 39, 83, 136, 39, 83, 136, 40, 84, 138, 40,
-    84, 138, 40, 85, 139, 40, 85, 139, 40, 86, 140, 40, 86, 140, 40, 87, 142, 40, 87, 142, 41, 88,
-    143, 41, 88, 143, 41, 89, 144, 41, 89, 144, 41, 90, 146, 41, 90, 146, 42, 91, 147, 42, 91, 147,
-    42, 92, 149, 42, 92, 149, 43, 93, 150, 43, 93, 150, 43, 93, 152, 43, 93, 152, 44, 94, 153, 44,
-    94, 153, 44, 95, 154, 44, 95, 154, 45, 96, 156, 45, 96, 156, 46, 97, 157, 46, 97, 157, 46, 98,
-    159, 46, 98, 159, 47, 98, 160, 47, 98, 160, 48, 99, 162, 48, 99, 162, 48, 100, 163, 48, 100,
-    163, 49, 101, 164, 49, 101, 164, 50, 101, 166, 50, 101, 166, 50, 102, 167, 50, 102, 167, 51,
-    103, 169, 51, 103, 169, 52, 104, 170, 52, 104, 170, 53, 104, 171, 53, 104, 171, 54, 105, 173,
-    54, 105, 173, 55, 106, 174, 55, 106, 174, 56, 106, 176, 56, 106, 176, 57, 107, 177, 57, 107,
-    177, 58, 108, 178, 58, 108, 178, 59, 109, 180, 59, 109, 180, 60, 109, 181, 60, 109, 181, 61,
-    110, 183, 61, 110, 183, 62, 111, 184, 62, 111, 184, 63, 112, 185, 63, 112, 185, 65, 113, 187,
-    65, 113, 187, 66, 114, 188, 66, 114, 188, 68, 114, 189, 68, 114, 189, 69, 115, 191, 69, 115,
-    191, 71, 116, 192, 71, 116, 192, 72, 117, 193, 72, 117, 193, 74, 118, 195, 74, 118, 195, 76,
-    119, 196, 76, 119, 196, 77, 120, 197, 77, 120, 197, 79, 121, 198, 79, 121, 198, 81, 122, 200,
-    81, 122, 200, 83, 123, 201, 83, 123, 201, 85, 124, 202, 85, 124, 202, 87, 124, 203, 87, 124,
-    203, 89, 125, 204, 89, 125, 204, 91, 126, 205, 91, 126, 205, 93, 127, 206, 93, 127, 206, 95,
-    128, 207, 95, 128, 207, 97, 129, 208, 97, 129, 208, 99, 130, 209, 99, 130, 209, 101, 131, 210,
-    101, 131, 210, 103, 132, 211, 103, 132, 211, 105, 133, 212, 105, 133, 212, 107, 134, 213, 107,
-    134, 213, 109, 135, 214, 109, 135, 214, 111, 136, 215, 111, 136, 215, 113, 136, 216, 113, 136,
-    216, 115, 137, 216, 115, 137, 216, 117, 138, 217, 117, 138, 217, 119, 139, 218, 119, 139, 218,
-    121, 140, 219, 121, 140, 219, 123, 141, 220, 123, 141, 220, 125, 142, 220, 125, 142, 220, 126,
-    143, 221, 126, 143, 221, 128, 143, 222, 128, 143, 222, 130, 144, 223, 130, 144, 223, 132, 145,
-    223, 132, 145, 223, 134, 146, 224, 134, 146, 224, 136, 147, 225, 136, 147, 225, 138, 148, 225,
-    138, 148, 225, 140, 149, 226, 140, 149, 226, 141, 149, 227, 141, 149, 227, 143, 150, 227, 143,
-    150, 227, 145, 151, 228, 145, 151, 228, 147, 152, 229, 147, 152, 229, 149, 153, 229, 149, 153,
-    229, 150, 154, 230, 150, 154, 230, 152, 155, 231, 152, 155, 231, 154, 155, 231, 154, 155, 231,
-    155, 156, 232, 155, 156, 232, 157, 157, 232, 157, 157, 232, 158, 158, 233, 158, 158, 233, 160,
-    159, 233, 160, 159, 233, 162, 160, 234, 162, 160, 234, 163, 161, 234, 163, 161, 234, 164, 162,
-    235, 164, 162, 235, 166, 163, 235, 166, 163, 235, 167, 164, 236, 167, 164, 236, 168, 165, 236,
-    168, 165, 236, 170, 165, 237, 170, 165, 237, 171, 166, 237, 171, 166, 237, 172, 167, 237, 172,
-    167, 237, 173, 168, 238, 173, 168, 238, 174, 169, 238, 174, 169, 238, 175, 170, 238, 175, 170,
-    238, 176, 171, 238, 176, 171, 238, 177, 172, 239, 177, 172, 239, 178, 173, 239, 178, 173, 239,
-    179, 173, 239, 179, 173, 239, 180, 174, 239, 180, 174, 239, 181, 175, 240, 181, 175, 240, 182,
-    176, 240, 182, 176, 240, 183, 177, 240, 183, 177, 240, 184, 178, 240, 184, 178, 240, 185, 179,
-    240, 185, 179, 240, 186, 179, 241, 186, 179, 241, 186, 180, 241, 186, 180, 241, 187, 181, 241,
-    187, 181, 241, 188, 182, 241, 188, 182, 241, 189, 183, 241, 189, 183, 241, 190, 184, 242, 190,
-    184, 242, 190, 185, 242, 190, 185, 242, 191, 185, 242, 191, 185, 242, 192, 186, 242, 192, 186,
-    242, 193, 187, 242, 193, 187, 242, 194, 188, 242, 194, 188, 242, 194, 189, 243, 194, 189, 243,
-    195, 190, 243, 195, 190, 243, 196, 191, 243, 196, 191, 243, 197, 192, 243, 197, 192, 243, 198,
-    192, 243, 198, 192, 243, 198, 193, 243, 198, 193, 243, 199, 194, 244, 199, 194, 244, 200, 195,
-    244, 200, 195, 244, 201, 196, 244, 201, 196, 244, 202, 197, 244, 202, 197, 244, 202, 198, 244,
-    202, 198, 244, 203, 198, 244, 203, 198, 244, 204, 199, 244, 204, 199, 244, 205, 200, 245, 205,
-    200, 245, 206, 201, 245, 206, 201, 245, 206, 202, 245, 206, 202, 245, 207, 203, 245, 207, 203,
-    245, 208, 204, 245, 208, 204, 245, 209, 205, 245, 209, 205, 245, 210, 205, 246, 210, 205, 246,
-    210, 206, 246, 210, 206, 246, 211, 207, 246, 211, 207, 246, 212, 208, 246, 212, 208, 246, 213,
-    209, 246, 213, 209, 246, 214, 210, 246, 214, 210, 246, 214, 211, 247, 214, 211, 247, 215, 212,
-    247, 215, 212, 247, 216, 212, 247, 216, 212, 247, 217, 213, 247, 217, 213, 247, 218, 214, 247,
-    218, 214, 247, 218, 215, 247, 218, 215, 247, 219, 216, 248, 219, 216, 248, 220, 217, 248, 220,
-    217, 248, 221, 218, 248, 221, 218, 248, 222, 219, 248, 222, 219, 248, 222, 220, 248, 222, 220,
-    248, 223, 220, 248, 223, 220, 248, 224, 221, 249, 224, 221, 249, 225, 222, 249, 225, 222, 249,
-    226, 223, 249, 226, 223, 249, 227, 224, 249, 227, 224, 249, 227, 225, 249, 227, 225, 249, 228,
-    226, 250, 228, 226, 250, 229, 227, 250, 229, 227, 250, 230, 228, 250, 230, 228, 250, 231, 228,
-    250, 231, 228, 250, 232, 229, 250, 232, 229, 250, 232, 230, 250, 232, 230, 250, 233, 231, 251,
-    233, 231, 251, 234, 232, 251, 234, 232, 251, 235, 233, 251, 235, 233, 251, 236, 234, 251, 236,
-    234, 251, 237, 235, 251, 237, 235, 251, 237, 236, 251, 237, 236, 251, 238, 237, 252, 238, 237,
-    252, 239, 238, 252, 239, 238, 252, 240, 238, 252, 240, 238, 252, 241, 239, 252, 241, 239, 252,
-    242, 240, 252, 242, 240, 252, 242, 241, 252, 242, 241, 252, 243, 242, 253, 243, 242, 253, 244,
-    243, 253, 244, 243, 253, 245, 244, 253, 245, 244, 253, 246, 245, 253, 246, 245, 253, 247, 246,
-    253, 247, 246, 253, 247, 247, 253, 247, 247, 253, 248, 248, 254, 248, 248, 254, 249, 249, 254,
-    249, 249, 254, 250, 249, 254, 250, 249, 254, 251, 250, 254, 251, 250, 254, 252, 251, 254, 252,
-    251, 254, 252, 252, 254, 252, 252, 254, 253, 253, 255, 253, 253, 255, 254, 254, 255, 254, 254,
-    255, 255, 255, 255, 255, 255, 255,
-];
-
-pub const GRAYC: &[u8] = &[
-    255, 255, 255, 255, 255, 255, 254, 254, 254, 254, 254, 254, 253, 253, 253, 253, 253, 253, 252,
-    252, 252, 252, 252, 252, 250, 250, 250, 250, 250, 250, 249, 249, 249, 249, 249, 249, 248, 248,
-    248, 248, 248, 248, 247, 247, 247, 247, 247, 247, 246, 246, 246, 246, 246, 246, 245, 245, 245,
-    245, 245, 245, 244, 244, 244, 244, 244, 244, 243, 243, 243, 243, 243, 243, 241, 241, 241, 241,
-    241, 241, 240, 240, 240, 240, 240, 240, 239, 239, 239, 239, 239, 239, 238, 238, 238, 238, 238,
-    238, 237, 237, 237, 237, 237, 237, 236, 236, 236, 236, 236, 236, 235, 235, 235, 235, 235, 235,
-    234, 234, 234, 234, 234, 234, 232, 232, 232, 232, 232, 232, 231, 231, 231, 231, 231, 231, 230,
-    230, 230, 230, 230, 230, 229, 229, 229, 229, 229, 229, 228, 228, 228, 228, 228, 228, 227, 227,
-    227, 227, 227, 227, 226, 226, 226, 226, 226, 226, 225, 225, 225, 225, 225, 225, 224, 224, 224,
-    224, 224, 224, 222, 222, 222, 222, 222, 222, 221, 221, 221, 221, 221, 221, 220, 220, 220, 220,
-    220, 220, 219, 219, 219, 219, 219, 219, 218, 218, 218, 218, 218, 218, 217, 217, 217, 217, 217,
-    217, 216, 216, 216, 216, 216, 216, 215, 215, 215, 215, 215, 215, 214, 214, 214, 214, 214, 214,
-    213, 213, 213, 213, 213, 213, 211, 211, 211, 211, 211, 211, 210, 210, 210, 210, 210, 210, 209,
-    209, 209, 209, 209, 209, 208, 208, 208, 208, 208, 208, 207, 207, 207, 207, 207, 207, 206, 206,
-    206, 206, 206, 206, 205, 205, 205, 205, 205, 205, 204, 204, 204, 204, 204, 204, 203, 203, 203,
-    203, 203, 203, 202, 202, 202, 202, 202, 202, 201, 201, 201, 201, 201, 201, 199, 199, 199, 199,
-    199, 199, 198, 198, 198, 198, 198, 198, 197, 197, 197, 197, 197, 197, 196, 196, 196, 196, 196,
-    196, 195, 195, 195, 195, 195, 195, 194, 194, 194, 194, 194, 194, 193, 193, 193, 193, 193, 193,
-    192, 192, 192, 192, 192, 192, 191, 191, 191, 191, 191, 191, 190, 190, 190, 190, 190, 190, 189,
-    189, 189, 189, 189, 189, 188, 188, 188, 188, 188, 188, 186, 186, 186, 186, 186, 186, 185, 185,
-    185, 185, 185, 185, 184, 184, 184, 184, 184, 184, 183, 183, 183, 183, 183, 183, 182, 182, 182,
-    182, 182, 182, 181, 181, 181, 181, 181, 181, 180, 180, 180, 180, 180, 180, 179, 179, 179, 179,
-    179, 179, 178, 178, 178, 178, 178, 178, 177, 177, 177, 177, 177, 177, 176, 176, 176, 176, 176,
-    176, 175, 175, 175, 175, 175, 175, 174, 174, 174, 174, 174, 174, 173, 173, 173, 173, 173, 173,
-    172, 172, 172, 172, 172, 172, 171, 171, 171, 171, 171, 171, 169, 169, 169, 169, 169, 169, 168,
-    168, 168, 168, 168, 168, 167, 167, 167, 167, 167, 167, 166, 166, 166, 166, 166, 166, 165, 165,
-    165, 165, 165, 165, 164, 164, 164, 164, 164, 164, 163, 163, 163, 163, 163, 163, 162, 162, 162,
-    162, 162, 162, 161, 161, 161, 161, 161, 161, 160, 160, 160, 160, 160, 160, 159, 159, 159, 159,
-    159, 159, 158, 158, 158, 158, 158, 158, 157, 157, 157, 157, 157, 157, 156, 156, 156, 156, 156,
-    156, 155, 155, 155, 155, 155, 155, 154, 154, 154, 154, 154, 154, 153, 153, 153, 153, 153, 153,
-    152, 152, 152, 152, 152, 152, 151, 151, 151, 151, 151, 151, 150, 150, 150, 150, 150, 150, 149,
-    149, 149, 149, 149, 149, 148, 148, 148, 148, 148, 148, 147, 147, 147, 147, 147, 147, 146, 146,
-    146, 146, 146, 146, 145, 145, 145, 145, 145, 145, 144, 144, 144, 144, 144, 144, 143, 143, 143,
-    143, 143, 143, 141, 141, 141, 141, 141, 141, 140, 140, 140, 140, 140, 140, 139, 139, 139, 139,
-    139, 139, 138, 138, 138, 138, 138, 138, 137, 137, 137, 137, 137, 137, 136, 136, 136, 136, 136,
-    136, 135, 135, 135, 135, 135, 135, 134, 134, 134, 134, 134, 134, 133, 133, 133, 133, 133, 133,
-    132, 132, 132, 132, 132, 132, 131, 131, 131, 131, 131, 131, 130, 130, 130, 130, 130, 130, 129,
-    129, 129, 129, 129, 129, 128, 128, 128, 128, 128, 128, 127, 127, 127, 127, 127, 127, 126, 126,
-    126, 126, 126, 126, 125, 125, 125, 125, 125, 125, 124, 124, 124, 124, 124, 124, 123, 123, 123,
-    123, 123, 123, 122, 122, 122, 122, 122, 122, 121, 121, 121, 121, 121, 121, 120, 120, 120, 120,
-    120, 120, 119, 119, 119, 119, 119, 119, 118, 118, 118, 118, 118, 118, 117, 117, 117, 117, 117,
-    117, 116, 116, 116, 116, 116, 116, 115, 115, 115, 115, 115, 115, 114, 114, 114, 114, 114, 114,
-    114, 114, 114, 114, 114, 114, 113, 113, 113, 113, 113, 113, 112, 112, 112, 112, 112, 112, 111,
-    111, 111, 111, 111, 111, 110, 110, 110, 110, 110, 110, 109, 109, 109, 109, 109, 109, 108, 108,
-    108, 108, 108, 108, 107, 107, 107, 107, 107, 107, 106, 106, 106, 106, 106, 106, 105, 105, 105,
-    105, 105, 105, 104, 104, 104, 104, 104, 104, 103, 103, 103, 103, 103, 103, 102, 102, 102, 102,
-    102, 102, 101, 101, 101, 101, 101, 101, 100, 100, 100, 100, 100, 100, 99, 99, 99, 99, 99, 99,
-    98, 98, 98, 98, 98, 98, 97, 97, 97, 97, 97, 97, 96, 96, 96, 96, 96, 96, 95, 95, 95, 95, 95, 95,
-    94, 94, 94, 94, 94, 94, 93, 93, 93, 93, 93, 93, 92, 92, 92, 92, 92, 92, 91, 91, 91, 91, 91, 91,
-    90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 90, 89, 89, 89, 89, 89, 89, 88, 88, 88, 88, 88, 88,
-    87, 87, 87, 87, 87, 87, 86, 86, 86, 86, 86, 86, 85, 85, 85, 85, 85, 85, 84, 84, 84, 84, 84, 84,
-    83, 83, 83, 83, 83, 83, 82, 82, 82, 82, 82, 82, 81, 81, 81, 81, 81, 81, 80, 80, 80, 80, 80, 80,
-    79, 79, 79, 79, 79, 79, 78, 78, 78, 78, 78, 78, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77, 77,
-    76, 76, 76, 76, 76, 76, 75, 75, 75, 75, 75, 75, 74, 74, 74, 74, 74, 74, 73, 73, 73, 73, 73, 73,
-    72, 72, 72, 72, 72, 72, 71, 71, 71, 71, 71, 71, 70, 70, 70, 70, 70, 70, 69, 69, 69, 69, 69, 69,
-    68, 68, 68, 68, 68, 68, 67, 67, 67, 67, 67, 67, 67, 67, 67, 67, 67, 67, 66, 66, 66, 66, 66, 66,
-    65, 65, 65, 65, 65, 65, 64, 64, 64, 64, 64, 64, 63, 63, 63, 63, 63, 63, 62, 62, 62, 62, 62, 62,
-    61, 61, 61, 61, 61, 61, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 59, 59, 59, 59, 59, 59,
-    58, 58, 58, 58, 58, 58, 57, 57, 57, 57, 57, 57, 56, 56, 56, 56, 56, 56, 55, 55, 55, 55, 55, 55,
-    54, 54, 54, 54, 54, 54, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 52, 52, 52, 52, 52, 52,
-    51, 51, 51, 51, 51, 51, 50, 50, 50, 50, 50, 50, 49, 49, 49, 49, 49, 49, 48, 48, 48, 48, 48, 48,
-    47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 46, 46, 46, 46, 46, 46, 45, 45, 45, 45, 45, 45,
-    44, 44, 44, 44, 44, 44, 43, 43, 43, 43, 43, 43, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
-    41, 41, 41, 41, 41, 41, 40, 40, 40, 40, 40, 40, 39, 39, 39, 39, 39, 39, 38, 38, 38, 38, 38, 38,
-    37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 36, 36, 36, 36, 36, 36, 35, 35, 35, 35, 35, 35,
-    34, 34, 34, 34, 34, 34, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 32, 32, 32, 32, 32, 32,
-    31, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 30, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
-    28, 28, 28, 28, 28, 28, 27, 27, 27, 27, 27, 27, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
-    25, 25, 25, 25, 25, 25, 24, 24, 24, 24, 24, 24, 23, 23, 23, 23, 23, 23, 22, 22, 22, 22, 22, 22,
-    22, 22, 22, 22, 22, 22, 21, 21, 21, 21, 21, 21, 20, 20, 20, 20, 20, 20, 19, 19, 19, 19, 19, 19,
-    18, 18, 18, 18, 18, 18, 17, 17, 17, 17, 17, 17, 16, 16, 16, 16, 16, 16, 15, 15, 15, 15, 15, 15,
-    14, 14, 14, 14, 14, 14, 13, 13, 13, 13, 13, 13, 11, 11, 11, 11, 11, 11, 10, 10, 10, 10, 10, 10,
-    9, 9, 9, 9, 9, 9, 7, 7, 7, 7, 7, 7, 6, 6, 6, 6, 6, 6, 4, 4, 4, 4, 4, 4, 3, 3, 3, 3, 3, 3, 1, 1,
-    1, 1, 1, 1, 0, 0, 0, 0, 0, 0,
-];
-
-pub const HAWAII: &[u8] = &[
-    140, 2, 115, 140, 2, 115, 141, 4, 114, 141, 4, 114, 141, 6, 113, 141, 6, 113, 141, 8, 112, 141,
-    8, 112, 141, 10, 111, 141, 10, 111, 142, 13, 110, 142, 13, 110, 142, 14, 109, 142, 14, 109,
-    142, 16, 108, 142, 16, 108, 142, 18, 107, 142, 18, 107, 142, 19, 106, 142, 19, 106, 143, 21,
-    105, 143, 21, 105, 143, 22, 104, 143, 22, 104, 143, 24, 103, 143, 24, 103, 143, 25, 102, 143,
-    25, 102, 143, 26, 101, 143, 26, 101, 144, 28, 100, 144, 28, 100, 144, 29, 99, 144, 29, 99, 144,
-    30, 99, 144, 30, 99, 144, 31, 98, 144, 31, 98, 144, 32, 97, 144, 32, 97, 144, 33, 96, 144, 33,
-    96, 145, 35, 95, 145, 35, 95, 145, 36, 94, 145, 36, 94, 145, 37, 93, 145, 37, 93, 145, 38, 92,
-    145, 38, 92, 145, 39, 91, 145, 39, 91, 145, 40, 90, 145, 40, 90, 146, 41, 90, 146, 41, 90, 146,
-    42, 89, 146, 42, 89, 146, 43, 88, 146, 43, 88, 146, 44, 87, 146, 44, 87, 146, 45, 86, 146, 45,
-    86, 146, 46, 85, 146, 46, 85, 146, 47, 84, 146, 47, 84, 147, 48, 84, 147, 48, 84, 147, 49, 83,
-    147, 49, 83, 147, 50, 82, 147, 50, 82, 147, 51, 81, 147, 51, 81, 147, 52, 80, 147, 52, 80, 147,
-    53, 80, 147, 53, 80, 147, 54, 79, 147, 54, 79, 148, 55, 78, 148, 55, 78, 148, 56, 77, 148, 56,
-    77, 148, 57, 77, 148, 57, 77, 148, 58, 76, 148, 58, 76, 148, 59, 75, 148, 59, 75, 148, 60, 74,
-    148, 60, 74, 148, 61, 74, 148, 61, 74, 149, 62, 73, 149, 62, 73, 149, 63, 72, 149, 63, 72, 149,
-    64, 71, 149, 64, 71, 149, 65, 71, 149, 65, 71, 149, 66, 70, 149, 66, 70, 149, 67, 69, 149, 67,
-    69, 149, 68, 69, 149, 68, 69, 149, 69, 68, 149, 69, 68, 150, 70, 67, 150, 70, 67, 150, 71, 66,
-    150, 71, 66, 150, 72, 66, 150, 72, 66, 150, 73, 65, 150, 73, 65, 150, 74, 64, 150, 74, 64, 150,
-    75, 64, 150, 75, 64, 150, 76, 63, 150, 76, 63, 150, 77, 62, 150, 77, 62, 151, 78, 62, 151, 78,
-    62, 151, 79, 61, 151, 79, 61, 151, 80, 60, 151, 80, 60, 151, 81, 60, 151, 81, 60, 151, 82, 59,
-    151, 82, 59, 151, 83, 58, 151, 83, 58, 151, 83, 58, 151, 83, 58, 151, 84, 57, 151, 84, 57, 152,
-    85, 56, 152, 85, 56, 152, 86, 56, 152, 86, 56, 152, 87, 55, 152, 87, 55, 152, 88, 54, 152, 88,
-    54, 152, 89, 54, 152, 89, 54, 152, 90, 53, 152, 90, 53, 152, 92, 52, 152, 92, 52, 153, 93, 52,
-    153, 93, 52, 153, 94, 51, 153, 94, 51, 153, 95, 50, 153, 95, 50, 153, 96, 50, 153, 96, 50, 153,
-    97, 49, 153, 97, 49, 153, 98, 48, 153, 98, 48, 153, 99, 48, 153, 99, 48, 153, 100, 47, 153,
-    100, 47, 154, 101, 46, 154, 101, 46, 154, 102, 46, 154, 102, 46, 154, 103, 45, 154, 103, 45,
-    154, 104, 44, 154, 104, 44, 154, 105, 44, 154, 105, 44, 154, 106, 43, 154, 106, 43, 154, 107,
-    42, 154, 107, 42, 155, 108, 42, 155, 108, 42, 155, 109, 41, 155, 109, 41, 155, 111, 40, 155,
-    111, 40, 155, 112, 40, 155, 112, 40, 155, 113, 39, 155, 113, 39, 155, 114, 38, 155, 114, 38,
-    155, 115, 38, 155, 115, 38, 155, 116, 37, 155, 116, 37, 156, 117, 36, 156, 117, 36, 156, 119,
-    36, 156, 119, 36, 156, 120, 35, 156, 120, 35, 156, 121, 35, 156, 121, 35, 156, 122, 34, 156,
-    122, 34, 156, 123, 33, 156, 123, 33, 156, 125, 33, 156, 125, 33, 156, 126, 32, 156, 126, 32,
-    156, 127, 32, 156, 127, 32, 156, 128, 31, 156, 128, 31, 157, 129, 31, 157, 129, 31, 157, 131,
-    30, 157, 131, 30, 157, 132, 30, 157, 132, 30, 157, 133, 29, 157, 133, 29, 157, 135, 29, 157,
-    135, 29, 157, 136, 29, 157, 136, 29, 157, 137, 28, 157, 137, 28, 157, 138, 28, 157, 138, 28,
-    157, 140, 28, 157, 140, 28, 157, 141, 28, 157, 141, 28, 157, 142, 28, 157, 142, 28, 157, 144,
-    28, 157, 144, 28, 156, 145, 28, 156, 145, 28, 156, 146, 28, 156, 146, 28, 156, 148, 28, 156,
-    148, 28, 156, 149, 28, 156, 149, 28, 156, 150, 28, 156, 150, 28, 156, 152, 29, 156, 152, 29,
-    155, 153, 29, 155, 153, 29, 155, 154, 30, 155, 154, 30, 155, 156, 31, 155, 156, 31, 155, 157,
-    31, 155, 157, 31, 154, 159, 32, 154, 159, 32, 154, 160, 33, 154, 160, 33, 154, 161, 34, 154,
-    161, 34, 153, 163, 35, 153, 163, 35, 153, 164, 36, 153, 164, 36, 152, 165, 38, 152, 165, 38,
-    152, 166, 39, 152, 166, 39, 151, 168, 40, 151, 168, 40, 151, 169, 42, 151, 169, 42, 150, 170,
-    43, 150, 170, 43, 149, 171, 45, 149, 171, 45, 149, 173, 46, 149, 173, 46, 148, 174, 48, 148,
-    174, 48, 147, 175, 50, 147, 175, 50, 147, 176, 51, 147, 176, 51, 146, 177, 53, 146, 177, 53,
-    145, 179, 55, 145, 179, 55, 145, 180, 57, 145, 180, 57, 144, 181, 59, 144, 181, 59, 143, 182,
-    60, 143, 182, 60, 142, 183, 62, 142, 183, 62, 141, 184, 64, 141, 184, 64, 140, 185, 66, 140,
-    185, 66, 140, 186, 68, 140, 186, 68, 139, 187, 70, 139, 187, 70, 138, 188, 72, 138, 188, 72,
-    137, 189, 74, 137, 189, 74, 136, 190, 76, 136, 190, 76, 135, 190, 78, 135, 190, 78, 134, 191,
-    80, 134, 191, 80, 133, 192, 82, 133, 192, 82, 132, 193, 84, 132, 193, 84, 131, 194, 87, 131,
-    194, 87, 130, 195, 89, 130, 195, 89, 130, 195, 91, 130, 195, 91, 129, 196, 93, 129, 196, 93,
-    128, 197, 95, 128, 197, 95, 127, 198, 97, 127, 198, 97, 126, 198, 99, 126, 198, 99, 125, 199,
-    101, 125, 199, 101, 124, 200, 103, 124, 200, 103, 123, 201, 105, 123, 201, 105, 122, 201, 108,
-    122, 201, 108, 121, 202, 110, 121, 202, 110, 120, 203, 112, 120, 203, 112, 119, 204, 114, 119,
-    204, 114, 118, 204, 116, 118, 204, 116, 117, 205, 118, 117, 205, 118, 116, 206, 121, 116, 206,
-    121, 115, 206, 123, 115, 206, 123, 114, 207, 125, 114, 207, 125, 113, 208, 127, 113, 208, 127,
-    112, 208, 129, 112, 208, 129, 112, 209, 131, 112, 209, 131, 111, 210, 134, 111, 210, 134, 110,
-    210, 136, 110, 210, 136, 109, 211, 138, 109, 211, 138, 108, 212, 140, 108, 212, 140, 107, 212,
-    142, 107, 212, 142, 106, 213, 145, 106, 213, 145, 105, 214, 147, 105, 214, 147, 104, 214, 149,
-    104, 214, 149, 104, 215, 151, 104, 215, 151, 103, 216, 153, 103, 216, 153, 102, 216, 156, 102,
-    216, 156, 101, 217, 158, 101, 217, 158, 100, 218, 160, 100, 218, 160, 100, 218, 162, 100, 218,
-    162, 99, 219, 165, 99, 219, 165, 98, 220, 167, 98, 220, 167, 98, 220, 169, 98, 220, 169, 97,
-    221, 171, 97, 221, 171, 97, 222, 174, 97, 222, 174, 96, 222, 176, 96, 222, 176, 96, 223, 178,
-    96, 223, 178, 96, 224, 180, 96, 224, 180, 95, 224, 183, 95, 224, 183, 95, 225, 185, 95, 225,
-    185, 95, 226, 187, 95, 226, 187, 95, 226, 189, 95, 226, 189, 95, 227, 192, 95, 227, 192, 95,
-    228, 194, 95, 228, 194, 96, 228, 196, 96, 228, 196, 96, 229, 198, 96, 229, 198, 97, 229, 200,
-    97, 229, 200, 97, 230, 202, 97, 230, 202, 98, 231, 205, 98, 231, 205, 99, 231, 207, 99, 231,
-    207, 100, 232, 209, 100, 232, 209, 102, 232, 211, 102, 232, 211, 103, 233, 213, 103, 233, 213,
-    104, 233, 215, 104, 233, 215, 106, 234, 217, 106, 234, 217, 108, 235, 219, 108, 235, 219, 109,
-    235, 220, 109, 235, 220, 111, 235, 222, 111, 235, 222, 113, 236, 224, 113, 236, 224, 115, 236,
-    226, 115, 236, 226, 118, 237, 227, 118, 237, 227, 120, 237, 229, 120, 237, 229, 122, 238, 231,
-    122, 238, 231, 125, 238, 232, 125, 238, 232, 127, 238, 234, 127, 238, 234, 130, 239, 235, 130,
-    239, 235, 132, 239, 236, 132, 239, 236, 135, 239, 238, 135, 239, 238, 138, 240, 239, 138, 240,
-    239, 141, 240, 240, 141, 240, 240, 143, 240, 241, 143, 240, 241, 146, 240, 243, 146, 240, 243,
-    149, 241, 244, 149, 241, 244, 152, 241, 245, 152, 241, 245, 154, 241, 246, 154, 241, 246, 157,
-    241, 247, 157, 241, 247, 160, 241, 248, 160, 241, 248, 163, 241, 249, 163, 241, 249, 166, 241,
-    249, 166, 241, 249, 168, 242, 250, 168, 242, 250, 171, 242, 251, 171, 242, 251, 174, 242, 252,
-    174, 242, 252, 177, 242, 253, 177, 242, 253, 179, 242, 253, 179, 242, 253,
-];
-
-pub const IMOLA: &[u8] = &[
-    26, 51, 179, 26, 51, 179, 26, 52, 178, 26, 52, 178, 27, 53, 178, 27, 53, 178, 27, 53, 177, 27,
-    53, 177, 28, 54, 177, 28, 54, 177, 28, 55, 177, 28, 55, 177, 28, 55, 176, 28, 55, 176, 29, 56,
-    176, 29, 56, 176, 29, 57, 176, 29, 57, 176, 30, 57, 175, 30, 57, 175, 30, 58, 175, 30, 58, 175,
-    30, 59, 175, 30, 59, 175, 31, 60, 174, 31, 60, 174, 31, 60, 174, 31, 60, 174, 31, 61, 174, 31,
-    61, 174, 32, 62, 173, 32, 62, 173, 32, 62, 173, 32, 62, 173, 32, 63, 173, 32, 63, 173, 33, 64,
-    172, 33, 64, 172, 33, 64, 172, 33, 64, 172, 33, 65, 172, 33, 65, 172, 34, 66, 171, 34, 66, 171,
-    34, 66, 171, 34, 66, 171, 34, 67, 171, 34, 67, 171, 35, 68, 170, 35, 68, 170, 35, 68, 170, 35,
-    68, 170, 35, 69, 170, 35, 69, 170, 36, 70, 169, 36, 70, 169, 36, 70, 169, 36, 70, 169, 36, 71,
-    169, 36, 71, 169, 37, 72, 168, 37, 72, 168, 37, 72, 168, 37, 72, 168, 37, 73, 168, 37, 73, 168,
-    38, 74, 167, 38, 74, 167, 38, 74, 167, 38, 74, 167, 38, 75, 167, 38, 75, 167, 39, 76, 166, 39,
-    76, 166, 39, 76, 166, 39, 76, 166, 39, 77, 166, 39, 77, 166, 40, 78, 165, 40, 78, 165, 40, 78,
-    165, 40, 78, 165, 40, 79, 165, 40, 79, 165, 41, 80, 164, 41, 80, 164, 41, 80, 164, 41, 80, 164,
-    41, 81, 164, 41, 81, 164, 42, 82, 163, 42, 82, 163, 42, 82, 163, 42, 82, 163, 42, 83, 163, 42,
-    83, 163, 43, 83, 162, 43, 83, 162, 43, 84, 162, 43, 84, 162, 43, 85, 162, 43, 85, 162, 44, 85,
-    161, 44, 85, 161, 44, 86, 161, 44, 86, 161, 44, 87, 161, 44, 87, 161, 45, 87, 160, 45, 87, 160,
-    45, 88, 160, 45, 88, 160, 45, 89, 160, 45, 89, 160, 46, 89, 159, 46, 89, 159, 46, 90, 159, 46,
-    90, 159, 46, 91, 159, 46, 91, 159, 47, 91, 158, 47, 91, 158, 47, 92, 158, 47, 92, 158, 47, 93,
-    158, 47, 93, 158, 48, 93, 157, 48, 93, 157, 48, 94, 157, 48, 94, 157, 48, 95, 156, 48, 95, 156,
-    49, 95, 156, 49, 95, 156, 49, 96, 156, 49, 96, 156, 50, 96, 155, 50, 96, 155, 50, 97, 155, 50,
-    97, 155, 50, 98, 155, 50, 98, 155, 51, 98, 154, 51, 98, 154, 51, 99, 154, 51, 99, 154, 52, 100,
-    153, 52, 100, 153, 52, 100, 153, 52, 100, 153, 52, 101, 152, 52, 101, 152, 53, 101, 152, 53,
-    101, 152, 53, 102, 152, 53, 102, 152, 54, 103, 151, 54, 103, 151, 54, 103, 151, 54, 103, 151,
-    55, 104, 150, 55, 104, 150, 55, 104, 150, 55, 104, 150, 56, 105, 149, 56, 105, 149, 56, 106,
-    149, 56, 106, 149, 57, 106, 148, 57, 106, 148, 57, 107, 148, 57, 107, 148, 58, 107, 147, 58,
-    107, 147, 58, 108, 147, 58, 108, 147, 59, 108, 146, 59, 108, 146, 59, 109, 146, 59, 109, 146,
-    60, 110, 145, 60, 110, 145, 60, 110, 145, 60, 110, 145, 61, 111, 144, 61, 111, 144, 61, 111,
-    144, 61, 111, 144, 62, 112, 143, 62, 112, 143, 62, 112, 143, 62, 112, 143, 63, 113, 142, 63,
-    113, 142, 64, 114, 141, 64, 114, 141, 64, 114, 141, 64, 114, 141, 65, 115, 140, 65, 115, 140,
-    65, 115, 140, 65, 115, 140, 66, 116, 139, 66, 116, 139, 66, 116, 139, 66, 116, 139, 67, 117,
-    138, 67, 117, 138, 68, 118, 138, 68, 118, 138, 68, 118, 137, 68, 118, 137, 69, 119, 137, 69,
-    119, 137, 69, 119, 136, 69, 119, 136, 70, 120, 136, 70, 120, 136, 70, 121, 135, 70, 121, 135,
-    71, 121, 135, 71, 121, 135, 72, 122, 134, 72, 122, 134, 72, 123, 134, 72, 123, 134, 73, 123,
-    133, 73, 123, 133, 74, 124, 133, 74, 124, 133, 74, 125, 132, 74, 125, 132, 75, 125, 132, 75,
-    125, 132, 76, 126, 131, 76, 126, 131, 76, 127, 131, 76, 127, 131, 77, 127, 130, 77, 127, 130,
-    78, 128, 130, 78, 128, 130, 78, 129, 129, 78, 129, 129, 79, 129, 129, 79, 129, 129, 80, 130,
-    129, 80, 130, 129, 81, 131, 128, 81, 131, 128, 81, 132, 128, 81, 132, 128, 82, 133, 128, 82,
-    133, 128, 83, 133, 127, 83, 133, 127, 84, 134, 127, 84, 134, 127, 85, 135, 127, 85, 135, 127,
-    86, 136, 126, 86, 136, 126, 86, 137, 126, 86, 137, 126, 87, 138, 126, 87, 138, 126, 88, 138,
-    125, 88, 138, 125, 89, 139, 125, 89, 139, 125, 90, 140, 125, 90, 140, 125, 91, 141, 125, 91,
-    141, 125, 92, 142, 124, 92, 142, 124, 92, 143, 124, 92, 143, 124, 93, 144, 124, 93, 144, 124,
-    94, 145, 123, 94, 145, 123, 95, 146, 123, 95, 146, 123, 96, 146, 123, 96, 146, 123, 97, 147,
-    123, 97, 147, 123, 98, 148, 122, 98, 148, 122, 99, 149, 122, 99, 149, 122, 100, 150, 122, 100,
-    150, 122, 101, 151, 122, 101, 151, 122, 102, 152, 122, 102, 152, 122, 103, 153, 121, 103, 153,
-    121, 103, 154, 121, 103, 154, 121, 104, 155, 121, 104, 155, 121, 105, 156, 121, 105, 156, 121,
-    106, 157, 120, 106, 157, 120, 107, 158, 120, 107, 158, 120, 108, 159, 120, 108, 159, 120, 109,
-    160, 120, 109, 160, 120, 110, 161, 119, 110, 161, 119, 111, 162, 119, 111, 162, 119, 112, 163,
-    119, 112, 163, 119, 113, 164, 119, 113, 164, 119, 114, 165, 118, 114, 165, 118, 115, 166, 118,
-    115, 166, 118, 116, 167, 118, 116, 167, 118, 117, 168, 118, 117, 168, 118, 118, 169, 117, 118,
-    169, 117, 119, 170, 117, 119, 170, 117, 120, 171, 117, 120, 171, 117, 121, 172, 117, 121, 172,
-    117, 122, 173, 116, 122, 173, 116, 123, 174, 116, 123, 174, 116, 124, 175, 116, 124, 175, 116,
-    125, 176, 116, 125, 176, 116, 126, 177, 115, 126, 177, 115, 127, 178, 115, 127, 178, 115, 128,
-    179, 115, 128, 179, 115, 129, 180, 115, 129, 180, 115, 130, 181, 114, 130, 181, 114, 131, 182,
-    114, 131, 182, 114, 132, 183, 114, 132, 183, 114, 133, 184, 114, 133, 184, 114, 134, 185, 113,
-    134, 185, 113, 135, 186, 113, 135, 186, 113, 136, 187, 113, 136, 187, 113, 137, 188, 113, 137,
-    188, 113, 138, 189, 112, 138, 189, 112, 139, 190, 112, 139, 190, 112, 140, 191, 112, 140, 191,
-    112, 141, 192, 112, 141, 192, 112, 142, 193, 111, 142, 193, 111, 144, 194, 111, 144, 194, 111,
-    145, 195, 111, 145, 195, 111, 146, 196, 110, 146, 196, 110, 147, 198, 110, 147, 198, 110, 148,
-    199, 110, 148, 199, 110, 149, 200, 110, 149, 200, 110, 150, 201, 109, 150, 201, 109, 151, 202,
-    109, 151, 202, 109, 152, 203, 109, 152, 203, 109, 153, 204, 109, 153, 204, 109, 155, 205, 108,
-    155, 205, 108, 156, 206, 108, 156, 206, 108, 157, 207, 108, 157, 207, 108, 158, 208, 108, 158,
-    208, 108, 159, 210, 107, 159, 210, 107, 161, 211, 107, 161, 211, 107, 162, 212, 107, 162, 212,
-    107, 163, 213, 107, 163, 213, 107, 164, 214, 106, 164, 214, 106, 166, 215, 106, 166, 215, 106,
-    167, 216, 106, 167, 216, 106, 169, 217, 106, 169, 217, 106, 170, 218, 105, 170, 218, 105, 172,
-    219, 105, 172, 219, 105, 173, 221, 105, 173, 221, 105, 175, 222, 105, 175, 222, 105, 176, 223,
-    104, 176, 223, 104, 178, 224, 104, 178, 224, 104, 180, 225, 104, 180, 225, 104, 181, 226, 104,
-    181, 226, 104, 183, 227, 104, 183, 227, 104, 185, 228, 104, 185, 228, 104, 187, 229, 103, 187,
-    229, 103, 189, 230, 103, 189, 230, 103, 191, 231, 103, 191, 231, 103, 192, 232, 103, 192, 232,
-    103, 194, 233, 103, 194, 233, 103, 196, 234, 103, 196, 234, 103, 198, 235, 103, 198, 235, 103,
-    200, 235, 103, 200, 235, 103, 202, 236, 103, 202, 236, 103, 205, 237, 102, 205, 237, 102, 207,
-    238, 102, 207, 238, 102, 209, 239, 102, 209, 239, 102, 211, 240, 102, 211, 240, 102, 213, 240,
-    102, 213, 240, 102, 215, 241, 102, 215, 241, 102, 217, 242, 102, 217, 242, 102, 219, 243, 102,
-    219, 243, 102, 221, 244, 102, 221, 244, 102, 223, 244, 102, 223, 244, 102, 226, 245, 102, 226,
-    245, 102, 228, 246, 102, 228, 246, 102, 230, 246, 102, 230, 246, 102, 232, 247, 102, 232, 247,
-    102, 234, 248, 102, 234, 248, 102, 236, 249, 102, 236, 249, 102, 238, 249, 102, 238, 249, 102,
-    240, 250, 102, 240, 250, 102, 242, 251, 102, 242, 251, 102, 245, 251, 102, 245, 251, 102, 247,
-    252, 102, 247, 252, 102, 249, 253, 102, 249, 253, 102, 251, 254, 102, 251, 254, 102, 253, 254,
-    102, 253, 254, 102, 255, 255, 102, 255, 255, 102,
-];
-
-pub const LAJOLLA: &[u8] = &[
-    255, 255, 204, 255, 255, 204, 255, 254, 202, 255, 254, 202, 255, 254, 200, 255, 254, 200, 255,
-    253, 199, 255, 253, 199, 255, 252, 197, 255, 252, 197, 255, 252, 195, 255, 252, 195, 254, 251,
-    193, 254, 251, 193, 254, 250, 192, 254, 250, 192, 254, 250, 190, 254, 250, 190, 254, 249, 188,
-    254, 249, 188, 254, 249, 186, 254, 249, 186, 254, 248, 184, 254, 248, 184, 254, 247, 183, 254,
-    247, 183, 254, 247, 181, 254, 247, 181, 254, 246, 179, 254, 246, 179, 253, 245, 177, 253, 245,
-    177, 253, 245, 175, 253, 245, 175, 253, 244, 174, 253, 244, 174, 253, 243, 172, 253, 243, 172,
-    253, 243, 170, 253, 243, 170, 253, 242, 168, 253, 242, 168, 253, 241, 166, 253, 241, 166, 252,
-    240, 165, 252, 240, 165, 252, 240, 163, 252, 240, 163, 252, 239, 161, 252, 239, 161, 252, 238,
-    159, 252, 238, 159, 252, 238, 157, 252, 238, 157, 252, 237, 156, 252, 237, 156, 251, 236, 154,
-    251, 236, 154, 251, 235, 152, 251, 235, 152, 251, 234, 150, 251, 234, 150, 251, 234, 148, 251,
-    234, 148, 251, 233, 146, 251, 233, 146, 250, 232, 144, 250, 232, 144, 250, 231, 143, 250, 231,
-    143, 250, 230, 141, 250, 230, 141, 250, 229, 139, 250, 229, 139, 250, 228, 137, 250, 228, 137,
-    249, 227, 135, 249, 227, 135, 249, 226, 133, 249, 226, 133, 249, 225, 132, 249, 225, 132, 249,
-    224, 130, 249, 224, 130, 248, 223, 128, 248, 223, 128, 248, 222, 126, 248, 222, 126, 248, 221,
-    124, 248, 221, 124, 248, 220, 123, 248, 220, 123, 247, 218, 121, 247, 218, 121, 247, 217, 119,
-    247, 217, 119, 247, 216, 117, 247, 216, 117, 246, 215, 116, 246, 215, 116, 246, 214, 114, 246,
-    214, 114, 246, 212, 112, 246, 212, 112, 246, 211, 111, 246, 211, 111, 245, 210, 109, 245, 210,
-    109, 245, 208, 108, 245, 208, 108, 245, 207, 106, 245, 207, 106, 244, 206, 105, 244, 206, 105,
-    244, 204, 104, 244, 204, 104, 244, 203, 102, 244, 203, 102, 243, 202, 101, 243, 202, 101, 243,
-    200, 100, 243, 200, 100, 243, 199, 99, 243, 199, 99, 242, 197, 98, 242, 197, 98, 242, 196, 97,
-    242, 196, 97, 242, 195, 96, 242, 195, 96, 241, 193, 95, 241, 193, 95, 241, 192, 94, 241, 192,
-    94, 241, 191, 93, 241, 191, 93, 241, 189, 92, 241, 189, 92, 240, 188, 91, 240, 188, 91, 240,
-    187, 91, 240, 187, 91, 240, 185, 90, 240, 185, 90, 239, 184, 89, 239, 184, 89, 239, 183, 89,
-    239, 183, 89, 239, 181, 88, 239, 181, 88, 238, 180, 88, 238, 180, 88, 238, 179, 87, 238, 179,
-    87, 238, 178, 87, 238, 178, 87, 238, 176, 87, 238, 176, 87, 237, 175, 86, 237, 175, 86, 237,
-    174, 86, 237, 174, 86, 237, 173, 86, 237, 173, 86, 237, 171, 85, 237, 171, 85, 236, 170, 85,
-    236, 170, 85, 236, 169, 85, 236, 169, 85, 236, 168, 85, 236, 168, 85, 236, 167, 84, 236, 167,
-    84, 235, 165, 84, 235, 165, 84, 235, 164, 84, 235, 164, 84, 235, 163, 84, 235, 163, 84, 235,
-    162, 84, 235, 162, 84, 234, 161, 83, 234, 161, 83, 234, 159, 83, 234, 159, 83, 234, 158, 83,
-    234, 158, 83, 234, 157, 83, 234, 157, 83, 233, 156, 83, 233, 156, 83, 233, 155, 83, 233, 155,
-    83, 233, 154, 83, 233, 154, 83, 233, 152, 82, 233, 152, 82, 232, 151, 82, 232, 151, 82, 232,
-    150, 82, 232, 150, 82, 232, 149, 82, 232, 149, 82, 231, 148, 82, 231, 148, 82, 231, 147, 82,
-    231, 147, 82, 231, 145, 82, 231, 145, 82, 231, 144, 82, 231, 144, 82, 230, 143, 82, 230, 143,
-    82, 230, 142, 82, 230, 142, 82, 230, 141, 81, 230, 141, 81, 230, 140, 81, 230, 140, 81, 229,
-    138, 81, 229, 138, 81, 229, 137, 81, 229, 137, 81, 229, 136, 81, 229, 136, 81, 228, 135, 81,
-    228, 135, 81, 228, 134, 81, 228, 134, 81, 228, 132, 81, 228, 132, 81, 227, 131, 81, 227, 131,
-    81, 227, 130, 81, 227, 130, 81, 227, 129, 80, 227, 129, 80, 226, 128, 80, 226, 128, 80, 226,
-    126, 80, 226, 126, 80, 225, 125, 80, 225, 125, 80, 225, 124, 80, 225, 124, 80, 224, 123, 80,
-    224, 123, 80, 224, 121, 80, 224, 121, 80, 223, 120, 80, 223, 120, 80, 223, 119, 79, 223, 119,
-    79, 222, 118, 79, 222, 118, 79, 222, 116, 79, 222, 116, 79, 221, 115, 79, 221, 115, 79, 221,
-    114, 79, 221, 114, 79, 220, 112, 79, 220, 112, 79, 219, 111, 79, 219, 111, 79, 218, 110, 78,
-    218, 110, 78, 218, 108, 78, 218, 108, 78, 217, 107, 78, 217, 107, 78, 216, 106, 78, 216, 106,
-    78, 215, 105, 78, 215, 105, 78, 214, 103, 78, 214, 103, 78, 213, 102, 77, 213, 102, 77, 212,
-    101, 77, 212, 101, 77, 211, 99, 77, 211, 99, 77, 210, 98, 77, 210, 98, 77, 208, 97, 76, 208,
-    97, 76, 207, 95, 76, 207, 95, 76, 206, 94, 76, 206, 94, 76, 205, 93, 76, 205, 93, 76, 203, 92,
-    75, 203, 92, 75, 202, 91, 75, 202, 91, 75, 200, 89, 75, 200, 89, 75, 199, 88, 75, 199, 88, 75,
-    197, 87, 74, 197, 87, 74, 196, 86, 74, 196, 86, 74, 194, 85, 74, 194, 85, 74, 193, 84, 73, 193,
-    84, 73, 191, 83, 73, 191, 83, 73, 189, 82, 72, 189, 82, 72, 188, 81, 72, 188, 81, 72, 186, 80,
-    72, 186, 80, 72, 184, 79, 71, 184, 79, 71, 182, 78, 71, 182, 78, 71, 181, 77, 70, 181, 77, 70,
-    179, 77, 70, 179, 77, 70, 177, 76, 70, 177, 76, 70, 175, 75, 69, 175, 75, 69, 174, 74, 69, 174,
-    74, 69, 172, 74, 68, 172, 74, 68, 170, 73, 68, 170, 73, 68, 168, 72, 67, 168, 72, 67, 166, 71,
-    67, 166, 71, 67, 165, 71, 66, 165, 71, 66, 163, 70, 66, 163, 70, 66, 161, 70, 65, 161, 70, 65,
-    159, 69, 65, 159, 69, 65, 157, 68, 64, 157, 68, 64, 156, 68, 63, 156, 68, 63, 154, 67, 63, 154,
-    67, 63, 152, 67, 62, 152, 67, 62, 150, 66, 62, 150, 66, 62, 148, 66, 61, 148, 66, 61, 147, 65,
-    60, 147, 65, 60, 145, 65, 60, 145, 65, 60, 143, 64, 59, 143, 64, 59, 141, 64, 58, 141, 64, 58,
-    139, 63, 58, 139, 63, 58, 138, 63, 57, 138, 63, 57, 136, 62, 56, 136, 62, 56, 134, 62, 55, 134,
-    62, 55, 132, 61, 55, 132, 61, 55, 131, 61, 54, 131, 61, 54, 129, 60, 53, 129, 60, 53, 127, 59,
-    52, 127, 59, 52, 125, 59, 52, 125, 59, 52, 123, 58, 51, 123, 58, 51, 122, 58, 50, 122, 58, 50,
-    120, 57, 49, 120, 57, 49, 118, 57, 49, 118, 57, 49, 116, 56, 48, 116, 56, 48, 115, 56, 47, 115,
-    56, 47, 113, 55, 46, 113, 55, 46, 111, 55, 45, 111, 55, 45, 110, 54, 45, 110, 54, 45, 108, 54,
-    44, 108, 54, 44, 106, 53, 43, 106, 53, 43, 104, 53, 42, 104, 53, 42, 103, 52, 41, 103, 52, 41,
-    101, 51, 40, 101, 51, 40, 99, 51, 40, 99, 51, 40, 97, 50, 39, 97, 50, 39, 96, 50, 38, 96, 50,
-    38, 94, 49, 37, 94, 49, 37, 92, 49, 36, 92, 49, 36, 91, 48, 36, 91, 48, 36, 89, 48, 35, 89, 48,
-    35, 87, 47, 34, 87, 47, 34, 86, 47, 33, 86, 47, 33, 84, 46, 32, 84, 46, 32, 82, 46, 32, 82, 46,
-    32, 81, 45, 31, 81, 45, 31, 79, 44, 30, 79, 44, 30, 78, 44, 29, 78, 44, 29, 76, 43, 29, 76, 43,
-    29, 74, 43, 28, 74, 43, 28, 73, 42, 27, 73, 42, 27, 71, 42, 26, 71, 42, 26, 69, 41, 26, 69, 41,
-    26, 68, 41, 25, 68, 41, 25, 66, 40, 24, 66, 40, 24, 65, 40, 23, 65, 40, 23, 63, 39, 23, 63, 39,
-    23, 62, 39, 22, 62, 39, 22, 60, 38, 21, 60, 38, 21, 58, 38, 21, 58, 38, 21, 57, 37, 20, 57, 37,
-    20, 55, 37, 19, 55, 37, 19, 54, 36, 19, 54, 36, 19, 52, 36, 18, 52, 36, 18, 51, 35, 17, 51, 35,
-    17, 49, 35, 17, 49, 35, 17, 48, 34, 16, 48, 34, 16, 46, 34, 15, 46, 34, 15, 45, 33, 14, 45, 33,
-    14, 43, 33, 13, 43, 33, 13, 42, 32, 13, 42, 32, 13, 41, 31, 12, 41, 31, 12, 39, 31, 11, 39, 31,
-    11, 38, 30, 10, 38, 30, 10, 36, 30, 9, 36, 30, 9, 35, 29, 8, 35, 29, 8, 34, 29, 7, 34, 29, 7,
-    32, 28, 6, 32, 28, 6, 31, 28, 5, 31, 28, 5, 30, 27, 4, 30, 27, 4, 28, 27, 3, 28, 27, 3, 27, 26,
-    2, 27, 26, 2, 26, 26, 1, 26, 26, 1,
-];
-
-pub const LAPAZ: &[u8] = &[
-    26, 12, 100, 26, 12, 100, 27, 14, 101, 27, 14, 101, 27, 15, 102, 27, 15, 102, 27, 16, 103, 27,
-    16, 103, 28, 18, 104, 28, 18, 104, 28, 19, 104, 28, 19, 104, 28, 20, 105, 28, 20, 105, 29, 22,
-    106, 29, 22, 106, 29, 23, 107, 29, 23, 107, 29, 24, 108, 29, 24, 108, 30, 25, 109, 30, 25, 109,
-    30, 26, 109, 30, 26, 109, 30, 28, 110, 30, 28, 110, 31, 29, 111, 31, 29, 111, 31, 30, 112, 31,
-    30, 112, 31, 31, 113, 31, 31, 113, 32, 32, 113, 32, 32, 113, 32, 33, 114, 32, 33, 114, 32, 35,
-    115, 32, 35, 115, 32, 36, 116, 32, 36, 116, 33, 37, 117, 33, 37, 117, 33, 38, 117, 33, 38, 117,
-    33, 39, 118, 33, 39, 118, 33, 40, 119, 33, 40, 119, 34, 41, 120, 34, 41, 120, 34, 42, 121, 34,
-    42, 121, 34, 43, 121, 34, 43, 121, 34, 44, 122, 34, 44, 122, 35, 45, 123, 35, 45, 123, 35, 47,
-    124, 35, 47, 124, 35, 48, 124, 35, 48, 124, 35, 49, 125, 35, 49, 125, 36, 50, 126, 36, 50, 126,
-    36, 51, 127, 36, 51, 127, 36, 52, 127, 36, 52, 127, 36, 53, 128, 36, 53, 128, 37, 54, 129, 37,
-    54, 129, 37, 55, 130, 37, 55, 130, 37, 56, 130, 37, 56, 130, 37, 57, 131, 37, 57, 131, 38, 58,
-    132, 38, 58, 132, 38, 59, 132, 38, 59, 132, 38, 60, 133, 38, 60, 133, 38, 61, 134, 38, 61, 134,
-    39, 62, 135, 39, 62, 135, 39, 63, 135, 39, 63, 135, 39, 64, 136, 39, 64, 136, 39, 65, 137, 39,
-    65, 137, 40, 66, 137, 40, 66, 137, 40, 67, 138, 40, 67, 138, 40, 69, 139, 40, 69, 139, 40, 70,
-    139, 40, 70, 139, 41, 71, 140, 41, 71, 140, 41, 72, 140, 41, 72, 140, 41, 73, 141, 41, 73, 141,
-    42, 74, 142, 42, 74, 142, 42, 75, 142, 42, 75, 142, 42, 76, 143, 42, 76, 143, 43, 77, 144, 43,
-    77, 144, 43, 78, 144, 43, 78, 144, 43, 79, 145, 43, 79, 145, 44, 80, 145, 44, 80, 145, 44, 81,
-    146, 44, 81, 146, 44, 82, 146, 44, 82, 146, 45, 83, 147, 45, 83, 147, 45, 84, 148, 45, 84, 148,
-    45, 85, 148, 45, 85, 148, 46, 86, 149, 46, 86, 149, 46, 87, 149, 46, 87, 149, 46, 88, 150, 46,
-    88, 150, 47, 89, 150, 47, 89, 150, 47, 90, 151, 47, 90, 151, 48, 91, 151, 48, 91, 151, 48, 92,
-    152, 48, 92, 152, 48, 93, 152, 48, 93, 152, 49, 94, 153, 49, 94, 153, 49, 94, 153, 49, 94, 153,
-    50, 95, 153, 50, 95, 153, 50, 96, 154, 50, 96, 154, 51, 97, 154, 51, 97, 154, 51, 98, 155, 51,
-    98, 155, 52, 99, 155, 52, 99, 155, 52, 100, 156, 52, 100, 156, 53, 101, 156, 53, 101, 156, 53,
-    102, 156, 53, 102, 156, 54, 103, 157, 54, 103, 157, 55, 104, 157, 55, 104, 157, 55, 105, 157,
-    55, 105, 157, 56, 106, 158, 56, 106, 158, 56, 107, 158, 56, 107, 158, 57, 108, 158, 57, 108,
-    158, 58, 109, 159, 58, 109, 159, 58, 110, 159, 58, 110, 159, 59, 111, 159, 59, 111, 159, 60,
-    112, 160, 60, 112, 160, 60, 112, 160, 60, 112, 160, 61, 113, 160, 61, 113, 160, 62, 114, 161,
-    62, 114, 161, 62, 115, 161, 62, 115, 161, 63, 116, 161, 63, 116, 161, 64, 117, 161, 64, 117,
-    161, 65, 118, 162, 65, 118, 162, 66, 119, 162, 66, 119, 162, 66, 120, 162, 66, 120, 162, 67,
-    121, 162, 67, 121, 162, 68, 122, 162, 68, 122, 162, 69, 122, 162, 69, 122, 162, 70, 123, 163,
-    70, 123, 163, 71, 124, 163, 71, 124, 163, 72, 125, 163, 72, 125, 163, 73, 126, 163, 73, 126,
-    163, 74, 127, 163, 74, 127, 163, 75, 128, 163, 75, 128, 163, 76, 128, 163, 76, 128, 163, 77,
-    129, 163, 77, 129, 163, 78, 130, 163, 78, 130, 163, 79, 131, 164, 79, 131, 164, 80, 132, 164,
-    80, 132, 164, 81, 133, 164, 81, 133, 164, 82, 133, 164, 82, 133, 164, 83, 134, 164, 83, 134,
-    164, 84, 135, 164, 84, 135, 164, 85, 136, 164, 85, 136, 164, 86, 136, 164, 86, 136, 164, 87,
-    137, 164, 87, 137, 164, 88, 138, 163, 88, 138, 163, 90, 139, 163, 90, 139, 163, 91, 139, 163,
-    91, 139, 163, 92, 140, 163, 92, 140, 163, 93, 141, 163, 93, 141, 163, 94, 142, 163, 94, 142,
-    163, 96, 142, 163, 96, 142, 163, 97, 143, 163, 97, 143, 163, 98, 144, 163, 98, 144, 163, 99,
-    144, 162, 99, 144, 162, 101, 145, 162, 101, 145, 162, 102, 146, 162, 102, 146, 162, 103, 146,
-    162, 103, 146, 162, 104, 147, 162, 104, 147, 162, 106, 148, 161, 106, 148, 161, 107, 148, 161,
-    107, 148, 161, 108, 149, 161, 108, 149, 161, 110, 149, 161, 110, 149, 161, 111, 150, 161, 111,
-    150, 161, 112, 151, 160, 112, 151, 160, 114, 151, 160, 114, 151, 160, 115, 152, 160, 115, 152,
-    160, 116, 152, 159, 116, 152, 159, 118, 153, 159, 118, 153, 159, 119, 153, 159, 119, 153, 159,
-    120, 154, 159, 120, 154, 159, 122, 154, 158, 122, 154, 158, 123, 155, 158, 123, 155, 158, 124,
-    155, 158, 124, 155, 158, 126, 156, 157, 126, 156, 157, 127, 156, 157, 127, 156, 157, 128, 157,
-    157, 128, 157, 157, 130, 157, 156, 130, 157, 156, 131, 158, 156, 131, 158, 156, 133, 158, 156,
-    133, 158, 156, 134, 158, 155, 134, 158, 155, 135, 159, 155, 135, 159, 155, 137, 159, 154, 137,
-    159, 154, 138, 160, 154, 138, 160, 154, 139, 160, 154, 139, 160, 154, 141, 160, 153, 141, 160,
-    153, 142, 161, 153, 142, 161, 153, 144, 161, 153, 144, 161, 153, 145, 162, 152, 145, 162, 152,
-    146, 162, 152, 146, 162, 152, 148, 162, 152, 148, 162, 152, 149, 163, 152, 149, 163, 152, 151,
-    163, 151, 151, 163, 151, 152, 164, 151, 152, 164, 151, 153, 164, 151, 153, 164, 151, 155, 164,
-    150, 155, 164, 150, 156, 165, 150, 156, 165, 150, 158, 165, 150, 158, 165, 150, 159, 166, 150,
-    159, 166, 150, 161, 166, 150, 161, 166, 150, 162, 166, 150, 162, 166, 150, 164, 167, 149, 164,
-    167, 149, 165, 167, 149, 165, 167, 149, 167, 168, 149, 167, 168, 149, 168, 168, 149, 168, 168,
-    149, 170, 169, 149, 170, 169, 149, 171, 169, 149, 171, 169, 149, 173, 170, 149, 173, 170, 149,
-    174, 170, 149, 174, 170, 149, 176, 171, 149, 176, 171, 149, 178, 171, 150, 178, 171, 150, 179,
-    172, 150, 179, 172, 150, 181, 173, 150, 181, 173, 150, 183, 173, 150, 183, 173, 150, 184, 174,
-    151, 184, 174, 151, 186, 175, 151, 186, 175, 151, 188, 176, 152, 188, 176, 152, 190, 176, 152,
-    190, 176, 152, 191, 177, 153, 191, 177, 153, 193, 178, 153, 193, 178, 153, 195, 179, 154, 195,
-    179, 154, 197, 180, 155, 197, 180, 155, 199, 181, 156, 199, 181, 156, 200, 182, 156, 200, 182,
-    156, 202, 183, 157, 202, 183, 157, 204, 184, 158, 204, 184, 158, 206, 185, 159, 206, 185, 159,
-    208, 186, 160, 208, 186, 160, 210, 187, 162, 210, 187, 162, 211, 188, 163, 211, 188, 163, 213,
-    189, 164, 213, 189, 164, 215, 190, 166, 215, 190, 166, 217, 192, 167, 217, 192, 167, 218, 193,
-    168, 218, 193, 168, 220, 194, 170, 220, 194, 170, 222, 195, 171, 222, 195, 171, 223, 197, 173,
-    223, 197, 173, 225, 198, 175, 225, 198, 175, 227, 199, 176, 227, 199, 176, 228, 201, 178, 228,
-    201, 178, 230, 202, 180, 230, 202, 180, 231, 203, 181, 231, 203, 181, 232, 204, 183, 232, 204,
-    183, 234, 206, 185, 234, 206, 185, 235, 207, 187, 235, 207, 187, 236, 208, 189, 236, 208, 189,
-    237, 210, 190, 237, 210, 190, 239, 211, 192, 239, 211, 192, 240, 212, 194, 240, 212, 194, 241,
-    213, 196, 241, 213, 196, 242, 215, 198, 242, 215, 198, 243, 216, 200, 243, 216, 200, 244, 217,
-    201, 244, 217, 201, 244, 218, 203, 244, 218, 203, 245, 219, 205, 245, 219, 205, 246, 221, 207,
-    246, 221, 207, 247, 222, 209, 247, 222, 209, 247, 223, 211, 247, 223, 211, 248, 224, 212, 248,
-    224, 212, 249, 225, 214, 249, 225, 214, 249, 226, 216, 249, 226, 216, 250, 227, 218, 250, 227,
-    218, 250, 229, 220, 250, 229, 220, 251, 230, 221, 251, 230, 221, 251, 231, 223, 251, 231, 223,
-    251, 232, 225, 251, 232, 225, 252, 233, 227, 252, 233, 227, 252, 234, 229, 252, 234, 229, 252,
-    235, 230, 252, 235, 230, 253, 236, 232, 253, 236, 232, 253, 237, 234, 253, 237, 234, 253, 238,
-    236, 253, 238, 236, 254, 239, 237, 254, 239, 237, 254, 240, 239, 254, 240, 239, 254, 241, 241,
-    254, 241, 241, 254, 242, 243, 254, 242, 243,
-];
-
-pub const LISBON: &[u8] = &[
-    230, 229, 255, 230, 229, 255, 227, 227, 253, 227, 227, 253, 224, 226, 252, 224, 226, 252, 221,
-    224, 250, 221, 224, 250, 219, 222, 249, 219, 222, 249, 216, 220, 247, 216, 220, 247, 213, 218,
-    245, 213, 218, 245, 211, 216, 244, 211, 216, 244, 208, 214, 242, 208, 214, 242, 205, 212, 241,
-    205, 212, 241, 203, 210, 239, 203, 210, 239, 200, 208, 237, 200, 208, 237, 197, 206, 236, 197,
-    206, 236, 195, 204, 234, 195, 204, 234, 192, 202, 233, 192, 202, 233, 189, 200, 231, 189, 200,
-    231, 187, 198, 229, 187, 198, 229, 184, 196, 228, 184, 196, 228, 181, 194, 226, 181, 194, 226,
-    179, 192, 225, 179, 192, 225, 176, 190, 223, 176, 190, 223, 173, 188, 222, 173, 188, 222, 171,
-    186, 220, 171, 186, 220, 168, 185, 218, 168, 185, 218, 165, 183, 217, 165, 183, 217, 163, 181,
-    215, 163, 181, 215, 160, 179, 214, 160, 179, 214, 158, 177, 212, 158, 177, 212, 155, 175, 211,
-    155, 175, 211, 152, 173, 209, 152, 173, 209, 150, 171, 207, 150, 171, 207, 147, 169, 206, 147,
-    169, 206, 144, 167, 204, 144, 167, 204, 142, 165, 203, 142, 165, 203, 139, 163, 201, 139, 163,
-    201, 137, 162, 200, 137, 162, 200, 134, 160, 198, 134, 160, 198, 132, 158, 196, 132, 158, 196,
-    129, 156, 195, 129, 156, 195, 126, 154, 193, 126, 154, 193, 124, 152, 192, 124, 152, 192, 121,
-    150, 190, 121, 150, 190, 119, 148, 189, 119, 148, 189, 116, 146, 187, 116, 146, 187, 114, 144,
-    185, 114, 144, 185, 111, 142, 184, 111, 142, 184, 109, 141, 182, 109, 141, 182, 106, 139, 181,
-    106, 139, 181, 104, 137, 179, 104, 137, 179, 101, 135, 177, 101, 135, 177, 98, 133, 176, 98,
-    133, 176, 96, 131, 174, 96, 131, 174, 94, 129, 172, 94, 129, 172, 91, 127, 171, 91, 127, 171,
-    89, 125, 169, 89, 125, 169, 86, 123, 167, 86, 123, 167, 84, 121, 166, 84, 121, 166, 81, 119,
-    164, 81, 119, 164, 79, 117, 162, 79, 117, 162, 76, 115, 160, 76, 115, 160, 74, 114, 158, 74,
-    114, 158, 72, 112, 157, 72, 112, 157, 69, 110, 155, 69, 110, 155, 67, 108, 153, 67, 108, 153,
-    65, 106, 151, 65, 106, 151, 63, 104, 149, 63, 104, 149, 61, 102, 147, 61, 102, 147, 58, 100,
-    145, 58, 100, 145, 56, 98, 143, 56, 98, 143, 54, 96, 140, 54, 96, 140, 52, 94, 138, 52, 94,
-    138, 50, 92, 136, 50, 92, 136, 48, 90, 134, 48, 90, 134, 47, 89, 132, 47, 89, 132, 45, 87, 130,
-    45, 87, 130, 43, 85, 127, 43, 85, 127, 42, 83, 125, 42, 83, 125, 40, 81, 123, 40, 81, 123, 39,
-    79, 120, 39, 79, 120, 37, 78, 118, 37, 78, 118, 36, 76, 116, 36, 76, 116, 34, 74, 113, 34, 74,
-    113, 33, 72, 111, 33, 72, 111, 32, 71, 109, 32, 71, 109, 31, 69, 106, 31, 69, 106, 30, 67, 104,
-    30, 67, 104, 29, 66, 102, 29, 66, 102, 28, 64, 100, 28, 64, 100, 27, 63, 97, 27, 63, 97, 26,
-    61, 95, 26, 61, 95, 25, 60, 93, 25, 60, 93, 25, 58, 90, 25, 58, 90, 24, 57, 88, 24, 57, 88, 23,
-    55, 86, 23, 55, 86, 23, 54, 83, 23, 54, 83, 22, 52, 81, 22, 52, 81, 22, 51, 79, 22, 51, 79, 21,
-    49, 77, 21, 49, 77, 21, 48, 75, 21, 48, 75, 20, 46, 72, 20, 46, 72, 20, 45, 70, 20, 45, 70, 19,
-    44, 68, 19, 44, 68, 19, 42, 66, 19, 42, 66, 19, 41, 64, 19, 41, 64, 18, 40, 62, 18, 40, 62, 18,
-    39, 60, 18, 39, 60, 18, 37, 58, 18, 37, 58, 18, 36, 55, 18, 36, 55, 17, 35, 53, 17, 35, 53, 17,
-    34, 51, 17, 34, 51, 17, 33, 50, 17, 33, 50, 17, 32, 48, 17, 32, 48, 17, 31, 46, 17, 31, 46, 17,
-    30, 44, 17, 30, 44, 17, 29, 42, 17, 29, 42, 17, 28, 40, 17, 28, 40, 17, 27, 39, 17, 27, 39, 18,
-    27, 37, 18, 27, 37, 18, 26, 36, 18, 26, 36, 18, 26, 34, 18, 26, 34, 18, 25, 33, 18, 25, 33, 19,
-    25, 32, 19, 25, 32, 19, 25, 30, 19, 25, 30, 20, 24, 29, 20, 24, 29, 20, 24, 28, 20, 24, 28, 21,
-    24, 27, 21, 24, 27, 21, 24, 26, 21, 24, 26, 22, 25, 26, 22, 25, 26, 23, 25, 25, 23, 25, 25, 24,
-    25, 25, 24, 25, 25, 24, 26, 24, 24, 26, 24, 25, 26, 24, 25, 26, 24, 26, 27, 24, 26, 27, 24, 27,
-    28, 23, 27, 28, 23, 28, 28, 23, 28, 28, 23, 30, 29, 23, 30, 29, 23, 31, 30, 24, 31, 30, 24, 32,
-    31, 24, 32, 31, 24, 33, 32, 24, 33, 32, 24, 35, 33, 24, 35, 33, 24, 36, 35, 25, 36, 35, 25, 37,
-    36, 25, 37, 36, 25, 39, 37, 26, 39, 37, 26, 40, 38, 26, 40, 38, 26, 42, 40, 27, 42, 40, 27, 43,
-    41, 28, 43, 41, 28, 45, 43, 28, 45, 43, 28, 47, 44, 29, 47, 44, 29, 48, 45, 30, 48, 45, 30, 50,
-    47, 31, 50, 47, 31, 51, 48, 32, 51, 48, 32, 53, 50, 32, 53, 50, 32, 55, 52, 33, 55, 52, 33, 56,
-    53, 34, 56, 53, 34, 58, 55, 35, 58, 55, 35, 60, 56, 36, 60, 56, 36, 62, 58, 37, 62, 58, 37, 63,
-    60, 38, 63, 60, 38, 65, 61, 39, 65, 61, 39, 67, 63, 40, 67, 63, 40, 69, 64, 41, 69, 64, 41, 70,
-    66, 42, 70, 66, 42, 72, 68, 43, 72, 68, 43, 74, 69, 44, 74, 69, 44, 76, 71, 45, 76, 71, 45, 77,
-    73, 46, 77, 73, 46, 79, 75, 47, 79, 75, 47, 81, 76, 49, 81, 76, 49, 83, 78, 50, 83, 78, 50, 85,
-    80, 51, 85, 80, 51, 87, 81, 52, 87, 81, 52, 88, 83, 53, 88, 83, 53, 90, 85, 54, 90, 85, 54, 92,
-    87, 55, 92, 87, 55, 94, 88, 56, 94, 88, 56, 96, 90, 57, 96, 90, 57, 98, 92, 58, 98, 92, 58,
-    100, 94, 60, 100, 94, 60, 102, 96, 61, 102, 96, 61, 104, 97, 62, 104, 97, 62, 105, 99, 63, 105,
-    99, 63, 107, 101, 64, 107, 101, 64, 109, 103, 65, 109, 103, 65, 111, 105, 67, 111, 105, 67,
-    113, 107, 68, 113, 107, 68, 115, 108, 69, 115, 108, 69, 117, 110, 70, 117, 110, 70, 119, 112,
-    71, 119, 112, 71, 121, 114, 73, 121, 114, 73, 123, 116, 74, 123, 116, 74, 125, 118, 75, 125,
-    118, 75, 127, 120, 76, 127, 120, 76, 129, 122, 78, 129, 122, 78, 131, 123, 79, 131, 123, 79,
-    133, 125, 80, 133, 125, 80, 135, 127, 82, 135, 127, 82, 137, 129, 83, 137, 129, 83, 139, 131,
-    85, 139, 131, 85, 141, 133, 86, 141, 133, 86, 143, 135, 88, 143, 135, 88, 145, 137, 89, 145,
-    137, 89, 148, 139, 91, 148, 139, 91, 150, 141, 92, 150, 141, 92, 152, 143, 94, 152, 143, 94,
-    154, 145, 96, 154, 145, 96, 156, 147, 97, 156, 147, 97, 158, 150, 99, 158, 150, 99, 160, 152,
-    101, 160, 152, 101, 162, 154, 103, 162, 154, 103, 164, 156, 105, 164, 156, 105, 166, 158, 106,
-    166, 158, 106, 168, 160, 108, 168, 160, 108, 171, 162, 110, 171, 162, 110, 173, 164, 112, 173,
-    164, 112, 175, 167, 114, 175, 167, 114, 177, 169, 117, 177, 169, 117, 179, 171, 119, 179, 171,
-    119, 181, 173, 121, 181, 173, 121, 183, 175, 123, 183, 175, 123, 185, 177, 125, 185, 177, 125,
-    187, 179, 128, 187, 179, 128, 189, 182, 130, 189, 182, 130, 191, 184, 132, 191, 184, 132, 193,
-    186, 135, 193, 186, 135, 195, 188, 137, 195, 188, 137, 197, 190, 139, 197, 190, 139, 199, 192,
-    142, 199, 192, 142, 201, 195, 144, 201, 195, 144, 203, 197, 147, 203, 197, 147, 205, 199, 149,
-    205, 199, 149, 207, 201, 152, 207, 201, 152, 209, 203, 154, 209, 203, 154, 211, 205, 157, 211,
-    205, 157, 213, 207, 159, 213, 207, 159, 215, 210, 162, 215, 210, 162, 217, 212, 164, 217, 212,
-    164, 218, 214, 167, 218, 214, 167, 220, 216, 169, 220, 216, 169, 222, 218, 172, 222, 218, 172,
-    224, 220, 175, 224, 220, 175, 226, 222, 177, 226, 222, 177, 228, 225, 180, 228, 225, 180, 230,
-    227, 182, 230, 227, 182, 232, 229, 185, 232, 229, 185, 234, 231, 188, 234, 231, 188, 236, 233,
-    190, 236, 233, 190, 238, 235, 193, 238, 235, 193, 240, 238, 195, 240, 238, 195, 241, 240, 198,
-    241, 240, 198, 243, 242, 201, 243, 242, 201, 245, 244, 203, 245, 244, 203, 247, 246, 206, 247,
-    246, 206, 249, 248, 209, 249, 248, 209, 251, 251, 211, 251, 251, 211, 253, 253, 214, 253, 253,
-    214, 255, 255, 217, 255, 255, 217,
-];
-
-pub const NUUK: &[u8] = &[
-    5, 89, 140, 5, 89, 140, 7, 90, 140, 7, 90, 140, 9, 90, 140, 9, 90, 140, 11, 90, 139, 11, 90,
-    139, 13, 91, 139, 13, 91, 139, 14, 91, 139, 14, 91, 139, 16, 91, 138, 16, 91, 138, 17, 91, 138,
-    17, 91, 138, 19, 92, 137, 19, 92, 137, 20, 92, 137, 20, 92, 137, 21, 92, 137, 21, 92, 137, 23,
-    92, 136, 23, 92, 136, 24, 93, 136, 24, 93, 136, 25, 93, 136, 25, 93, 136, 26, 93, 135, 26, 93,
-    135, 27, 94, 135, 27, 94, 135, 28, 94, 135, 28, 94, 135, 30, 94, 134, 30, 94, 134, 31, 95, 134,
-    31, 95, 134, 32, 95, 134, 32, 95, 134, 33, 95, 134, 33, 95, 134, 34, 96, 133, 34, 96, 133, 35,
-    96, 133, 35, 96, 133, 36, 96, 133, 36, 96, 133, 37, 97, 133, 37, 97, 133, 38, 97, 132, 38, 97,
-    132, 39, 97, 132, 39, 97, 132, 40, 98, 132, 40, 98, 132, 41, 98, 132, 41, 98, 132, 42, 99, 131,
-    42, 99, 131, 43, 99, 131, 43, 99, 131, 44, 99, 131, 44, 99, 131, 45, 100, 131, 45, 100, 131,
-    46, 100, 131, 46, 100, 131, 47, 101, 131, 47, 101, 131, 48, 101, 130, 48, 101, 130, 50, 102,
-    130, 50, 102, 130, 51, 102, 130, 51, 102, 130, 52, 103, 130, 52, 103, 130, 53, 103, 130, 53,
-    103, 130, 54, 104, 130, 54, 104, 130, 55, 104, 130, 55, 104, 130, 56, 105, 130, 56, 105, 130,
-    57, 105, 130, 57, 105, 130, 59, 106, 130, 59, 106, 130, 60, 106, 130, 60, 106, 130, 61, 107,
-    130, 61, 107, 130, 62, 108, 130, 62, 108, 130, 63, 108, 130, 63, 108, 130, 64, 109, 130, 64,
-    109, 130, 66, 109, 130, 66, 109, 130, 67, 110, 130, 67, 110, 130, 68, 111, 130, 68, 111, 130,
-    69, 111, 130, 69, 111, 130, 70, 112, 131, 70, 112, 131, 72, 113, 131, 72, 113, 131, 73, 113,
-    131, 73, 113, 131, 74, 114, 131, 74, 114, 131, 75, 115, 131, 75, 115, 131, 77, 115, 132, 77,
-    115, 132, 78, 116, 132, 78, 116, 132, 79, 117, 132, 79, 117, 132, 81, 117, 132, 81, 117, 132,
-    82, 118, 133, 82, 118, 133, 83, 119, 133, 83, 119, 133, 85, 120, 133, 85, 120, 133, 86, 120,
-    134, 86, 120, 134, 87, 121, 134, 87, 121, 134, 88, 122, 134, 88, 122, 134, 90, 122, 135, 90,
-    122, 135, 91, 123, 135, 91, 123, 135, 92, 124, 135, 92, 124, 135, 94, 125, 136, 94, 125, 136,
-    95, 125, 136, 95, 125, 136, 96, 126, 136, 96, 126, 136, 98, 127, 137, 98, 127, 137, 99, 128,
-    137, 99, 128, 137, 100, 129, 138, 100, 129, 138, 102, 129, 138, 102, 129, 138, 103, 130, 138,
-    103, 130, 138, 104, 131, 139, 104, 131, 139, 106, 132, 139, 106, 132, 139, 107, 132, 140, 107,
-    132, 140, 108, 133, 140, 108, 133, 140, 110, 134, 140, 110, 134, 140, 111, 135, 141, 111, 135,
-    141, 112, 135, 141, 112, 135, 141, 114, 136, 142, 114, 136, 142, 115, 137, 142, 115, 137, 142,
-    116, 138, 143, 116, 138, 143, 118, 139, 143, 118, 139, 143, 119, 139, 143, 119, 139, 143, 120,
-    140, 144, 120, 140, 144, 121, 141, 144, 121, 141, 144, 123, 142, 145, 123, 142, 145, 124, 142,
-    145, 124, 142, 145, 125, 143, 145, 125, 143, 145, 127, 144, 146, 127, 144, 146, 128, 145, 146,
-    128, 145, 146, 129, 145, 146, 129, 145, 146, 130, 146, 147, 130, 146, 147, 132, 147, 147, 132,
-    147, 147, 133, 148, 147, 133, 148, 147, 134, 148, 148, 134, 148, 148, 135, 149, 148, 135, 149,
-    148, 137, 150, 148, 137, 150, 148, 138, 151, 149, 138, 151, 149, 139, 151, 149, 139, 151, 149,
-    140, 152, 149, 140, 152, 149, 141, 153, 150, 141, 153, 150, 142, 154, 150, 142, 154, 150, 144,
-    154, 150, 144, 154, 150, 145, 155, 150, 145, 155, 150, 146, 156, 150, 146, 156, 150, 147, 156,
-    151, 147, 156, 151, 148, 157, 151, 148, 157, 151, 149, 158, 151, 149, 158, 151, 150, 158, 151,
-    150, 158, 151, 151, 159, 151, 151, 159, 151, 152, 160, 151, 152, 160, 151, 153, 160, 151, 153,
-    160, 151, 154, 161, 152, 154, 161, 152, 155, 162, 152, 155, 162, 152, 156, 162, 152, 156, 162,
-    152, 157, 163, 152, 157, 163, 152, 158, 164, 152, 158, 164, 152, 159, 164, 152, 159, 164, 152,
-    160, 165, 152, 160, 165, 152, 161, 166, 152, 161, 166, 152, 162, 166, 152, 162, 166, 152, 162,
-    167, 152, 162, 167, 152, 163, 167, 152, 163, 167, 152, 164, 168, 151, 164, 168, 151, 165, 168,
-    151, 165, 168, 151, 166, 169, 151, 166, 169, 151, 166, 170, 151, 166, 170, 151, 167, 170, 151,
-    167, 170, 151, 168, 171, 151, 168, 171, 151, 169, 171, 151, 169, 171, 151, 169, 172, 151, 169,
-    172, 151, 170, 172, 150, 170, 172, 150, 171, 173, 150, 171, 173, 150, 171, 173, 150, 171, 173,
-    150, 172, 174, 150, 172, 174, 150, 173, 174, 149, 173, 174, 149, 173, 175, 149, 173, 175, 149,
-    174, 175, 149, 174, 175, 149, 174, 176, 149, 174, 176, 149, 175, 176, 148, 175, 176, 148, 176,
-    177, 148, 176, 177, 148, 176, 177, 148, 176, 177, 148, 177, 177, 148, 177, 177, 148, 177, 178,
-    147, 177, 178, 147, 178, 178, 147, 178, 178, 147, 178, 179, 147, 178, 179, 147, 179, 179, 146,
-    179, 179, 146, 179, 180, 146, 179, 180, 146, 180, 180, 146, 180, 180, 146, 180, 181, 145, 180,
-    181, 145, 181, 181, 145, 181, 181, 145, 181, 181, 145, 181, 181, 145, 182, 182, 144, 182, 182,
-    144, 182, 182, 144, 182, 182, 144, 183, 183, 144, 183, 183, 144, 183, 183, 143, 183, 183, 143,
-    183, 183, 143, 183, 183, 143, 184, 184, 142, 184, 184, 142, 184, 184, 142, 184, 184, 142, 185,
-    185, 142, 185, 185, 142, 185, 185, 141, 185, 185, 141, 186, 185, 141, 186, 185, 141, 186, 186,
-    141, 186, 186, 141, 186, 186, 140, 186, 186, 140, 187, 187, 140, 187, 187, 140, 187, 187, 139,
-    187, 187, 139, 188, 187, 139, 188, 187, 139, 188, 188, 139, 188, 188, 139, 189, 188, 138, 189,
-    188, 138, 189, 189, 138, 189, 189, 138, 189, 189, 138, 189, 189, 138, 190, 189, 137, 190, 189,
-    137, 190, 190, 137, 190, 190, 137, 191, 190, 136, 191, 190, 136, 191, 191, 136, 191, 191, 136,
-    192, 191, 136, 192, 191, 136, 192, 192, 135, 192, 192, 135, 192, 192, 135, 192, 192, 135, 193,
-    193, 135, 193, 193, 135, 193, 193, 134, 193, 193, 134, 194, 193, 134, 194, 193, 134, 194, 194,
-    134, 194, 194, 134, 195, 194, 133, 195, 194, 133, 195, 195, 133, 195, 195, 133, 196, 195, 133,
-    196, 195, 133, 196, 196, 133, 196, 196, 133, 197, 196, 132, 197, 196, 132, 197, 197, 132, 197,
-    197, 132, 198, 198, 132, 198, 198, 132, 199, 198, 132, 199, 198, 132, 199, 199, 132, 199, 199,
-    132, 200, 199, 131, 200, 199, 131, 200, 200, 131, 200, 200, 131, 201, 201, 131, 201, 201, 131,
-    202, 201, 131, 202, 201, 131, 202, 202, 131, 202, 202, 131, 203, 203, 131, 203, 203, 131, 204,
-    203, 131, 204, 203, 131, 205, 204, 131, 205, 204, 131, 205, 205, 131, 205, 205, 131, 206, 206,
-    131, 206, 206, 131, 207, 207, 132, 207, 207, 132, 208, 207, 132, 208, 207, 132, 209, 208, 132,
-    209, 208, 132, 210, 209, 132, 210, 209, 132, 210, 210, 133, 210, 210, 133, 211, 211, 133, 211,
-    211, 133, 212, 212, 134, 212, 212, 134, 213, 213, 134, 213, 213, 134, 214, 214, 135, 214, 214,
-    135, 215, 215, 135, 215, 215, 135, 216, 216, 136, 216, 216, 136, 217, 217, 136, 217, 217, 136,
-    219, 218, 137, 219, 218, 137, 220, 219, 138, 220, 219, 138, 221, 221, 139, 221, 221, 139, 222,
-    222, 140, 222, 222, 140, 223, 223, 141, 223, 223, 141, 224, 224, 142, 224, 224, 142, 225, 225,
-    143, 225, 225, 143, 227, 226, 144, 227, 226, 144, 228, 227, 145, 228, 227, 145, 229, 229, 146,
-    229, 229, 146, 230, 230, 147, 230, 230, 147, 231, 231, 148, 231, 231, 148, 232, 232, 149, 232,
-    232, 149, 233, 233, 151, 233, 233, 151, 235, 234, 152, 235, 234, 152, 236, 235, 153, 236, 235,
-    153, 237, 237, 154, 237, 237, 154, 238, 238, 156, 238, 238, 156, 239, 239, 157, 239, 239, 157,
-    240, 240, 158, 240, 240, 158, 241, 241, 160, 241, 241, 160, 242, 242, 161, 242, 242, 161, 243,
-    243, 163, 243, 243, 163, 244, 244, 164, 244, 244, 164, 245, 245, 165, 245, 245, 165, 246, 246,
-    167, 246, 246, 167, 247, 247, 168, 247, 247, 168, 248, 248, 170, 248, 248, 170, 249, 249, 171,
-    249, 249, 171, 250, 250, 173, 250, 250, 173, 251, 251, 174, 251, 251, 174, 252, 252, 175, 252,
-    252, 175, 253, 253, 177, 253, 253, 177, 254, 254, 178, 254, 254, 178,
-];
-
-pub const OLERON: &[u8] = &[
-    26, 38, 89, 26, 38, 89, 27, 40, 91, 27, 40, 91, 29, 41, 92, 29, 41, 92, 30, 43, 94, 30, 43, 94,
-    32, 44, 95, 32, 44, 95, 33, 46, 97, 33, 46, 97, 35, 47, 98, 35, 47, 98, 36, 49, 100, 36, 49,
-    100, 38, 50, 101, 38, 50, 101, 40, 52, 103, 40, 52, 103, 41, 53, 104, 41, 53, 104, 43, 55, 106,
-    43, 55, 106, 44, 56, 107, 44, 56, 107, 46, 58, 109, 46, 58, 109, 47, 59, 111, 47, 59, 111, 49,
-    61, 112, 49, 61, 112, 50, 63, 114, 50, 63, 114, 52, 64, 115, 52, 64, 115, 53, 66, 117, 53, 66,
-    117, 55, 67, 118, 55, 67, 118, 57, 69, 120, 57, 69, 120, 58, 71, 122, 58, 71, 122, 60, 72, 123,
-    60, 72, 123, 61, 74, 125, 61, 74, 125, 63, 75, 126, 63, 75, 126, 65, 77, 128, 65, 77, 128, 66,
-    79, 130, 66, 79, 130, 68, 80, 131, 68, 80, 131, 69, 82, 133, 69, 82, 133, 71, 84, 135, 71, 84,
-    135, 73, 85, 136, 73, 85, 136, 74, 87, 138, 74, 87, 138, 76, 89, 140, 76, 89, 140, 78, 90, 141,
-    78, 90, 141, 79, 92, 143, 79, 92, 143, 81, 94, 145, 81, 94, 145, 83, 95, 146, 83, 95, 146, 84,
-    97, 148, 84, 97, 148, 86, 99, 150, 86, 99, 150, 88, 100, 151, 88, 100, 151, 89, 102, 153, 89,
-    102, 153, 91, 104, 155, 91, 104, 155, 93, 105, 156, 93, 105, 156, 94, 107, 158, 94, 107, 158,
-    96, 109, 160, 96, 109, 160, 98, 111, 162, 98, 111, 162, 100, 112, 163, 100, 112, 163, 101, 114,
-    165, 101, 114, 165, 103, 116, 167, 103, 116, 167, 105, 117, 169, 105, 117, 169, 107, 119, 170,
-    107, 119, 170, 108, 121, 172, 108, 121, 172, 110, 123, 174, 110, 123, 174, 112, 125, 176, 112,
-    125, 176, 114, 126, 177, 114, 126, 177, 115, 128, 179, 115, 128, 179, 117, 130, 181, 117, 130,
-    181, 119, 132, 183, 119, 132, 183, 121, 133, 184, 121, 133, 184, 122, 135, 186, 122, 135, 186,
-    124, 137, 188, 124, 137, 188, 126, 139, 190, 126, 139, 190, 128, 141, 192, 128, 141, 192, 130,
-    142, 193, 130, 142, 193, 131, 144, 195, 131, 144, 195, 133, 146, 197, 133, 146, 197, 135, 148,
-    199, 135, 148, 199, 137, 150, 201, 137, 150, 201, 139, 151, 202, 139, 151, 202, 141, 153, 204,
-    141, 153, 204, 142, 155, 206, 142, 155, 206, 144, 157, 208, 144, 157, 208, 146, 159, 210, 146,
-    159, 210, 148, 161, 211, 148, 161, 211, 150, 162, 213, 150, 162, 213, 152, 164, 215, 152, 164,
-    215, 153, 166, 217, 153, 166, 217, 155, 168, 219, 155, 168, 219, 157, 170, 220, 157, 170, 220,
-    159, 172, 222, 159, 172, 222, 161, 173, 224, 161, 173, 224, 163, 175, 225, 163, 175, 225, 164,
-    177, 227, 164, 177, 227, 166, 179, 229, 166, 179, 229, 168, 181, 230, 168, 181, 230, 170, 183,
-    232, 170, 183, 232, 172, 184, 233, 172, 184, 233, 173, 186, 234, 173, 186, 234, 175, 188, 236,
-    175, 188, 236, 177, 189, 237, 177, 189, 237, 178, 191, 238, 178, 191, 238, 180, 193, 239, 180,
-    193, 239, 182, 194, 240, 182, 194, 240, 183, 196, 241, 183, 196, 241, 185, 198, 242, 185, 198,
-    242, 186, 199, 243, 186, 199, 243, 188, 201, 243, 188, 201, 243, 189, 202, 244, 189, 202, 244,
-    191, 203, 244, 191, 203, 244, 192, 205, 245, 192, 205, 245, 194, 206, 245, 194, 206, 245, 195,
-    208, 246, 195, 208, 246, 196, 209, 246, 196, 209, 246, 198, 210, 247, 198, 210, 247, 199, 212,
-    247, 199, 212, 247, 200, 213, 248, 200, 213, 248, 202, 214, 248, 202, 214, 248, 203, 216, 248,
-    203, 216, 248, 204, 217, 249, 204, 217, 249, 206, 218, 249, 206, 218, 249, 207, 220, 249, 207,
-    220, 249, 208, 221, 250, 208, 221, 250, 210, 222, 250, 210, 222, 250, 211, 224, 250, 211, 224,
-    250, 212, 225, 251, 212, 225, 251, 214, 226, 251, 214, 226, 251, 215, 228, 251, 215, 228, 251,
-    216, 229, 252, 216, 229, 252, 218, 230, 252, 218, 230, 252, 219, 232, 252, 219, 232, 252, 220,
-    233, 253, 220, 233, 253, 222, 234, 253, 222, 234, 253, 223, 236, 253, 223, 236, 253, 224, 237,
-    254, 224, 237, 254, 226, 238, 254, 226, 238, 254, 227, 240, 254, 227, 240, 254, 228, 241, 255,
-    228, 241, 255, 230, 242, 255, 230, 242, 255, 26, 76, 0, 26, 76, 0, 29, 77, 0, 29, 77, 0, 31,
-    78, 0, 31, 78, 0, 34, 79, 0, 34, 79, 0, 37, 79, 0, 37, 79, 0, 39, 80, 0, 39, 80, 0, 42, 81, 0,
-    42, 81, 0, 44, 81, 0, 44, 81, 0, 47, 82, 0, 47, 82, 0, 49, 83, 0, 49, 83, 0, 51, 84, 0, 51, 84,
-    0, 53, 84, 0, 53, 84, 0, 56, 85, 0, 56, 85, 0, 58, 86, 0, 58, 86, 0, 60, 86, 0, 60, 86, 0, 62,
-    87, 0, 62, 87, 0, 64, 87, 0, 64, 87, 0, 66, 88, 0, 66, 88, 0, 68, 89, 0, 68, 89, 0, 70, 89, 0,
-    70, 89, 0, 73, 90, 1, 73, 90, 1, 75, 91, 1, 75, 91, 1, 77, 92, 1, 77, 92, 1, 79, 92, 2, 79, 92,
-    2, 81, 93, 2, 81, 93, 2, 83, 94, 2, 83, 94, 2, 85, 95, 3, 85, 95, 3, 87, 96, 4, 87, 96, 4, 90,
-    96, 5, 90, 96, 5, 92, 97, 6, 92, 97, 6, 94, 98, 7, 94, 98, 7, 96, 99, 9, 96, 99, 9, 99, 100,
-    10, 99, 100, 10, 101, 102, 12, 101, 102, 12, 103, 103, 14, 103, 103, 14, 106, 104, 16, 106,
-    104, 16, 108, 105, 18, 108, 105, 18, 110, 106, 20, 110, 106, 20, 113, 108, 22, 113, 108, 22,
-    115, 109, 24, 115, 109, 24, 117, 110, 26, 117, 110, 26, 120, 112, 29, 120, 112, 29, 122, 113,
-    31, 122, 113, 31, 124, 114, 33, 124, 114, 33, 126, 116, 35, 126, 116, 35, 129, 117, 37, 129,
-    117, 37, 131, 118, 40, 131, 118, 40, 133, 120, 42, 133, 120, 42, 135, 121, 44, 135, 121, 44,
-    138, 123, 46, 138, 123, 46, 140, 124, 49, 140, 124, 49, 142, 125, 51, 142, 125, 51, 144, 127,
-    53, 144, 127, 53, 146, 128, 55, 146, 128, 55, 148, 130, 58, 148, 130, 58, 151, 131, 60, 151,
-    131, 60, 153, 132, 62, 153, 132, 62, 155, 134, 64, 155, 134, 64, 157, 135, 67, 157, 135, 67,
-    159, 137, 69, 159, 137, 69, 161, 138, 71, 161, 138, 71, 163, 140, 73, 163, 140, 73, 166, 141,
-    76, 166, 141, 76, 168, 143, 78, 168, 143, 78, 170, 144, 80, 170, 144, 80, 172, 146, 83, 172,
-    146, 83, 174, 147, 85, 174, 147, 85, 177, 149, 87, 177, 149, 87, 179, 151, 89, 179, 151, 89,
-    181, 152, 92, 181, 152, 92, 183, 154, 94, 183, 154, 94, 186, 156, 96, 186, 156, 96, 188, 157,
-    99, 188, 157, 99, 190, 159, 101, 190, 159, 101, 193, 161, 103, 193, 161, 103, 195, 163, 106,
-    195, 163, 106, 197, 164, 108, 197, 164, 108, 199, 166, 110, 199, 166, 110, 202, 168, 113, 202,
-    168, 113, 204, 170, 115, 204, 170, 115, 206, 172, 117, 206, 172, 117, 209, 173, 120, 209, 173,
-    120, 211, 175, 122, 211, 175, 122, 213, 177, 124, 213, 177, 124, 215, 179, 127, 215, 179, 127,
-    217, 181, 129, 217, 181, 129, 220, 182, 132, 220, 182, 132, 222, 184, 134, 222, 184, 134, 224,
-    186, 137, 224, 186, 137, 226, 188, 139, 226, 188, 139, 228, 190, 142, 228, 190, 142, 229, 192,
-    144, 229, 192, 144, 231, 194, 147, 231, 194, 147, 233, 196, 149, 233, 196, 149, 234, 197, 152,
-    234, 197, 152, 236, 199, 154, 236, 199, 154, 237, 201, 157, 237, 201, 157, 238, 203, 159, 238,
-    203, 159, 240, 205, 162, 240, 205, 162, 241, 206, 164, 241, 206, 164, 242, 208, 167, 242, 208,
-    167, 242, 210, 169, 242, 210, 169, 243, 212, 171, 243, 212, 171, 244, 213, 174, 244, 213, 174,
-    245, 215, 176, 245, 215, 176, 245, 217, 178, 245, 217, 178, 246, 218, 181, 246, 218, 181, 246,
-    220, 183, 246, 220, 183, 247, 222, 185, 247, 222, 185, 247, 223, 188, 247, 223, 188, 247, 225,
-    190, 247, 225, 190, 248, 226, 192, 248, 226, 192, 248, 228, 195, 248, 228, 195, 248, 230, 197,
-    248, 230, 197, 249, 231, 199, 249, 231, 199, 249, 233, 201, 249, 233, 201, 249, 234, 204, 249,
-    234, 204, 250, 236, 206, 250, 236, 206, 250, 238, 208, 250, 238, 208, 250, 239, 211, 250, 239,
-    211, 251, 241, 213, 251, 241, 213, 251, 243, 215, 251, 243, 215, 251, 244, 218, 251, 244, 218,
-    251, 246, 220, 251, 246, 220, 252, 248, 222, 252, 248, 222, 252, 249, 225, 252, 249, 225, 252,
-    251, 227, 252, 251, 227, 253, 253, 230, 253, 253, 230,
-];
-
-pub const OSLO: &[u8] = &[
-    1, 1, 1, 1, 1, 1, 1, 2, 3, 1, 2, 3, 2, 4, 5, 2, 4, 5, 2, 5, 7, 2, 5, 7, 3, 6, 9, 3, 6, 9, 4, 7,
-    11, 4, 7, 11, 4, 8, 13, 4, 8, 13, 5, 9, 15, 5, 9, 15, 5, 10, 16, 5, 10, 16, 6, 11, 18, 6, 11,
-    18, 6, 12, 19, 6, 12, 19, 7, 13, 21, 7, 13, 21, 8, 14, 22, 8, 14, 22, 8, 15, 23, 8, 15, 23, 9,
-    16, 24, 9, 16, 24, 9, 17, 25, 9, 17, 25, 10, 18, 27, 10, 18, 27, 11, 19, 28, 11, 19, 28, 11,
-    19, 29, 11, 19, 29, 11, 20, 30, 11, 20, 30, 12, 21, 31, 12, 21, 31, 12, 22, 32, 12, 22, 32, 13,
-    22, 34, 13, 22, 34, 13, 23, 35, 13, 23, 35, 13, 24, 36, 13, 24, 36, 13, 25, 37, 13, 25, 37, 13,
-    25, 39, 13, 25, 39, 13, 26, 40, 13, 26, 40, 13, 27, 41, 13, 27, 41, 14, 27, 42, 14, 27, 42, 14,
-    28, 44, 14, 28, 44, 14, 29, 45, 14, 29, 45, 14, 30, 46, 14, 30, 46, 14, 30, 47, 14, 30, 47, 14,
-    31, 49, 14, 31, 49, 14, 32, 50, 14, 32, 50, 15, 33, 51, 15, 33, 51, 15, 33, 53, 15, 33, 53, 15,
-    34, 54, 15, 34, 54, 15, 35, 55, 15, 35, 55, 15, 36, 57, 15, 36, 57, 15, 37, 58, 15, 37, 58, 16,
-    38, 60, 16, 38, 60, 16, 38, 61, 16, 38, 61, 16, 39, 62, 16, 39, 62, 16, 40, 64, 16, 40, 64, 16,
-    41, 65, 16, 41, 65, 17, 42, 67, 17, 42, 67, 17, 43, 68, 17, 43, 68, 17, 43, 69, 17, 43, 69, 17,
-    44, 71, 17, 44, 71, 18, 45, 72, 18, 45, 72, 18, 46, 74, 18, 46, 74, 18, 47, 75, 18, 47, 75, 18,
-    48, 76, 18, 48, 76, 19, 49, 78, 19, 49, 78, 19, 50, 79, 19, 50, 79, 19, 50, 81, 19, 50, 81, 20,
-    51, 82, 20, 51, 82, 20, 52, 84, 20, 52, 84, 20, 53, 85, 20, 53, 85, 20, 54, 87, 20, 54, 87, 21,
-    55, 88, 21, 55, 88, 21, 56, 90, 21, 56, 90, 21, 57, 91, 21, 57, 91, 22, 58, 93, 22, 58, 93, 22,
-    59, 94, 22, 59, 94, 23, 59, 96, 23, 59, 96, 23, 60, 97, 23, 60, 97, 23, 61, 99, 23, 61, 99, 24,
-    62, 100, 24, 62, 100, 24, 63, 102, 24, 63, 102, 25, 64, 103, 25, 64, 103, 25, 65, 105, 25, 65,
-    105, 25, 66, 106, 25, 66, 106, 26, 67, 108, 26, 67, 108, 26, 68, 109, 26, 68, 109, 27, 69, 111,
-    27, 69, 111, 27, 70, 112, 27, 70, 112, 28, 71, 114, 28, 71, 114, 28, 72, 115, 28, 72, 115, 29,
-    72, 117, 29, 72, 117, 29, 73, 118, 29, 73, 118, 30, 74, 120, 30, 74, 120, 30, 75, 122, 30, 75,
-    122, 31, 76, 123, 31, 76, 123, 32, 77, 125, 32, 77, 125, 32, 78, 126, 32, 78, 126, 33, 79, 128,
-    33, 79, 128, 33, 80, 129, 33, 80, 129, 34, 81, 131, 34, 81, 131, 35, 82, 133, 35, 82, 133, 35,
-    83, 134, 35, 83, 134, 36, 84, 136, 36, 84, 136, 37, 85, 137, 37, 85, 137, 38, 86, 139, 38, 86,
-    139, 38, 87, 140, 38, 87, 140, 39, 88, 142, 39, 88, 142, 40, 89, 144, 40, 89, 144, 41, 90, 145,
-    41, 90, 145, 42, 91, 147, 42, 91, 147, 43, 92, 148, 43, 92, 148, 44, 93, 150, 44, 93, 150, 45,
-    94, 152, 45, 94, 152, 46, 95, 153, 46, 95, 153, 47, 96, 155, 47, 96, 155, 48, 97, 156, 48, 97,
-    156, 49, 98, 158, 49, 98, 158, 50, 99, 160, 50, 99, 160, 51, 100, 161, 51, 100, 161, 52, 101,
-    163, 52, 101, 163, 54, 102, 164, 54, 102, 164, 55, 103, 166, 55, 103, 166, 56, 105, 168, 56,
-    105, 168, 58, 106, 169, 58, 106, 169, 59, 107, 171, 59, 107, 171, 61, 108, 172, 61, 108, 172,
-    62, 109, 174, 62, 109, 174, 64, 110, 175, 64, 110, 175, 65, 112, 177, 65, 112, 177, 67, 113,
-    178, 67, 113, 178, 68, 114, 179, 68, 114, 179, 70, 115, 181, 70, 115, 181, 71, 117, 182, 71,
-    117, 182, 73, 118, 184, 73, 118, 184, 75, 119, 185, 75, 119, 185, 76, 120, 186, 76, 120, 186,
-    78, 122, 187, 78, 122, 187, 80, 123, 188, 80, 123, 188, 81, 124, 189, 81, 124, 189, 83, 125,
-    190, 83, 125, 190, 84, 126, 191, 84, 126, 191, 86, 127, 192, 86, 127, 192, 88, 129, 193, 88,
-    129, 193, 89, 130, 194, 89, 130, 194, 91, 131, 195, 91, 131, 195, 92, 132, 195, 92, 132, 195,
-    94, 133, 196, 94, 133, 196, 96, 134, 197, 96, 134, 197, 97, 135, 197, 97, 135, 197, 99, 136,
-    198, 99, 136, 198, 100, 137, 198, 100, 137, 198, 101, 138, 199, 101, 138, 199, 103, 139, 199,
-    103, 139, 199, 104, 140, 199, 104, 140, 199, 106, 141, 200, 106, 141, 200, 107, 142, 200, 107,
-    142, 200, 108, 143, 200, 108, 143, 200, 110, 144, 200, 110, 144, 200, 111, 144, 201, 111, 144,
-    201, 112, 145, 201, 112, 145, 201, 114, 146, 201, 114, 146, 201, 115, 147, 201, 115, 147, 201,
-    116, 148, 201, 116, 148, 201, 117, 148, 201, 117, 148, 201, 119, 149, 201, 119, 149, 201, 120,
-    150, 201, 120, 150, 201, 121, 151, 202, 121, 151, 202, 122, 152, 202, 122, 152, 202, 123, 152,
-    202, 123, 152, 202, 125, 153, 202, 125, 153, 202, 126, 154, 202, 126, 154, 202, 127, 155, 202,
-    127, 155, 202, 128, 155, 202, 128, 155, 202, 129, 156, 202, 129, 156, 202, 131, 157, 202, 131,
-    157, 202, 132, 158, 202, 132, 158, 202, 133, 158, 202, 133, 158, 202, 134, 159, 202, 134, 159,
-    202, 135, 160, 202, 135, 160, 202, 137, 160, 202, 137, 160, 202, 138, 161, 202, 138, 161, 202,
-    139, 162, 201, 139, 162, 201, 140, 163, 201, 140, 163, 201, 141, 163, 201, 141, 163, 201, 142,
-    164, 201, 142, 164, 201, 144, 165, 201, 144, 165, 201, 145, 166, 201, 145, 166, 201, 146, 166,
-    201, 146, 166, 201, 147, 167, 201, 147, 167, 201, 148, 168, 201, 148, 168, 201, 150, 169, 201,
-    150, 169, 201, 151, 169, 201, 151, 169, 201, 152, 170, 201, 152, 170, 201, 153, 171, 201, 153,
-    171, 201, 154, 172, 201, 154, 172, 201, 156, 172, 201, 156, 172, 201, 157, 173, 201, 157, 173,
-    201, 158, 174, 201, 158, 174, 201, 159, 175, 201, 159, 175, 201, 160, 175, 201, 160, 175, 201,
-    162, 176, 202, 162, 176, 202, 163, 177, 202, 163, 177, 202, 164, 178, 202, 164, 178, 202, 165,
-    179, 202, 165, 179, 202, 167, 180, 202, 167, 180, 202, 168, 180, 202, 168, 180, 202, 169, 181,
-    202, 169, 181, 202, 170, 182, 202, 170, 182, 202, 172, 183, 202, 172, 183, 202, 173, 184, 203,
-    173, 184, 203, 174, 185, 203, 174, 185, 203, 176, 186, 203, 176, 186, 203, 177, 187, 203, 177,
-    187, 203, 178, 188, 204, 178, 188, 204, 180, 189, 204, 180, 189, 204, 181, 189, 204, 181, 189,
-    204, 182, 190, 205, 182, 190, 205, 184, 191, 205, 184, 191, 205, 185, 193, 205, 185, 193, 205,
-    186, 194, 206, 186, 194, 206, 188, 195, 206, 188, 195, 206, 189, 196, 207, 189, 196, 207, 191,
-    197, 207, 191, 197, 207, 192, 198, 208, 192, 198, 208, 194, 199, 209, 194, 199, 209, 195, 200,
-    209, 195, 200, 209, 197, 201, 210, 197, 201, 210, 198, 203, 211, 198, 203, 211, 199, 204, 211,
-    199, 204, 211, 201, 205, 212, 201, 205, 212, 202, 206, 213, 202, 206, 213, 204, 208, 214, 204,
-    208, 214, 206, 209, 215, 206, 209, 215, 207, 210, 216, 207, 210, 216, 209, 211, 217, 209, 211,
-    217, 210, 213, 218, 210, 213, 218, 212, 214, 219, 212, 214, 219, 213, 216, 220, 213, 216, 220,
-    215, 217, 221, 215, 217, 221, 216, 218, 222, 216, 218, 222, 218, 220, 223, 218, 220, 223, 219,
-    221, 224, 219, 221, 224, 221, 222, 225, 221, 222, 225, 222, 224, 226, 222, 224, 226, 224, 225,
-    228, 224, 225, 228, 226, 227, 229, 226, 227, 229, 227, 228, 230, 227, 228, 230, 229, 230, 231,
-    229, 230, 231, 230, 231, 233, 230, 231, 233, 232, 233, 234, 232, 233, 234, 233, 234, 235, 233,
-    234, 235, 235, 236, 237, 235, 236, 237, 236, 237, 238, 236, 237, 238, 238, 238, 239, 238, 238,
-    239, 239, 240, 241, 239, 240, 241, 241, 241, 242, 241, 241, 242, 243, 243, 244, 243, 243, 244,
-    244, 244, 245, 244, 244, 245, 246, 246, 246, 246, 246, 246, 247, 247, 248, 247, 247, 248, 249,
-    249, 249, 249, 249, 249, 250, 250, 251, 250, 250, 251, 252, 252, 252, 252, 252, 252, 253, 253,
-    254, 253, 253, 254, 255, 255, 255, 255, 255, 255,
-];
-
-pub const ROMA: &[u8] = &[
-    127, 25, 0, 127, 25, 0, 128, 28, 1, 128, 28, 1, 129, 31, 2, 129, 31, 2, 130, 34, 3, 130, 34, 3,
-    131, 37, 4, 131, 37, 4, 132, 39, 5, 132, 39, 5, 134, 42, 5, 134, 42, 5, 135, 44, 6, 135, 44, 6,
-    136, 47, 7, 136, 47, 7, 137, 49, 8, 137, 49, 8, 138, 51, 8, 138, 51, 8, 139, 53, 9, 139, 53, 9,
-    140, 56, 10, 140, 56, 10, 141, 58, 11, 141, 58, 11, 143, 60, 12, 143, 60, 12, 144, 62, 13, 144,
-    62, 13, 145, 64, 14, 145, 64, 14, 146, 66, 15, 146, 66, 15, 147, 68, 16, 147, 68, 16, 148, 71,
-    16, 148, 71, 16, 149, 73, 17, 149, 73, 17, 150, 75, 18, 150, 75, 18, 151, 77, 19, 151, 77, 19,
-    152, 79, 20, 152, 79, 20, 153, 81, 21, 153, 81, 21, 154, 83, 22, 154, 83, 22, 155, 85, 22, 155,
-    85, 22, 156, 87, 23, 156, 87, 23, 157, 89, 24, 157, 89, 24, 158, 91, 25, 158, 91, 25, 159, 93,
-    26, 159, 93, 26, 160, 95, 27, 160, 95, 27, 161, 97, 28, 161, 97, 28, 162, 99, 28, 162, 99, 28,
-    163, 101, 29, 163, 101, 29, 164, 103, 30, 164, 103, 30, 165, 105, 31, 165, 105, 31, 166, 107,
-    32, 166, 107, 32, 167, 109, 33, 167, 109, 33, 168, 111, 33, 168, 111, 33, 169, 113, 34, 169,
-    113, 34, 170, 114, 35, 170, 114, 35, 171, 116, 36, 171, 116, 36, 172, 118, 37, 172, 118, 37,
-    173, 120, 38, 173, 120, 38, 173, 122, 39, 173, 122, 39, 174, 124, 39, 174, 124, 39, 175, 126,
-    40, 175, 126, 40, 176, 128, 41, 176, 128, 41, 177, 130, 42, 177, 130, 42, 178, 132, 43, 178,
-    132, 43, 179, 134, 44, 179, 134, 44, 180, 136, 45, 180, 136, 45, 181, 138, 46, 181, 138, 46,
-    182, 140, 47, 182, 140, 47, 183, 143, 48, 183, 143, 48, 184, 145, 49, 184, 145, 49, 185, 147,
-    51, 185, 147, 51, 186, 149, 52, 186, 149, 52, 187, 151, 53, 187, 151, 53, 188, 153, 54, 188,
-    153, 54, 189, 155, 56, 189, 155, 56, 191, 157, 57, 191, 157, 57, 192, 159, 58, 192, 159, 58,
-    193, 162, 60, 193, 162, 60, 194, 164, 62, 194, 164, 62, 195, 166, 63, 195, 166, 63, 196, 168,
-    65, 196, 168, 65, 197, 171, 67, 197, 171, 67, 198, 173, 69, 198, 173, 69, 200, 175, 71, 200,
-    175, 71, 201, 177, 73, 201, 177, 73, 202, 180, 75, 202, 180, 75, 203, 182, 77, 203, 182, 77,
-    205, 184, 79, 205, 184, 79, 206, 187, 82, 206, 187, 82, 207, 189, 84, 207, 189, 84, 208, 191,
-    87, 208, 191, 87, 209, 193, 89, 209, 193, 89, 211, 195, 92, 211, 195, 92, 212, 198, 95, 212,
-    198, 95, 213, 200, 97, 213, 200, 97, 214, 202, 100, 214, 202, 100, 215, 204, 103, 215, 204,
-    103, 216, 206, 106, 216, 206, 106, 217, 207, 109, 217, 207, 109, 218, 209, 111, 218, 209, 111,
-    219, 211, 114, 219, 211, 114, 220, 213, 117, 220, 213, 117, 221, 214, 120, 221, 214, 120, 222,
-    216, 123, 222, 216, 123, 222, 217, 126, 222, 217, 126, 223, 219, 128, 223, 219, 128, 224, 220,
-    131, 224, 220, 131, 224, 221, 134, 224, 221, 134, 225, 222, 137, 225, 222, 137, 225, 223, 139,
-    225, 223, 139, 226, 224, 142, 226, 224, 142, 226, 225, 144, 226, 225, 144, 226, 226, 147, 226,
-    226, 147, 227, 227, 149, 227, 227, 149, 227, 228, 152, 227, 228, 152, 227, 229, 154, 227, 229,
-    154, 227, 229, 157, 227, 229, 157, 227, 230, 159, 227, 230, 159, 227, 231, 161, 227, 231, 161,
-    226, 231, 163, 226, 231, 163, 226, 232, 166, 226, 232, 166, 226, 232, 168, 226, 232, 168, 225,
-    233, 170, 225, 233, 170, 225, 233, 172, 225, 233, 172, 224, 234, 174, 224, 234, 174, 224, 234,
-    176, 224, 234, 176, 223, 234, 178, 223, 234, 178, 222, 234, 180, 222, 234, 180, 221, 235, 182,
-    221, 235, 182, 220, 235, 183, 220, 235, 183, 219, 235, 185, 219, 235, 185, 218, 235, 187, 218,
-    235, 187, 217, 235, 188, 217, 235, 188, 215, 235, 190, 215, 235, 190, 214, 236, 192, 214, 236,
-    192, 212, 236, 193, 212, 236, 193, 211, 236, 195, 211, 236, 195, 209, 236, 196, 209, 236, 196,
-    208, 235, 197, 208, 235, 197, 206, 235, 199, 206, 235, 199, 204, 235, 200, 204, 235, 200, 202,
-    235, 201, 202, 235, 201, 200, 235, 202, 200, 235, 202, 198, 235, 203, 198, 235, 203, 196, 234,
-    204, 196, 234, 204, 194, 234, 205, 194, 234, 205, 191, 234, 206, 191, 234, 206, 189, 233, 207,
-    189, 233, 207, 187, 233, 208, 187, 233, 208, 184, 232, 209, 184, 232, 209, 182, 232, 210, 182,
-    232, 210, 179, 231, 211, 179, 231, 211, 177, 230, 211, 177, 230, 211, 174, 230, 212, 174, 230,
-    212, 171, 229, 212, 171, 229, 212, 169, 228, 213, 169, 228, 213, 166, 227, 213, 166, 227, 213,
-    163, 227, 214, 163, 227, 214, 160, 226, 214, 160, 226, 214, 158, 225, 215, 158, 225, 215, 155,
-    224, 215, 155, 224, 215, 152, 223, 215, 152, 223, 215, 149, 221, 215, 149, 221, 215, 146, 220,
-    215, 146, 220, 215, 143, 219, 216, 143, 219, 216, 141, 218, 216, 141, 218, 216, 138, 216, 216,
-    138, 216, 216, 135, 215, 216, 135, 215, 216, 132, 214, 216, 132, 214, 216, 130, 212, 216, 130,
-    212, 216, 127, 211, 215, 127, 211, 215, 124, 209, 215, 124, 209, 215, 122, 208, 215, 122, 208,
-    215, 119, 206, 215, 119, 206, 215, 117, 204, 214, 117, 204, 214, 114, 203, 214, 114, 203, 214,
-    112, 201, 214, 112, 201, 214, 110, 199, 213, 110, 199, 213, 107, 198, 213, 107, 198, 213, 105,
-    196, 213, 105, 196, 213, 103, 194, 212, 103, 194, 212, 101, 193, 212, 101, 193, 212, 99, 191,
-    211, 99, 191, 211, 97, 189, 211, 97, 189, 211, 96, 187, 210, 96, 187, 210, 94, 186, 209, 94,
-    186, 209, 92, 184, 209, 92, 184, 209, 91, 182, 208, 91, 182, 208, 89, 180, 208, 89, 180, 208,
-    88, 178, 207, 88, 178, 207, 87, 177, 206, 87, 177, 206, 85, 175, 206, 85, 175, 206, 84, 173,
-    205, 84, 173, 205, 83, 171, 204, 83, 171, 204, 82, 170, 204, 82, 170, 204, 80, 168, 203, 80,
-    168, 203, 79, 166, 202, 79, 166, 202, 78, 164, 201, 78, 164, 201, 77, 163, 201, 77, 163, 201,
-    76, 161, 200, 76, 161, 200, 75, 159, 199, 75, 159, 199, 74, 157, 199, 74, 157, 199, 74, 156,
-    198, 74, 156, 198, 73, 154, 197, 73, 154, 197, 72, 152, 197, 72, 152, 197, 71, 150, 196, 71,
-    150, 196, 70, 149, 195, 70, 149, 195, 69, 147, 194, 69, 147, 194, 69, 145, 194, 69, 145, 194,
-    68, 144, 193, 68, 144, 193, 67, 142, 192, 67, 142, 192, 66, 140, 191, 66, 140, 191, 66, 138,
-    191, 66, 138, 191, 65, 137, 190, 65, 137, 190, 64, 135, 189, 64, 135, 189, 64, 133, 189, 64,
-    133, 189, 63, 132, 188, 63, 132, 188, 62, 130, 187, 62, 130, 187, 61, 129, 187, 61, 129, 187,
-    61, 127, 186, 61, 127, 186, 60, 125, 185, 60, 125, 185, 59, 124, 184, 59, 124, 184, 59, 122,
-    184, 59, 122, 184, 58, 120, 183, 58, 120, 183, 57, 119, 182, 57, 119, 182, 57, 117, 182, 57,
-    117, 182, 56, 116, 181, 56, 116, 181, 56, 114, 180, 56, 114, 180, 55, 112, 180, 55, 112, 180,
-    54, 111, 179, 54, 111, 179, 54, 109, 178, 54, 109, 178, 53, 108, 177, 53, 108, 177, 52, 106,
-    177, 52, 106, 177, 52, 104, 176, 52, 104, 176, 51, 103, 175, 51, 103, 175, 50, 101, 175, 50,
-    101, 175, 50, 100, 174, 50, 100, 174, 49, 98, 173, 49, 98, 173, 48, 97, 173, 48, 97, 173, 48,
-    95, 172, 48, 95, 172, 47, 94, 171, 47, 94, 171, 47, 92, 171, 47, 92, 171, 46, 90, 170, 46, 90,
-    170, 45, 89, 169, 45, 89, 169, 45, 87, 169, 45, 87, 169, 44, 86, 168, 44, 86, 168, 43, 84, 167,
-    43, 84, 167, 43, 83, 167, 43, 83, 167, 42, 81, 166, 42, 81, 166, 41, 80, 165, 41, 80, 165, 41,
-    78, 165, 41, 78, 165, 40, 77, 164, 40, 77, 164, 39, 75, 163, 39, 75, 163, 39, 74, 163, 39, 74,
-    163, 38, 72, 162, 38, 72, 162, 37, 71, 161, 37, 71, 161, 37, 69, 161, 37, 69, 161, 36, 68, 160,
-    36, 68, 160, 35, 66, 160, 35, 66, 160, 34, 65, 159, 34, 65, 159, 34, 63, 158, 34, 63, 158, 33,
-    62, 158, 33, 62, 158, 32, 60, 157, 32, 60, 157, 31, 59, 156, 31, 59, 156, 30, 57, 156, 30, 57,
-    156, 29, 56, 155, 29, 56, 155, 28, 54, 154, 28, 54, 154, 27, 53, 154, 27, 53, 154, 26, 51, 153,
-    26, 51, 153,
-];
-
-pub const ROMAO: &[u8] = &[
-    115, 57, 87, 115, 57, 87, 116, 57, 86, 116, 57, 86, 117, 57, 84, 117, 57, 84, 117, 56, 83, 117,
-    56, 83, 118, 56, 81, 118, 56, 81, 119, 56, 80, 119, 56, 80, 119, 56, 79, 119, 56, 79, 120, 56,
-    77, 120, 56, 77, 121, 56, 76, 121, 56, 76, 121, 56, 75, 121, 56, 75, 122, 56, 73, 122, 56, 73,
-    123, 56, 72, 123, 56, 72, 124, 56, 71, 124, 56, 71, 124, 57, 70, 124, 57, 70, 125, 57, 69, 125,
-    57, 69, 126, 57, 67, 126, 57, 67, 126, 57, 66, 126, 57, 66, 127, 58, 65, 127, 58, 65, 128, 58,
-    64, 128, 58, 64, 129, 59, 63, 129, 59, 63, 129, 59, 62, 129, 59, 62, 130, 60, 61, 130, 60, 61,
-    131, 60, 60, 131, 60, 60, 132, 61, 59, 132, 61, 59, 132, 61, 58, 132, 61, 58, 133, 62, 57, 133,
-    62, 57, 134, 63, 56, 134, 63, 56, 135, 64, 55, 135, 64, 55, 135, 64, 55, 135, 64, 55, 136, 65,
-    54, 136, 65, 54, 137, 66, 53, 137, 66, 53, 138, 67, 52, 138, 67, 52, 139, 68, 51, 139, 68, 51,
-    140, 69, 51, 140, 69, 51, 140, 70, 50, 140, 70, 50, 141, 71, 49, 141, 71, 49, 142, 72, 49, 142,
-    72, 49, 143, 73, 48, 143, 73, 48, 144, 74, 48, 144, 74, 48, 145, 76, 47, 145, 76, 47, 146, 77,
-    47, 146, 77, 47, 147, 78, 46, 147, 78, 46, 148, 80, 46, 148, 80, 46, 148, 81, 45, 148, 81, 45,
-    149, 82, 45, 149, 82, 45, 150, 84, 45, 150, 84, 45, 151, 85, 44, 151, 85, 44, 152, 87, 44, 152,
-    87, 44, 153, 88, 44, 153, 88, 44, 154, 90, 44, 154, 90, 44, 155, 91, 44, 155, 91, 44, 156, 93,
-    43, 156, 93, 43, 157, 95, 43, 157, 95, 43, 158, 96, 43, 158, 96, 43, 159, 98, 43, 159, 98, 43,
-    160, 100, 44, 160, 100, 44, 162, 102, 44, 162, 102, 44, 163, 103, 44, 163, 103, 44, 164, 105,
-    44, 164, 105, 44, 165, 107, 45, 165, 107, 45, 166, 109, 45, 166, 109, 45, 167, 111, 45, 167,
-    111, 45, 168, 113, 46, 168, 113, 46, 169, 115, 46, 169, 115, 46, 170, 117, 47, 170, 117, 47,
-    171, 119, 48, 171, 119, 48, 173, 121, 48, 173, 121, 48, 174, 123, 49, 174, 123, 49, 175, 125,
-    50, 175, 125, 50, 176, 127, 51, 176, 127, 51, 177, 129, 52, 177, 129, 52, 178, 131, 53, 178,
-    131, 53, 180, 134, 54, 180, 134, 54, 181, 136, 55, 181, 136, 55, 182, 138, 57, 182, 138, 57,
-    183, 140, 58, 183, 140, 58, 184, 142, 59, 184, 142, 59, 185, 145, 61, 185, 145, 61, 187, 147,
-    63, 187, 147, 63, 188, 149, 64, 188, 149, 64, 189, 152, 66, 189, 152, 66, 190, 154, 68, 190,
-    154, 68, 191, 156, 70, 191, 156, 70, 193, 159, 71, 193, 159, 71, 194, 161, 73, 194, 161, 73,
-    195, 163, 75, 195, 163, 75, 196, 165, 78, 196, 165, 78, 197, 168, 80, 197, 168, 80, 198, 170,
-    82, 198, 170, 82, 200, 172, 84, 200, 172, 84, 201, 175, 87, 201, 175, 87, 202, 177, 89, 202,
-    177, 89, 203, 179, 92, 203, 179, 92, 204, 181, 94, 204, 181, 94, 205, 184, 97, 205, 184, 97,
-    206, 186, 99, 206, 186, 99, 207, 188, 102, 207, 188, 102, 207, 190, 104, 207, 190, 104, 208,
-    192, 107, 208, 192, 107, 209, 194, 110, 209, 194, 110, 210, 196, 112, 210, 196, 112, 210, 198,
-    115, 210, 198, 115, 211, 200, 118, 211, 200, 118, 212, 201, 120, 212, 201, 120, 212, 203, 123,
-    212, 203, 123, 212, 205, 126, 212, 205, 126, 213, 206, 129, 213, 206, 129, 213, 208, 131, 213,
-    208, 131, 213, 209, 134, 213, 209, 134, 214, 211, 136, 214, 211, 136, 214, 212, 139, 214, 212,
-    139, 214, 213, 142, 214, 213, 142, 214, 215, 144, 214, 215, 144, 214, 216, 147, 214, 216, 147,
-    213, 217, 149, 213, 217, 149, 213, 218, 152, 213, 218, 152, 213, 219, 154, 213, 219, 154, 212,
-    220, 156, 212, 220, 156, 212, 221, 159, 212, 221, 159, 211, 221, 161, 211, 221, 161, 211, 222,
-    163, 211, 222, 163, 210, 223, 165, 210, 223, 165, 209, 223, 167, 209, 223, 167, 208, 224, 169,
-    208, 224, 169, 207, 224, 171, 207, 224, 171, 206, 224, 173, 206, 224, 173, 205, 225, 175, 205,
-    225, 175, 204, 225, 177, 204, 225, 177, 203, 225, 179, 203, 225, 179, 202, 225, 181, 202, 225,
-    181, 200, 225, 182, 200, 225, 182, 199, 225, 184, 199, 225, 184, 197, 225, 185, 197, 225, 185,
-    196, 225, 187, 196, 225, 187, 194, 225, 188, 194, 225, 188, 193, 225, 190, 193, 225, 190, 191,
-    225, 191, 191, 225, 191, 189, 224, 192, 189, 224, 192, 187, 224, 194, 187, 224, 194, 185, 223,
-    195, 185, 223, 195, 184, 223, 196, 184, 223, 196, 182, 222, 197, 182, 222, 197, 180, 222, 198,
-    180, 222, 198, 177, 221, 199, 177, 221, 199, 175, 220, 200, 175, 220, 200, 173, 220, 200, 173,
-    220, 200, 171, 219, 201, 171, 219, 201, 169, 218, 202, 169, 218, 202, 167, 217, 203, 167, 217,
-    203, 164, 216, 203, 164, 216, 203, 162, 215, 204, 162, 215, 204, 160, 214, 204, 160, 214, 204,
-    157, 213, 205, 157, 213, 205, 155, 212, 205, 155, 212, 205, 153, 211, 206, 153, 211, 206, 150,
-    209, 206, 150, 209, 206, 148, 208, 206, 148, 208, 206, 146, 207, 206, 146, 207, 206, 143, 206,
-    207, 143, 206, 207, 141, 204, 207, 141, 204, 207, 139, 203, 207, 139, 203, 207, 136, 201, 207,
-    136, 201, 207, 134, 200, 207, 134, 200, 207, 132, 198, 207, 132, 198, 207, 129, 197, 207, 129,
-    197, 207, 127, 195, 207, 127, 195, 207, 125, 194, 206, 125, 194, 206, 123, 192, 206, 123, 192,
-    206, 120, 190, 206, 120, 190, 206, 118, 189, 206, 118, 189, 206, 116, 187, 205, 116, 187, 205,
-    114, 185, 205, 114, 185, 205, 112, 184, 205, 112, 184, 205, 110, 182, 204, 110, 182, 204, 108,
-    180, 204, 108, 180, 204, 106, 178, 203, 106, 178, 203, 104, 177, 203, 104, 177, 203, 103, 175,
-    202, 103, 175, 202, 101, 173, 202, 101, 173, 202, 99, 171, 201, 99, 171, 201, 98, 169, 201, 98,
-    169, 201, 96, 168, 200, 96, 168, 200, 95, 166, 199, 95, 166, 199, 93, 164, 199, 93, 164, 199,
-    92, 162, 198, 92, 162, 198, 90, 160, 197, 90, 160, 197, 89, 158, 196, 89, 158, 196, 88, 156,
-    196, 88, 156, 196, 87, 155, 195, 87, 155, 195, 86, 153, 194, 86, 153, 194, 85, 151, 193, 85,
-    151, 193, 84, 149, 192, 84, 149, 192, 83, 147, 191, 83, 147, 191, 82, 145, 190, 82, 145, 190,
-    82, 143, 189, 82, 143, 189, 81, 141, 188, 81, 141, 188, 80, 139, 187, 80, 139, 187, 80, 138,
-    186, 80, 138, 186, 79, 136, 185, 79, 136, 185, 79, 134, 184, 79, 134, 184, 79, 132, 183, 79,
-    132, 183, 79, 130, 182, 79, 130, 182, 78, 128, 180, 78, 128, 180, 78, 126, 179, 78, 126, 179,
-    78, 124, 178, 78, 124, 178, 78, 122, 176, 78, 122, 176, 79, 120, 175, 79, 120, 175, 79, 118,
-    174, 79, 118, 174, 79, 117, 172, 79, 117, 172, 79, 115, 171, 79, 115, 171, 80, 113, 169, 80,
-    113, 169, 80, 111, 168, 80, 111, 168, 81, 109, 166, 81, 109, 166, 81, 107, 164, 81, 107, 164,
-    82, 105, 163, 82, 105, 163, 82, 103, 161, 82, 103, 161, 83, 102, 159, 83, 102, 159, 84, 100,
-    158, 84, 100, 158, 84, 98, 156, 84, 98, 156, 85, 96, 154, 85, 96, 154, 86, 95, 152, 86, 95,
-    152, 87, 93, 150, 87, 93, 150, 87, 91, 148, 87, 91, 148, 88, 89, 147, 88, 89, 147, 89, 88, 145,
-    89, 88, 145, 90, 86, 143, 90, 86, 143, 91, 85, 141, 91, 85, 141, 92, 83, 139, 92, 83, 139, 92,
-    82, 137, 92, 82, 137, 93, 80, 135, 93, 80, 135, 94, 79, 133, 94, 79, 133, 95, 77, 131, 95, 77,
-    131, 96, 76, 129, 96, 76, 129, 97, 75, 127, 97, 75, 127, 98, 73, 125, 98, 73, 125, 99, 72, 123,
-    99, 72, 123, 99, 71, 121, 99, 71, 121, 100, 70, 119, 100, 70, 119, 101, 69, 118, 101, 69, 118,
-    102, 68, 116, 102, 68, 116, 103, 67, 114, 103, 67, 114, 104, 66, 112, 104, 66, 112, 104, 65,
-    110, 104, 65, 110, 105, 64, 108, 105, 64, 108, 106, 63, 107, 106, 63, 107, 107, 63, 105, 107,
-    63, 105, 108, 62, 103, 108, 62, 103, 108, 61, 101, 108, 61, 101, 109, 61, 100, 109, 61, 100,
-    110, 60, 98, 110, 60, 98, 111, 59, 96, 111, 59, 96, 111, 59, 95, 111, 59, 95, 112, 58, 93, 112,
-    58, 93, 113, 58, 92, 113, 58, 92, 114, 58, 90, 114, 58, 90, 114, 57, 89, 114, 57, 89,
-];
-
-pub const TOFINO: &[u8] = &[
-    222, 217, 255, 222, 217, 255, 219, 215, 254, 219, 215, 254, 217, 213, 253, 217, 213, 253, 214,
-    211, 251, 214, 211, 251, 211, 209, 250, 211, 209, 250, 208, 207, 249, 208, 207, 249, 206, 205,
-    248, 206, 205, 248, 203, 203, 247, 203, 203, 247, 200, 202, 246, 200, 202, 246, 198, 200, 244,
-    198, 200, 244, 195, 198, 243, 195, 198, 243, 192, 196, 242, 192, 196, 242, 190, 194, 241, 190,
-    194, 241, 187, 192, 240, 187, 192, 240, 184, 190, 239, 184, 190, 239, 182, 188, 237, 182, 188,
-    237, 179, 187, 236, 179, 187, 236, 176, 185, 235, 176, 185, 235, 174, 183, 234, 174, 183, 234,
-    171, 181, 233, 171, 181, 233, 168, 179, 232, 168, 179, 232, 166, 177, 230, 166, 177, 230, 163,
-    175, 229, 163, 175, 229, 160, 173, 228, 160, 173, 228, 158, 172, 227, 158, 172, 227, 155, 170,
-    226, 155, 170, 226, 152, 168, 225, 152, 168, 225, 150, 166, 223, 150, 166, 223, 147, 164, 222,
-    147, 164, 222, 144, 162, 221, 144, 162, 221, 142, 160, 220, 142, 160, 220, 139, 159, 219, 139,
-    159, 219, 136, 157, 217, 136, 157, 217, 134, 155, 216, 134, 155, 216, 131, 153, 215, 131, 153,
-    215, 128, 151, 213, 128, 151, 213, 126, 149, 212, 126, 149, 212, 123, 147, 211, 123, 147, 211,
-    121, 145, 209, 121, 145, 209, 118, 143, 208, 118, 143, 208, 115, 141, 206, 115, 141, 206, 113,
-    139, 205, 113, 139, 205, 110, 137, 203, 110, 137, 203, 107, 135, 202, 107, 135, 202, 105, 133,
-    200, 105, 133, 200, 102, 131, 198, 102, 131, 198, 100, 129, 197, 100, 129, 197, 97, 127, 195,
-    97, 127, 195, 95, 125, 193, 95, 125, 193, 92, 123, 191, 92, 123, 191, 90, 121, 189, 90, 121,
-    189, 87, 119, 186, 87, 119, 186, 85, 117, 184, 85, 117, 184, 83, 115, 182, 83, 115, 182, 81,
-    113, 180, 81, 113, 180, 79, 111, 177, 79, 111, 177, 76, 109, 175, 76, 109, 175, 74, 107, 172,
-    74, 107, 172, 73, 105, 170, 73, 105, 170, 71, 103, 167, 71, 103, 167, 69, 101, 165, 69, 101,
-    165, 67, 99, 162, 67, 99, 162, 66, 98, 160, 66, 98, 160, 64, 96, 157, 64, 96, 157, 62, 94, 154,
-    62, 94, 154, 61, 92, 152, 61, 92, 152, 60, 90, 149, 60, 90, 149, 58, 89, 147, 58, 89, 147, 57,
-    87, 144, 57, 87, 144, 56, 85, 141, 56, 85, 141, 55, 84, 139, 55, 84, 139, 53, 82, 136, 53, 82,
-    136, 52, 80, 134, 52, 80, 134, 51, 79, 131, 51, 79, 131, 50, 77, 128, 50, 77, 128, 49, 76, 126,
-    49, 76, 126, 48, 74, 123, 48, 74, 123, 47, 73, 121, 47, 73, 121, 46, 71, 118, 46, 71, 118, 45,
-    69, 116, 45, 69, 116, 44, 68, 113, 44, 68, 113, 43, 66, 111, 43, 66, 111, 42, 65, 108, 42, 65,
-    108, 41, 63, 106, 41, 63, 106, 40, 62, 103, 40, 62, 103, 39, 60, 101, 39, 60, 101, 38, 59, 98,
-    38, 59, 98, 37, 58, 96, 37, 58, 96, 36, 56, 93, 36, 56, 93, 35, 55, 91, 35, 55, 91, 34, 53, 89,
-    34, 53, 89, 34, 52, 86, 34, 52, 86, 33, 50, 84, 33, 50, 84, 32, 49, 81, 32, 49, 81, 31, 48, 79,
-    31, 48, 79, 30, 46, 77, 30, 46, 77, 29, 45, 74, 29, 45, 74, 28, 43, 72, 28, 43, 72, 28, 42, 70,
-    28, 42, 70, 27, 41, 67, 27, 41, 67, 26, 40, 65, 26, 40, 65, 25, 38, 63, 25, 38, 63, 25, 37, 61,
-    25, 37, 61, 24, 36, 59, 24, 36, 59, 23, 35, 56, 23, 35, 56, 22, 33, 54, 22, 33, 54, 22, 32, 52,
-    22, 32, 52, 21, 31, 50, 21, 31, 50, 21, 30, 48, 21, 30, 48, 20, 29, 46, 20, 29, 46, 19, 28, 44,
-    19, 28, 44, 19, 27, 42, 19, 27, 42, 18, 26, 40, 18, 26, 40, 18, 25, 38, 18, 25, 38, 17, 24, 36,
-    17, 24, 36, 17, 24, 35, 17, 24, 35, 17, 23, 33, 17, 23, 33, 16, 22, 32, 16, 22, 32, 16, 22, 30,
-    16, 22, 30, 15, 21, 29, 15, 21, 29, 15, 21, 27, 15, 21, 27, 14, 21, 26, 14, 21, 26, 14, 21, 25,
-    14, 21, 25, 13, 21, 24, 13, 21, 24, 13, 21, 23, 13, 21, 23, 13, 21, 22, 13, 21, 22, 13, 21, 21,
-    13, 21, 21, 13, 21, 20, 13, 21, 20, 13, 22, 19, 13, 22, 19, 13, 22, 19, 13, 22, 19, 13, 23, 18,
-    13, 23, 18, 13, 23, 18, 13, 23, 18, 13, 24, 18, 13, 24, 18, 14, 25, 18, 14, 25, 18, 14, 26, 17,
-    14, 26, 17, 15, 27, 18, 15, 27, 18, 15, 28, 18, 15, 28, 18, 16, 28, 18, 16, 28, 18, 16, 30, 18,
-    16, 30, 18, 17, 31, 18, 17, 31, 18, 17, 32, 19, 17, 32, 19, 17, 33, 19, 17, 33, 19, 18, 34, 20,
-    18, 34, 20, 18, 35, 20, 18, 35, 20, 19, 37, 21, 19, 37, 21, 19, 38, 21, 19, 38, 21, 20, 39, 22,
-    20, 39, 22, 20, 41, 22, 20, 41, 22, 21, 42, 23, 21, 42, 23, 21, 44, 24, 21, 44, 24, 22, 45, 24,
-    22, 45, 24, 22, 47, 25, 22, 47, 25, 23, 48, 26, 23, 48, 26, 24, 50, 26, 24, 50, 26, 24, 51, 27,
-    24, 51, 27, 25, 53, 28, 25, 53, 28, 26, 54, 28, 26, 54, 28, 26, 56, 29, 26, 56, 29, 27, 57, 30,
-    27, 57, 30, 28, 59, 31, 28, 59, 31, 29, 61, 32, 29, 61, 32, 29, 62, 32, 29, 62, 32, 30, 64, 33,
-    30, 64, 33, 31, 66, 34, 31, 66, 34, 32, 67, 35, 32, 67, 35, 32, 69, 36, 32, 69, 36, 33, 71, 37,
-    33, 71, 37, 34, 72, 37, 34, 72, 37, 35, 74, 38, 35, 74, 38, 35, 76, 39, 35, 76, 39, 36, 77, 40,
-    36, 77, 40, 37, 79, 41, 37, 79, 41, 38, 81, 42, 38, 81, 42, 39, 83, 43, 39, 83, 43, 39, 84, 44,
-    39, 84, 44, 40, 86, 44, 40, 86, 44, 41, 88, 45, 41, 88, 45, 42, 90, 46, 42, 90, 46, 43, 91, 47,
-    43, 91, 47, 44, 93, 48, 44, 93, 48, 44, 95, 49, 44, 95, 49, 45, 97, 50, 45, 97, 50, 46, 98, 51,
-    46, 98, 51, 47, 100, 52, 47, 100, 52, 48, 102, 53, 48, 102, 53, 49, 104, 54, 49, 104, 54, 50,
-    106, 55, 50, 106, 55, 51, 108, 56, 51, 108, 56, 52, 109, 57, 52, 109, 57, 53, 111, 58, 53, 111,
-    58, 54, 113, 59, 54, 113, 59, 55, 115, 60, 55, 115, 60, 56, 117, 61, 56, 117, 61, 57, 119, 62,
-    57, 119, 62, 58, 121, 63, 58, 121, 63, 59, 123, 64, 59, 123, 64, 61, 125, 65, 61, 125, 65, 62,
-    127, 66, 62, 127, 66, 63, 129, 68, 63, 129, 68, 65, 131, 69, 65, 131, 69, 66, 133, 70, 66, 133,
-    70, 68, 135, 71, 68, 135, 71, 70, 137, 73, 70, 137, 73, 72, 139, 74, 72, 139, 74, 74, 141, 75,
-    74, 141, 75, 76, 143, 77, 76, 143, 77, 78, 145, 78, 78, 145, 78, 80, 147, 80, 80, 147, 80, 82,
-    149, 81, 82, 149, 81, 85, 151, 83, 85, 151, 83, 87, 154, 84, 87, 154, 84, 90, 156, 86, 90, 156,
-    86, 92, 158, 87, 92, 158, 87, 95, 160, 89, 95, 160, 89, 98, 162, 90, 98, 162, 90, 100, 164, 92,
-    100, 164, 92, 103, 166, 94, 103, 166, 94, 106, 168, 95, 106, 168, 95, 109, 169, 97, 109, 169,
-    97, 112, 171, 99, 112, 171, 99, 115, 173, 100, 115, 173, 100, 118, 175, 102, 118, 175, 102,
-    121, 177, 103, 121, 177, 103, 124, 179, 105, 124, 179, 105, 127, 180, 107, 127, 180, 107, 130,
-    182, 108, 130, 182, 108, 133, 184, 110, 133, 184, 110, 136, 185, 112, 136, 185, 112, 139, 187,
-    113, 139, 187, 113, 142, 189, 115, 142, 189, 115, 145, 190, 116, 145, 190, 116, 148, 192, 118,
-    148, 192, 118, 151, 194, 120, 151, 194, 120, 154, 195, 121, 154, 195, 121, 157, 197, 123, 157,
-    197, 123, 160, 198, 124, 160, 198, 124, 163, 200, 126, 163, 200, 126, 166, 202, 127, 166, 202,
-    127, 169, 203, 129, 169, 203, 129, 172, 205, 131, 172, 205, 131, 175, 206, 132, 175, 206, 132,
-    178, 208, 134, 178, 208, 134, 181, 209, 135, 181, 209, 135, 183, 211, 137, 183, 211, 137, 186,
-    212, 138, 186, 212, 138, 189, 214, 140, 189, 214, 140, 192, 216, 141, 192, 216, 141, 195, 217,
-    143, 195, 217, 143, 198, 219, 145, 198, 219, 145, 201, 220, 146, 201, 220, 146, 204, 222, 148,
-    204, 222, 148, 207, 223, 149, 207, 223, 149, 210, 225, 151, 210, 225, 151, 213, 226, 152, 213,
-    226, 152, 216, 228, 154, 216, 228, 154, 219, 230, 155, 219, 230, 155,
-];
-
-pub const TOKYO: &[u8] = &[
-    26, 14, 52, 26, 14, 52, 28, 15, 52, 28, 15, 52, 29, 16, 53, 29, 16, 53, 31, 16, 54, 31, 16, 54,
-    33, 17, 55, 33, 17, 55, 34, 17, 56, 34, 17, 56, 36, 18, 57, 36, 18, 57, 37, 18, 57, 37, 18, 57,
-    39, 19, 58, 39, 19, 58, 40, 19, 59, 40, 19, 59, 42, 20, 60, 42, 20, 60, 43, 20, 61, 43, 20, 61,
-    45, 21, 62, 45, 21, 62, 46, 21, 63, 46, 21, 63, 48, 22, 63, 48, 22, 63, 49, 22, 64, 49, 22, 64,
-    51, 23, 65, 51, 23, 65, 52, 24, 66, 52, 24, 66, 54, 24, 67, 54, 24, 67, 55, 25, 68, 55, 25, 68,
-    57, 26, 69, 57, 26, 69, 58, 26, 70, 58, 26, 70, 60, 27, 71, 60, 27, 71, 61, 28, 71, 61, 28, 71,
-    63, 28, 72, 63, 28, 72, 64, 29, 73, 64, 29, 73, 66, 30, 74, 66, 30, 74, 67, 31, 75, 67, 31, 75,
-    69, 32, 76, 69, 32, 76, 70, 32, 77, 70, 32, 77, 72, 33, 78, 72, 33, 78, 74, 34, 79, 74, 34, 79,
-    75, 35, 80, 75, 35, 80, 77, 36, 81, 77, 36, 81, 78, 37, 82, 78, 37, 82, 80, 38, 83, 80, 38, 83,
-    81, 39, 84, 81, 39, 84, 83, 40, 85, 83, 40, 85, 84, 41, 86, 84, 41, 86, 86, 42, 87, 86, 42, 87,
-    87, 43, 88, 87, 43, 88, 89, 44, 89, 89, 44, 89, 90, 45, 89, 90, 45, 89, 92, 46, 90, 92, 46, 90,
-    93, 47, 91, 93, 47, 91, 95, 48, 92, 95, 48, 92, 96, 49, 93, 96, 49, 93, 97, 50, 94, 97, 50, 94,
-    99, 51, 95, 99, 51, 95, 100, 53, 96, 100, 53, 96, 102, 54, 97, 102, 54, 97, 103, 55, 98, 103,
-    55, 98, 104, 56, 99, 104, 56, 99, 105, 57, 100, 105, 57, 100, 107, 58, 100, 107, 58, 100, 108,
-    60, 101, 108, 60, 101, 109, 61, 102, 109, 61, 102, 110, 62, 103, 110, 62, 103, 112, 63, 104,
-    112, 63, 104, 113, 64, 104, 113, 64, 104, 114, 66, 105, 114, 66, 105, 115, 67, 106, 115, 67,
-    106, 116, 68, 107, 116, 68, 107, 117, 69, 108, 117, 69, 108, 118, 70, 108, 118, 70, 108, 119,
-    72, 109, 119, 72, 109, 120, 73, 110, 120, 73, 110, 121, 74, 110, 121, 74, 110, 122, 75, 111,
-    122, 75, 111, 122, 76, 112, 122, 76, 112, 123, 78, 112, 123, 78, 112, 124, 79, 113, 124, 79,
-    113, 125, 80, 114, 125, 80, 114, 126, 81, 114, 126, 81, 114, 126, 82, 115, 126, 82, 115, 127,
-    83, 115, 127, 83, 115, 128, 84, 116, 128, 84, 116, 128, 85, 116, 128, 85, 116, 129, 87, 117,
-    129, 87, 117, 129, 88, 117, 129, 88, 117, 130, 89, 118, 130, 89, 118, 131, 90, 118, 131, 90,
-    118, 131, 91, 119, 131, 91, 119, 132, 92, 119, 132, 92, 119, 132, 93, 120, 132, 93, 120, 133,
-    94, 120, 133, 94, 120, 133, 95, 121, 133, 95, 121, 134, 96, 121, 134, 96, 121, 134, 97, 122,
-    134, 97, 122, 134, 98, 122, 134, 98, 122, 135, 99, 122, 135, 99, 122, 135, 100, 123, 135, 100,
-    123, 135, 101, 123, 135, 101, 123, 136, 102, 123, 136, 102, 123, 136, 103, 124, 136, 103, 124,
-    137, 104, 124, 137, 104, 124, 137, 105, 125, 137, 105, 125, 137, 106, 125, 137, 106, 125, 137,
-    107, 125, 137, 107, 125, 138, 108, 126, 138, 108, 126, 138, 109, 126, 138, 109, 126, 138, 110,
-    126, 138, 110, 126, 139, 111, 127, 139, 111, 127, 139, 112, 127, 139, 112, 127, 139, 113, 127,
-    139, 113, 127, 139, 114, 128, 139, 114, 128, 140, 115, 128, 140, 115, 128, 140, 116, 128, 140,
-    116, 128, 140, 117, 128, 140, 117, 128, 140, 118, 129, 140, 118, 129, 140, 119, 129, 140, 119,
-    129, 141, 119, 129, 141, 119, 129, 141, 120, 130, 141, 120, 130, 141, 121, 130, 141, 121, 130,
-    141, 122, 130, 141, 122, 130, 141, 123, 130, 141, 123, 130, 142, 124, 131, 142, 124, 131, 142,
-    125, 131, 142, 125, 131, 142, 126, 131, 142, 126, 131, 142, 127, 132, 142, 127, 132, 142, 128,
-    132, 142, 128, 132, 142, 129, 132, 142, 129, 132, 143, 130, 132, 143, 130, 132, 143, 131, 133,
-    143, 131, 133, 143, 131, 133, 143, 131, 133, 143, 132, 133, 143, 132, 133, 143, 133, 133, 143,
-    133, 133, 143, 134, 134, 143, 134, 134, 144, 135, 134, 144, 135, 134, 144, 136, 134, 144, 136,
-    134, 144, 137, 134, 144, 137, 134, 144, 138, 135, 144, 138, 135, 144, 139, 135, 144, 139, 135,
-    144, 140, 135, 144, 140, 135, 145, 141, 135, 145, 141, 135, 145, 141, 136, 145, 141, 136, 145,
-    142, 136, 145, 142, 136, 145, 143, 136, 145, 143, 136, 145, 144, 136, 145, 144, 136, 145, 145,
-    137, 145, 145, 137, 145, 146, 137, 145, 146, 137, 146, 147, 137, 146, 147, 137, 146, 148, 137,
-    146, 148, 137, 146, 149, 138, 146, 149, 138, 146, 150, 138, 146, 150, 138, 146, 151, 138, 146,
-    151, 138, 146, 152, 138, 146, 152, 138, 147, 152, 139, 147, 152, 139, 147, 153, 139, 147, 153,
-    139, 147, 154, 139, 147, 154, 139, 147, 155, 139, 147, 155, 139, 147, 156, 140, 147, 156, 140,
-    147, 157, 140, 147, 157, 140, 148, 158, 140, 148, 158, 140, 148, 159, 140, 148, 159, 140, 148,
-    160, 141, 148, 160, 141, 148, 161, 141, 148, 161, 141, 148, 162, 141, 148, 162, 141, 148, 163,
-    141, 148, 163, 141, 149, 164, 142, 149, 164, 142, 149, 165, 142, 149, 165, 142, 149, 165, 142,
-    149, 165, 142, 149, 166, 143, 149, 166, 143, 149, 167, 143, 149, 167, 143, 150, 168, 143, 150,
-    168, 143, 150, 169, 143, 150, 169, 143, 150, 170, 144, 150, 170, 144, 150, 171, 144, 150, 171,
-    144, 151, 172, 144, 151, 172, 144, 151, 173, 144, 151, 173, 144, 151, 174, 145, 151, 174, 145,
-    151, 175, 145, 151, 175, 145, 152, 176, 145, 152, 176, 145, 152, 177, 146, 152, 177, 146, 152,
-    178, 146, 152, 178, 146, 153, 179, 146, 153, 179, 146, 153, 180, 147, 153, 180, 147, 153, 181,
-    147, 153, 181, 147, 154, 182, 147, 154, 182, 147, 154, 183, 148, 154, 183, 148, 154, 184, 148,
-    154, 184, 148, 155, 185, 148, 155, 185, 148, 155, 187, 149, 155, 187, 149, 156, 188, 149, 156,
-    188, 149, 156, 189, 149, 156, 189, 149, 157, 190, 150, 157, 190, 150, 157, 191, 150, 157, 191,
-    150, 158, 192, 151, 158, 192, 151, 159, 193, 151, 159, 193, 151, 159, 194, 152, 159, 194, 152,
-    160, 196, 152, 160, 196, 152, 161, 197, 153, 161, 197, 153, 161, 198, 153, 161, 198, 153, 162,
-    199, 154, 162, 199, 154, 163, 201, 155, 163, 201, 155, 164, 202, 155, 164, 202, 155, 165, 203,
-    156, 165, 203, 156, 166, 204, 157, 166, 204, 157, 167, 206, 157, 167, 206, 157, 168, 207, 158,
-    168, 207, 158, 169, 208, 159, 169, 208, 159, 170, 210, 160, 170, 210, 160, 172, 211, 160, 172,
-    211, 160, 173, 212, 161, 173, 212, 161, 174, 214, 162, 174, 214, 162, 176, 215, 163, 176, 215,
-    163, 177, 216, 164, 177, 216, 164, 178, 218, 165, 178, 218, 165, 180, 219, 166, 180, 219, 166,
-    182, 221, 167, 182, 221, 167, 183, 222, 168, 183, 222, 168, 185, 223, 169, 185, 223, 169, 186,
-    225, 170, 186, 225, 170, 188, 226, 171, 188, 226, 171, 190, 227, 172, 190, 227, 172, 192, 229,
-    174, 192, 229, 174, 193, 230, 175, 193, 230, 175, 195, 231, 176, 195, 231, 176, 197, 232, 177,
-    197, 232, 177, 199, 234, 178, 199, 234, 178, 201, 235, 180, 201, 235, 180, 202, 236, 181, 202,
-    236, 181, 204, 237, 182, 204, 237, 182, 206, 238, 183, 206, 238, 183, 208, 239, 184, 208, 239,
-    184, 210, 240, 186, 210, 240, 186, 212, 241, 187, 212, 241, 187, 213, 242, 188, 213, 242, 188,
-    215, 243, 189, 215, 243, 189, 217, 243, 190, 217, 243, 190, 218, 244, 191, 218, 244, 191, 220,
-    245, 193, 220, 245, 193, 222, 246, 194, 222, 246, 194, 224, 246, 195, 224, 246, 195, 225, 247,
-    196, 225, 247, 196, 227, 248, 197, 227, 248, 197, 228, 248, 198, 228, 248, 198, 230, 249, 199,
-    230, 249, 199, 231, 249, 200, 231, 249, 200, 233, 250, 201, 233, 250, 201, 234, 250, 202, 234,
-    250, 202, 236, 250, 203, 236, 250, 203, 237, 251, 204, 237, 251, 204, 239, 251, 205, 239, 251,
-    205, 240, 252, 206, 240, 252, 206, 241, 252, 207, 241, 252, 207, 243, 252, 208, 243, 252, 208,
-    244, 253, 209, 244, 253, 209, 245, 253, 210, 245, 253, 210, 247, 253, 211, 247, 253, 211, 248,
-    253, 212, 248, 253, 212, 249, 254, 213, 249, 254, 213, 251, 254, 214, 251, 254, 214, 252, 254,
-    215, 252, 254, 215, 253, 254, 216, 253, 254, 216, 254, 254, 216, 254, 254, 216,
-];
-
-pub const TURKU: &[u8] = &[
-    0, 0, 0, 0, 0, 0, 2, 2, 2, 2, 2, 2, 4, 4, 3, 4, 4, 3, 6, 6, 5, 6, 6, 5, 7, 7, 7, 7, 7, 7, 9, 9,
-    8, 9, 9, 8, 11, 11, 10, 11, 11, 10, 13, 13, 12, 13, 13, 12, 14, 14, 13, 14, 14, 13, 16, 16, 14,
-    16, 16, 14, 17, 17, 16, 17, 17, 16, 18, 18, 17, 18, 18, 17, 20, 19, 18, 20, 19, 18, 21, 21, 19,
-    21, 21, 19, 22, 22, 20, 22, 22, 20, 23, 23, 21, 23, 23, 21, 24, 24, 22, 24, 24, 22, 25, 25, 23,
-    25, 25, 23, 26, 26, 24, 26, 26, 24, 27, 27, 25, 27, 27, 25, 28, 28, 25, 28, 28, 25, 29, 29, 26,
-    29, 29, 26, 30, 30, 27, 30, 30, 27, 31, 31, 28, 31, 31, 28, 32, 32, 29, 32, 32, 29, 33, 33, 30,
-    33, 33, 30, 34, 34, 31, 34, 34, 31, 35, 35, 31, 35, 35, 31, 36, 36, 32, 36, 36, 32, 37, 37, 33,
-    37, 37, 33, 38, 38, 34, 38, 38, 34, 39, 39, 35, 39, 39, 35, 40, 40, 35, 40, 40, 35, 41, 41, 36,
-    41, 41, 36, 42, 42, 37, 42, 42, 37, 43, 43, 38, 43, 43, 38, 44, 44, 39, 44, 44, 39, 45, 45, 39,
-    45, 45, 39, 46, 46, 40, 46, 46, 40, 47, 47, 41, 47, 47, 41, 48, 48, 42, 48, 48, 42, 50, 49, 42,
-    50, 49, 42, 51, 50, 43, 51, 50, 43, 52, 51, 44, 52, 51, 44, 53, 52, 45, 53, 52, 45, 54, 54, 45,
-    54, 54, 45, 55, 55, 46, 55, 55, 46, 56, 56, 47, 56, 56, 47, 57, 57, 48, 57, 57, 48, 58, 58, 48,
-    58, 58, 48, 59, 59, 49, 59, 59, 49, 60, 60, 50, 60, 60, 50, 61, 61, 50, 61, 61, 50, 62, 62, 51,
-    62, 62, 51, 63, 63, 52, 63, 63, 52, 64, 64, 52, 64, 64, 52, 65, 65, 53, 65, 65, 53, 66, 66, 53,
-    66, 66, 53, 67, 67, 54, 67, 67, 54, 68, 68, 55, 68, 68, 55, 69, 69, 55, 69, 69, 55, 70, 70, 56,
-    70, 70, 56, 71, 71, 56, 71, 71, 56, 72, 72, 57, 72, 72, 57, 73, 73, 58, 73, 73, 58, 75, 74, 58,
-    75, 74, 58, 76, 75, 59, 76, 75, 59, 77, 76, 59, 77, 76, 59, 78, 77, 60, 78, 77, 60, 79, 78, 60,
-    79, 78, 60, 80, 79, 61, 80, 79, 61, 81, 80, 61, 81, 80, 61, 82, 81, 62, 82, 81, 62, 83, 82, 62,
-    83, 82, 62, 84, 83, 63, 84, 83, 63, 85, 84, 63, 85, 84, 63, 86, 86, 64, 86, 86, 64, 87, 87, 64,
-    87, 87, 64, 88, 88, 65, 88, 88, 65, 89, 89, 65, 89, 89, 65, 90, 90, 66, 90, 90, 66, 91, 91, 66,
-    91, 91, 66, 92, 92, 67, 92, 92, 67, 93, 93, 67, 93, 93, 67, 94, 94, 68, 94, 94, 68, 95, 95, 68,
-    95, 95, 68, 96, 96, 69, 96, 96, 69, 97, 97, 69, 97, 97, 69, 98, 98, 70, 98, 98, 70, 99, 99, 70,
-    99, 99, 70, 100, 100, 71, 100, 100, 71, 101, 101, 71, 101, 101, 71, 102, 102, 71, 102, 102, 71,
-    103, 103, 72, 103, 103, 72, 104, 104, 72, 104, 104, 72, 106, 105, 73, 106, 105, 73, 107, 106,
-    73, 107, 106, 73, 108, 107, 74, 108, 107, 74, 109, 108, 74, 109, 108, 74, 110, 109, 75, 110,
-    109, 75, 111, 110, 75, 111, 110, 75, 112, 111, 76, 112, 111, 76, 113, 112, 76, 113, 112, 76,
-    114, 113, 77, 114, 113, 77, 115, 114, 77, 115, 114, 77, 117, 115, 78, 117, 115, 78, 118, 116,
-    78, 118, 116, 78, 119, 117, 79, 119, 117, 79, 120, 118, 79, 120, 118, 79, 121, 119, 80, 121,
-    119, 80, 123, 121, 80, 123, 121, 80, 124, 122, 81, 124, 122, 81, 125, 123, 81, 125, 123, 81,
-    126, 124, 82, 126, 124, 82, 127, 125, 82, 127, 125, 82, 129, 126, 83, 129, 126, 83, 130, 127,
-    83, 130, 127, 83, 131, 128, 84, 131, 128, 84, 133, 129, 84, 133, 129, 84, 134, 130, 85, 134,
-    130, 85, 135, 131, 86, 135, 131, 86, 137, 132, 86, 137, 132, 86, 138, 133, 87, 138, 133, 87,
-    139, 134, 88, 139, 134, 88, 141, 135, 88, 141, 135, 88, 142, 136, 89, 142, 136, 89, 144, 138,
-    89, 144, 138, 89, 145, 139, 90, 145, 139, 90, 147, 140, 91, 147, 140, 91, 148, 141, 91, 148,
-    141, 91, 150, 142, 92, 150, 142, 92, 151, 143, 93, 151, 143, 93, 153, 144, 94, 153, 144, 94,
-    154, 145, 94, 154, 145, 94, 156, 146, 95, 156, 146, 95, 157, 147, 96, 157, 147, 96, 159, 148,
-    97, 159, 148, 97, 161, 148, 97, 161, 148, 97, 162, 149, 98, 162, 149, 98, 164, 150, 99, 164,
-    150, 99, 165, 151, 100, 165, 151, 100, 167, 152, 100, 167, 152, 100, 169, 153, 101, 169, 153,
-    101, 170, 154, 102, 170, 154, 102, 172, 154, 103, 172, 154, 103, 173, 155, 104, 173, 155, 104,
-    175, 156, 104, 175, 156, 104, 176, 157, 105, 176, 157, 105, 178, 157, 106, 178, 157, 106, 179,
-    158, 107, 179, 158, 107, 181, 159, 108, 181, 159, 108, 182, 159, 108, 182, 159, 108, 184, 160,
-    109, 184, 160, 109, 185, 160, 110, 185, 160, 110, 187, 161, 111, 187, 161, 111, 188, 161, 112,
-    188, 161, 112, 190, 162, 112, 190, 162, 112, 191, 162, 113, 191, 162, 113, 192, 162, 114, 192,
-    162, 114, 194, 163, 115, 194, 163, 115, 195, 163, 116, 195, 163, 116, 196, 164, 116, 196, 164,
-    116, 198, 164, 117, 198, 164, 117, 199, 164, 118, 199, 164, 118, 200, 164, 119, 200, 164, 119,
-    201, 165, 120, 201, 165, 120, 203, 165, 120, 203, 165, 120, 204, 165, 121, 204, 165, 121, 205,
-    165, 122, 205, 165, 122, 206, 166, 123, 206, 166, 123, 207, 166, 124, 207, 166, 124, 208, 166,
-    124, 208, 166, 124, 209, 166, 125, 209, 166, 125, 210, 166, 126, 210, 166, 126, 211, 166, 127,
-    211, 166, 127, 212, 167, 128, 212, 167, 128, 214, 167, 128, 214, 167, 128, 215, 167, 129, 215,
-    167, 129, 216, 167, 130, 216, 167, 130, 217, 167, 131, 217, 167, 131, 218, 167, 132, 218, 167,
-    132, 219, 168, 133, 219, 168, 133, 219, 168, 134, 219, 168, 134, 220, 168, 135, 220, 168, 135,
-    221, 168, 136, 221, 168, 136, 222, 168, 137, 222, 168, 137, 223, 169, 138, 223, 169, 138, 224,
-    169, 139, 224, 169, 139, 225, 169, 140, 225, 169, 140, 226, 169, 141, 226, 169, 141, 227, 170,
-    142, 227, 170, 142, 228, 170, 143, 228, 170, 143, 229, 170, 144, 229, 170, 144, 230, 171, 146,
-    230, 171, 146, 231, 171, 147, 231, 171, 147, 232, 172, 148, 232, 172, 148, 233, 172, 149, 233,
-    172, 149, 233, 173, 151, 233, 173, 151, 234, 173, 152, 234, 173, 152, 235, 174, 153, 235, 174,
-    153, 236, 174, 155, 236, 174, 155, 237, 175, 156, 237, 175, 156, 238, 176, 158, 238, 176, 158,
-    239, 176, 159, 239, 176, 159, 239, 177, 161, 239, 177, 161, 240, 178, 162, 240, 178, 162, 241,
-    179, 163, 241, 179, 163, 242, 179, 165, 242, 179, 165, 242, 180, 167, 242, 180, 167, 243, 181,
-    168, 243, 181, 168, 244, 182, 170, 244, 182, 170, 244, 183, 171, 244, 183, 171, 245, 184, 173,
-    245, 184, 173, 246, 185, 174, 246, 185, 174, 246, 186, 176, 246, 186, 176, 247, 187, 177, 247,
-    187, 177, 247, 188, 179, 247, 188, 179, 248, 189, 180, 248, 189, 180, 248, 190, 182, 248, 190,
-    182, 249, 191, 183, 249, 191, 183, 249, 192, 185, 249, 192, 185, 250, 193, 186, 250, 193, 186,
-    250, 194, 188, 250, 194, 188, 251, 195, 189, 251, 195, 189, 251, 196, 191, 251, 196, 191, 251,
-    197, 192, 251, 197, 192, 252, 198, 193, 252, 198, 193, 252, 199, 195, 252, 199, 195, 252, 200,
-    196, 252, 200, 196, 253, 201, 198, 253, 201, 198, 253, 203, 199, 253, 203, 199, 253, 204, 200,
-    253, 204, 200, 253, 205, 202, 253, 205, 202, 253, 206, 203, 253, 206, 203, 254, 207, 204, 254,
-    207, 204, 254, 208, 206, 254, 208, 206, 254, 209, 207, 254, 209, 207, 254, 210, 208, 254, 210,
-    208, 254, 211, 209, 254, 211, 209, 254, 212, 211, 254, 212, 211, 254, 214, 212, 254, 214, 212,
-    254, 215, 213, 254, 215, 213, 255, 216, 214, 255, 216, 214, 255, 217, 215, 255, 217, 215, 255,
-    218, 217, 255, 218, 217, 255, 219, 218, 255, 219, 218, 255, 220, 219, 255, 220, 219, 255, 221,
-    220, 255, 221, 220, 255, 222, 221, 255, 222, 221, 255, 223, 223, 255, 223, 223, 255, 224, 224,
-    255, 224, 224, 255, 226, 225, 255, 226, 225, 255, 227, 226, 255, 227, 226, 255, 228, 227, 255,
-    228, 227, 255, 229, 229, 255, 229, 229, 255, 230, 230, 255, 230, 230,
-];
-
-pub const VIK: &[u8] = &[
-    0, 18, 97, 0, 18, 97, 1, 20, 98, 1, 20, 98, 1, 21, 99, 1, 21, 99, 1, 23, 100, 1, 23, 100, 1,
-    24, 101, 1, 24, 101, 1, 26, 102, 1, 26, 102, 2, 28, 103, 2, 28, 103, 2, 29, 104, 2, 29, 104, 2,
-    31, 105, 2, 31, 105, 2, 32, 106, 2, 32, 106, 2, 34, 107, 2, 34, 107, 2, 35, 108, 2, 35, 108, 2,
-    37, 109, 2, 37, 109, 2, 39, 110, 2, 39, 110, 2, 40, 111, 2, 40, 111, 2, 42, 112, 2, 42, 112, 2,
-    43, 113, 2, 43, 113, 2, 45, 114, 2, 45, 114, 2, 46, 115, 2, 46, 115, 2, 48, 116, 2, 48, 116, 2,
-    49, 117, 2, 49, 117, 2, 51, 118, 2, 51, 118, 2, 52, 119, 2, 52, 119, 2, 54, 120, 2, 54, 120, 2,
-    55, 121, 2, 55, 121, 2, 57, 122, 2, 57, 122, 2, 58, 123, 2, 58, 123, 3, 60, 124, 3, 60, 124, 3,
-    62, 125, 3, 62, 125, 3, 63, 126, 3, 63, 126, 3, 65, 127, 3, 65, 127, 3, 66, 128, 3, 66, 128, 3,
-    68, 129, 3, 68, 129, 3, 69, 130, 3, 69, 130, 3, 71, 131, 3, 71, 131, 3, 73, 132, 3, 73, 132, 3,
-    74, 133, 3, 74, 133, 4, 76, 134, 4, 76, 134, 4, 77, 135, 4, 77, 135, 4, 79, 136, 4, 79, 136, 5,
-    81, 137, 5, 81, 137, 5, 82, 138, 5, 82, 138, 6, 84, 139, 6, 84, 139, 6, 86, 140, 6, 86, 140, 7,
-    87, 141, 7, 87, 141, 8, 89, 143, 8, 89, 143, 9, 91, 144, 9, 91, 144, 11, 93, 145, 11, 93, 145,
-    12, 94, 146, 12, 94, 146, 14, 96, 147, 14, 96, 147, 16, 98, 148, 16, 98, 148, 17, 100, 150, 17,
-    100, 150, 19, 102, 151, 19, 102, 151, 21, 103, 152, 21, 103, 152, 23, 105, 153, 23, 105, 153,
-    25, 107, 154, 25, 107, 154, 28, 109, 156, 28, 109, 156, 30, 111, 157, 30, 111, 157, 32, 113,
-    158, 32, 113, 158, 35, 115, 160, 35, 115, 160, 37, 117, 161, 37, 117, 161, 40, 119, 162, 40,
-    119, 162, 43, 121, 164, 43, 121, 164, 45, 123, 165, 45, 123, 165, 48, 125, 166, 48, 125, 166,
-    51, 127, 168, 51, 127, 168, 54, 129, 169, 54, 129, 169, 57, 131, 171, 57, 131, 171, 60, 133,
-    172, 60, 133, 172, 63, 135, 173, 63, 135, 173, 66, 137, 175, 66, 137, 175, 69, 139, 176, 69,
-    139, 176, 72, 141, 178, 72, 141, 178, 75, 144, 179, 75, 144, 179, 78, 146, 180, 78, 146, 180,
-    81, 148, 182, 81, 148, 182, 84, 150, 183, 84, 150, 183, 87, 152, 185, 87, 152, 185, 90, 154,
-    186, 90, 154, 186, 93, 156, 187, 93, 156, 187, 97, 158, 189, 97, 158, 189, 100, 160, 190, 100,
-    160, 190, 103, 162, 192, 103, 162, 192, 106, 164, 193, 106, 164, 193, 109, 166, 194, 109, 166,
-    194, 113, 168, 196, 113, 168, 196, 116, 170, 197, 116, 170, 197, 119, 172, 198, 119, 172, 198,
-    122, 174, 200, 122, 174, 200, 125, 176, 201, 125, 176, 201, 128, 178, 202, 128, 178, 202, 132,
-    180, 204, 132, 180, 204, 135, 182, 205, 135, 182, 205, 138, 184, 206, 138, 184, 206, 141, 186,
-    208, 141, 186, 208, 144, 188, 209, 144, 188, 209, 148, 190, 210, 148, 190, 210, 151, 192, 212,
-    151, 192, 212, 154, 194, 213, 154, 194, 213, 157, 196, 214, 157, 196, 214, 160, 197, 216, 160,
-    197, 216, 163, 199, 217, 163, 199, 217, 167, 201, 218, 167, 201, 218, 170, 203, 220, 170, 203,
-    220, 173, 205, 221, 173, 205, 221, 176, 207, 222, 176, 207, 222, 179, 209, 223, 179, 209, 223,
-    182, 211, 225, 182, 211, 225, 186, 213, 226, 186, 213, 226, 189, 214, 227, 189, 214, 227, 192,
-    216, 228, 192, 216, 228, 195, 218, 229, 195, 218, 229, 198, 219, 230, 198, 219, 230, 201, 221,
-    231, 201, 221, 231, 204, 223, 232, 204, 223, 232, 207, 224, 232, 207, 224, 232, 210, 225, 233,
-    210, 225, 233, 213, 227, 233, 213, 227, 233, 216, 228, 233, 216, 228, 233, 219, 229, 233, 219,
-    229, 233, 222, 230, 233, 222, 230, 233, 224, 230, 233, 224, 230, 233, 226, 231, 232, 226, 231,
-    232, 229, 231, 232, 229, 231, 232, 231, 231, 231, 231, 231, 231, 232, 231, 229, 232, 231, 229,
-    234, 230, 228, 234, 230, 228, 235, 230, 226, 235, 230, 226, 236, 229, 224, 236, 229, 224, 237,
-    228, 222, 237, 228, 222, 238, 227, 220, 238, 227, 220, 238, 225, 218, 238, 225, 218, 238, 224,
-    216, 238, 224, 216, 238, 222, 213, 238, 222, 213, 238, 221, 211, 238, 221, 211, 238, 219, 208,
-    238, 219, 208, 238, 217, 205, 238, 217, 205, 237, 215, 203, 237, 215, 203, 237, 213, 200, 237,
-    213, 200, 236, 211, 197, 236, 211, 197, 236, 209, 195, 236, 209, 195, 235, 208, 192, 235, 208,
-    192, 234, 206, 189, 234, 206, 189, 233, 204, 186, 233, 204, 186, 233, 202, 184, 233, 202, 184,
-    232, 200, 181, 232, 200, 181, 231, 198, 178, 231, 198, 178, 230, 196, 176, 230, 196, 176, 229,
-    193, 173, 229, 193, 173, 228, 191, 170, 228, 191, 170, 228, 190, 168, 228, 190, 168, 227, 188,
-    165, 227, 188, 165, 226, 186, 162, 226, 186, 162, 225, 184, 160, 225, 184, 160, 224, 182, 157,
-    224, 182, 157, 223, 180, 154, 223, 180, 154, 223, 178, 152, 223, 178, 152, 222, 176, 149, 222,
-    176, 149, 221, 174, 147, 221, 174, 147, 220, 172, 144, 220, 172, 144, 219, 170, 141, 219, 170,
-    141, 219, 168, 139, 219, 168, 139, 218, 166, 136, 218, 166, 136, 217, 164, 134, 217, 164, 134,
-    216, 162, 131, 216, 162, 131, 215, 160, 129, 215, 160, 129, 214, 159, 126, 214, 159, 126, 214,
-    157, 124, 214, 157, 124, 213, 155, 121, 213, 155, 121, 212, 153, 119, 212, 153, 119, 211, 151,
-    116, 211, 151, 116, 211, 149, 114, 211, 149, 114, 210, 148, 112, 210, 148, 112, 209, 146, 109,
-    209, 146, 109, 208, 144, 107, 208, 144, 107, 207, 142, 104, 207, 142, 104, 207, 140, 102, 207,
-    140, 102, 206, 139, 100, 206, 139, 100, 205, 137, 97, 205, 137, 97, 204, 135, 95, 204, 135, 95,
-    204, 133, 93, 204, 133, 93, 203, 131, 90, 203, 131, 90, 202, 130, 88, 202, 130, 88, 201, 128,
-    86, 201, 128, 86, 201, 126, 83, 201, 126, 83, 200, 124, 81, 200, 124, 81, 199, 123, 79, 199,
-    123, 79, 198, 121, 76, 198, 121, 76, 198, 119, 74, 198, 119, 74, 197, 117, 72, 197, 117, 72,
-    196, 116, 69, 196, 116, 69, 195, 114, 67, 195, 114, 67, 194, 112, 65, 194, 112, 65, 194, 110,
-    63, 194, 110, 63, 193, 109, 60, 193, 109, 60, 192, 107, 58, 192, 107, 58, 191, 105, 56, 191,
-    105, 56, 190, 103, 54, 190, 103, 54, 190, 101, 51, 190, 101, 51, 189, 100, 49, 189, 100, 49,
-    188, 98, 47, 188, 98, 47, 187, 96, 45, 187, 96, 45, 186, 94, 42, 186, 94, 42, 184, 92, 40, 184,
-    92, 40, 183, 90, 38, 183, 90, 38, 182, 88, 36, 182, 88, 36, 181, 85, 33, 181, 85, 33, 179, 83,
-    31, 179, 83, 31, 178, 81, 29, 178, 81, 29, 176, 79, 27, 176, 79, 27, 175, 76, 24, 175, 76, 24,
-    173, 74, 22, 173, 74, 22, 171, 72, 20, 171, 72, 20, 169, 69, 18, 169, 69, 18, 167, 67, 16, 167,
-    67, 16, 165, 64, 15, 165, 64, 15, 163, 62, 13, 163, 62, 13, 161, 60, 11, 161, 60, 11, 159, 57,
-    10, 159, 57, 10, 156, 55, 9, 156, 55, 9, 154, 53, 8, 154, 53, 8, 152, 51, 7, 152, 51, 7, 150,
-    49, 7, 150, 49, 7, 148, 47, 6, 148, 47, 6, 145, 45, 6, 145, 45, 6, 143, 43, 6, 143, 43, 6, 141,
-    41, 6, 141, 41, 6, 139, 39, 6, 139, 39, 6, 137, 38, 6, 137, 38, 6, 135, 36, 6, 135, 36, 6, 133,
-    34, 6, 133, 34, 6, 131, 33, 6, 131, 33, 6, 129, 31, 6, 129, 31, 6, 127, 30, 6, 127, 30, 6, 126,
-    29, 6, 126, 29, 6, 124, 27, 6, 124, 27, 6, 122, 26, 6, 122, 26, 6, 120, 24, 6, 120, 24, 6, 118,
-    23, 6, 118, 23, 6, 116, 21, 6, 116, 21, 6, 115, 20, 6, 115, 20, 6, 113, 19, 7, 113, 19, 7, 111,
-    17, 7, 111, 17, 7, 109, 16, 7, 109, 16, 7, 108, 14, 7, 108, 14, 7, 106, 13, 7, 106, 13, 7, 104,
-    12, 7, 104, 12, 7, 103, 10, 7, 103, 10, 7, 101, 9, 7, 101, 9, 7, 99, 7, 7, 99, 7, 7, 98, 6, 7,
-    98, 6, 7, 96, 4, 8, 96, 4, 8, 94, 3, 8, 94, 3, 8, 93, 2, 8, 93, 2, 8, 91, 1, 8, 91, 1, 8, 89,
-    0, 8, 89, 0, 8,
-];
-
-pub const VIKO: &[u8] = &[
-    79, 26, 61, 79, 26, 61, 78, 26, 62, 78, 26, 62, 78, 27, 63, 78, 27, 63, 77, 27, 65, 77, 27, 65,
-    76, 28, 66, 76, 28, 66, 75, 29, 67, 75, 29, 67, 75, 29, 68, 75, 29, 68, 74, 30, 70, 74, 30, 70,
-    73, 31, 71, 73, 31, 71, 72, 32, 73, 72, 32, 73, 72, 33, 74, 72, 33, 74, 71, 33, 75, 71, 33, 75,
-    70, 34, 77, 70, 34, 77, 69, 35, 78, 69, 35, 78, 69, 36, 80, 69, 36, 80, 68, 37, 81, 68, 37, 81,
-    67, 38, 83, 67, 38, 83, 67, 39, 84, 67, 39, 84, 66, 40, 86, 66, 40, 86, 65, 41, 88, 65, 41, 88,
-    64, 42, 89, 64, 42, 89, 63, 44, 91, 63, 44, 91, 63, 45, 93, 63, 45, 93, 62, 46, 94, 62, 46, 94,
-    61, 47, 96, 61, 47, 96, 61, 49, 98, 61, 49, 98, 60, 50, 99, 60, 50, 99, 59, 51, 101, 59, 51,
-    101, 58, 53, 103, 58, 53, 103, 58, 54, 104, 58, 54, 104, 57, 56, 106, 57, 56, 106, 56, 57, 108,
-    56, 57, 108, 56, 59, 110, 56, 59, 110, 55, 60, 111, 55, 60, 111, 54, 62, 113, 54, 62, 113, 54,
-    63, 115, 54, 63, 115, 53, 65, 116, 53, 65, 116, 53, 67, 118, 53, 67, 118, 52, 68, 120, 52, 68,
-    120, 52, 70, 122, 52, 70, 122, 52, 72, 123, 52, 72, 123, 52, 73, 125, 52, 73, 125, 51, 75, 127,
-    51, 75, 127, 51, 77, 128, 51, 77, 128, 51, 79, 130, 51, 79, 130, 51, 81, 132, 51, 81, 132, 51,
-    82, 133, 51, 82, 133, 52, 84, 135, 52, 84, 135, 52, 86, 137, 52, 86, 137, 52, 88, 138, 52, 88,
-    138, 53, 90, 140, 53, 90, 140, 53, 92, 141, 53, 92, 141, 54, 94, 143, 54, 94, 143, 55, 95, 144,
-    55, 95, 144, 56, 97, 146, 56, 97, 146, 57, 99, 148, 57, 99, 148, 58, 101, 149, 58, 101, 149,
-    59, 103, 151, 59, 103, 151, 60, 105, 152, 60, 105, 152, 61, 107, 154, 61, 107, 154, 63, 109,
-    155, 63, 109, 155, 64, 111, 156, 64, 111, 156, 66, 113, 158, 66, 113, 158, 67, 115, 159, 67,
-    115, 159, 69, 117, 161, 69, 117, 161, 71, 119, 162, 71, 119, 162, 73, 121, 164, 73, 121, 164,
-    75, 123, 165, 75, 123, 165, 77, 125, 166, 77, 125, 166, 79, 127, 168, 79, 127, 168, 81, 129,
-    169, 81, 129, 169, 83, 131, 171, 83, 131, 171, 85, 133, 172, 85, 133, 172, 87, 135, 173, 87,
-    135, 173, 90, 137, 175, 90, 137, 175, 92, 139, 176, 92, 139, 176, 94, 141, 177, 94, 141, 177,
-    97, 143, 178, 97, 143, 178, 99, 145, 180, 99, 145, 180, 102, 147, 181, 102, 147, 181, 104, 149,
-    182, 104, 149, 182, 107, 151, 183, 107, 151, 183, 109, 152, 184, 109, 152, 184, 112, 154, 186,
-    112, 154, 186, 114, 156, 187, 114, 156, 187, 117, 158, 188, 117, 158, 188, 120, 160, 189, 120,
-    160, 189, 122, 162, 190, 122, 162, 190, 125, 164, 191, 125, 164, 191, 128, 165, 192, 128, 165,
-    192, 131, 167, 193, 131, 167, 193, 133, 169, 194, 133, 169, 194, 136, 170, 194, 136, 170, 194,
-    139, 172, 195, 139, 172, 195, 142, 174, 196, 142, 174, 196, 144, 175, 197, 144, 175, 197, 147,
-    177, 197, 147, 177, 197, 150, 178, 198, 150, 178, 198, 153, 180, 198, 153, 180, 198, 155, 181,
-    199, 155, 181, 199, 158, 182, 199, 158, 182, 199, 161, 184, 200, 161, 184, 200, 164, 185, 200,
-    164, 185, 200, 166, 186, 200, 166, 186, 200, 169, 187, 200, 169, 187, 200, 171, 188, 200, 171,
-    188, 200, 174, 189, 200, 174, 189, 200, 176, 190, 200, 176, 190, 200, 179, 191, 200, 179, 191,
-    200, 181, 192, 200, 181, 192, 200, 184, 192, 199, 184, 192, 199, 186, 193, 199, 186, 193, 199,
-    188, 193, 198, 188, 193, 198, 190, 194, 198, 190, 194, 198, 192, 194, 197, 192, 194, 197, 194,
-    194, 196, 194, 194, 196, 196, 194, 195, 196, 194, 195, 198, 195, 194, 198, 195, 194, 200, 195,
-    193, 200, 195, 193, 201, 194, 192, 201, 194, 192, 203, 194, 191, 203, 194, 191, 205, 194, 190,
-    205, 194, 190, 206, 194, 188, 206, 194, 188, 207, 193, 187, 207, 193, 187, 209, 193, 185, 209,
-    193, 185, 210, 192, 184, 210, 192, 184, 211, 192, 182, 211, 192, 182, 212, 191, 181, 212, 191,
-    181, 213, 190, 179, 213, 190, 179, 213, 189, 177, 213, 189, 177, 214, 189, 175, 214, 189, 175,
-    215, 188, 173, 215, 188, 173, 215, 187, 172, 215, 187, 172, 216, 186, 170, 216, 186, 170, 216,
-    184, 168, 216, 184, 168, 217, 183, 166, 217, 183, 166, 217, 182, 164, 217, 182, 164, 217, 181,
-    161, 217, 181, 161, 217, 180, 159, 217, 180, 159, 217, 178, 157, 217, 178, 157, 217, 177, 155,
-    217, 177, 155, 217, 175, 153, 217, 175, 153, 217, 174, 151, 217, 174, 151, 217, 172, 148, 217,
-    172, 148, 217, 171, 146, 217, 171, 146, 217, 169, 144, 217, 169, 144, 216, 168, 141, 216, 168,
-    141, 216, 166, 139, 216, 166, 139, 216, 165, 137, 216, 165, 137, 215, 163, 135, 215, 163, 135,
-    215, 161, 132, 215, 161, 132, 214, 160, 130, 214, 160, 130, 213, 158, 128, 213, 158, 128, 213,
-    156, 125, 213, 156, 125, 212, 154, 123, 212, 154, 123, 212, 152, 121, 212, 152, 121, 211, 151,
-    118, 211, 151, 118, 210, 149, 116, 210, 149, 116, 209, 147, 114, 209, 147, 114, 208, 145, 111,
-    208, 145, 111, 208, 143, 109, 208, 143, 109, 207, 141, 107, 207, 141, 107, 206, 139, 104, 206,
-    139, 104, 205, 138, 102, 205, 138, 102, 204, 136, 100, 204, 136, 100, 203, 134, 97, 203, 134,
-    97, 202, 132, 95, 202, 132, 95, 200, 130, 93, 200, 130, 93, 199, 128, 91, 199, 128, 91, 198,
-    126, 88, 198, 126, 88, 197, 124, 86, 197, 124, 86, 196, 121, 84, 196, 121, 84, 194, 119, 82,
-    194, 119, 82, 193, 117, 79, 193, 117, 79, 192, 115, 77, 192, 115, 77, 190, 113, 75, 190, 113,
-    75, 189, 111, 73, 189, 111, 73, 187, 109, 71, 187, 109, 71, 186, 106, 69, 186, 106, 69, 184,
-    104, 67, 184, 104, 67, 183, 102, 65, 183, 102, 65, 181, 100, 63, 181, 100, 63, 179, 98, 61,
-    179, 98, 61, 178, 95, 59, 178, 95, 59, 176, 93, 57, 176, 93, 57, 174, 91, 55, 174, 91, 55, 172,
-    89, 53, 172, 89, 53, 170, 86, 51, 170, 86, 51, 169, 84, 50, 169, 84, 50, 167, 82, 48, 167, 82,
-    48, 165, 80, 47, 165, 80, 47, 163, 77, 45, 163, 77, 45, 161, 75, 44, 161, 75, 44, 159, 73, 42,
-    159, 73, 42, 157, 71, 41, 157, 71, 41, 155, 69, 40, 155, 69, 40, 153, 67, 39, 153, 67, 39, 151,
-    65, 38, 151, 65, 38, 149, 62, 37, 149, 62, 37, 147, 60, 36, 147, 60, 36, 145, 59, 35, 145, 59,
-    35, 143, 57, 34, 143, 57, 34, 141, 55, 33, 141, 55, 33, 140, 53, 33, 140, 53, 33, 138, 51, 32,
-    138, 51, 32, 136, 49, 32, 136, 49, 32, 134, 48, 31, 134, 48, 31, 132, 46, 31, 132, 46, 31, 130,
-    44, 31, 130, 44, 31, 129, 43, 30, 129, 43, 30, 127, 41, 30, 127, 41, 30, 125, 40, 30, 125, 40,
-    30, 124, 39, 30, 124, 39, 30, 122, 37, 30, 122, 37, 30, 121, 36, 30, 121, 36, 30, 119, 35, 30,
-    119, 35, 30, 118, 34, 31, 118, 34, 31, 116, 33, 31, 116, 33, 31, 115, 32, 31, 115, 32, 31, 114,
-    31, 31, 114, 31, 31, 112, 30, 32, 112, 30, 32, 111, 29, 32, 111, 29, 32, 110, 28, 32, 110, 28,
-    32, 108, 27, 33, 108, 27, 33, 107, 26, 33, 107, 26, 33, 106, 26, 34, 106, 26, 34, 105, 25, 34,
-    105, 25, 34, 104, 25, 35, 104, 25, 35, 103, 24, 35, 103, 24, 35, 102, 24, 36, 102, 24, 36, 101,
-    23, 37, 101, 23, 37, 100, 23, 37, 100, 23, 37, 99, 22, 38, 99, 22, 38, 98, 22, 39, 98, 22, 39,
-    97, 22, 39, 97, 22, 39, 96, 22, 40, 96, 22, 40, 95, 22, 41, 95, 22, 41, 94, 21, 42, 94, 21, 42,
-    93, 21, 43, 93, 21, 43, 92, 21, 43, 92, 21, 43, 91, 21, 44, 91, 21, 44, 90, 21, 45, 90, 21, 45,
-    90, 21, 46, 90, 21, 46, 89, 21, 47, 89, 21, 47, 88, 22, 48, 88, 22, 48, 87, 22, 49, 87, 22, 49,
-    86, 22, 50, 86, 22, 50, 86, 22, 51, 86, 22, 51, 85, 22, 52, 85, 22, 52, 84, 23, 53, 84, 23, 53,
-    83, 23, 54, 83, 23, 54, 83, 23, 55, 83, 23, 55, 82, 24, 56, 82, 24, 56, 81, 24, 57, 81, 24, 57,
-    80, 25, 58, 80, 25, 58, 80, 25, 60, 80, 25, 60,
-];
+include!(concat!(env!("OUT_DIR"), "/colormaps.rs"));
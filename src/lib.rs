@@ -3,24 +3,46 @@ use base64::{
     engine::{GeneralPurpose, GeneralPurposeConfig},
     Engine,
 };
+use coloring::{colorize_buffer, ExteriorColoring, InteriorColoring, PaletteMapping};
 use futures::{channel::mpsc, SinkExt, StreamExt};
-use image::{ImageFormat, Rgba, RgbaImage};
+use image::{ImageFormat, RgbaImage};
 use leptos::task;
 use num::complex::Complex64;
-use std::io::Cursor;
-
+use orbit_trap::OrbitTrap;
+use std::{io::Cursor, sync::Arc};
+use viewport::Viewport;
+
+pub mod animation;
+pub mod buddhabrot;
+pub mod color;
+pub mod coloring;
 pub mod colormaps;
 pub mod options;
+pub mod orbit_trap;
+pub mod palette;
+pub mod viewport;
 
 pub const MIN_STEPS: u32 = 150;
 pub const MAX_STEPS: u32 = 1024;
 pub const BAILOUT_NUM: f64 = 15.0;
+pub const SEARCH_REGION: [f64; 4] = [-1.5, 1.0, 0.0, 1.0];
+pub const SEARCH_ATTEMPTS: u32 = 10_000;
+pub const STRIPE_DENSITY: f64 = 5.0;
+
+const PERIOD_EPSILON: f64 = 1e-20;
 
 #[derive(Clone, Copy)]
 pub struct MandelbrotConfig {
     pub min_steps: u32,
     pub max_steps: u32,
     pub bailout_num: f64,
+    /// Trap orbits are measured against, skipped when no coloring uses it.
+    pub trap: Option<OrbitTrap>,
+    /// Stripes per turn around the origin for the stripe average.
+    pub stripe_density: f64,
+    /// Whether to accumulate the stripe and triangle inequality averages, which cost a few
+    /// transcendental functions per step.
+    pub orbit_averages: bool,
 }
 
 impl Default for MandelbrotConfig {
@@ -29,12 +51,23 @@ impl Default for MandelbrotConfig {
             min_steps: MIN_STEPS,
             max_steps: MAX_STEPS,
             bailout_num: 1.0 * 10.0f64.powf(BAILOUT_NUM),
+            trap: None,
+            stripe_density: STRIPE_DENSITY,
+            orbit_averages: false,
         }
     }
 }
 
-pub fn rand_range(min: f64, max: f64) -> f64 {
-    let u = fastrand::f64();
+impl MandelbrotConfig {
+    /// Whether samples iterated with this config hold everything coloring with `needed` reads.
+    pub fn covers(&self, needed: &MandelbrotConfig) -> bool {
+        (self.orbit_averages || !needed.orbit_averages)
+            && (needed.trap.is_none() || needed.trap == self.trap)
+    }
+}
+
+pub fn rand_range(rng: &mut fastrand::Rng, min: f64, max: f64) -> f64 {
+    let u = rng.f64();
     lerp(min, max, u)
 }
 
@@ -46,90 +79,414 @@ fn abs_square(input: Complex64) -> f64 {
     input.re.powf(2.0) + input.im.powf(2.0)
 }
 
-pub fn mandelbrot(input: (f64, f64), cfg: &MandelbrotConfig) -> u32 {
+/// The result of iterating a single point.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Sample {
+    /// Steps taken before escaping, or the step limit for points inside the set.
+    pub steps: u32,
+    pub inside: bool,
+    /// Length of the cycle an inside point settled into, zero if none was detected.
+    pub period: u32,
+    /// |z| after the last step.
+    pub magnitude: f64,
+    /// Estimated distance from an escaped point to the set, zero for points inside.
+    pub distance: f64,
+    /// Closest the orbit came to the orbit trap, infinite when there is no trap.
+    pub trap_distance: f64,
+    /// Average of `sin(stripe_density * arg z)` along the orbit, scaled to `0.0..=1.0`.
+    pub stripe: f64,
+    /// Average of where each |z| fell between its triangle inequality bounds, from 0 to 1.
+    pub triangle: f64,
+}
+
+impl Sample {
+    /// Continuous escape count, interpolated between steps by how far past the bailout z went.
+    pub fn smooth_steps(&self) -> f64 {
+        if self.inside || self.magnitude <= 1.0 {
+            return self.steps as f64;
+        }
+
+        (self.steps as f64 + 1.0 - self.magnitude.ln().log2()).max(0.0)
+    }
+}
+
+/// Running sums of the per-step statistics averaged along an orbit.
+///
+/// The sums before the last step are kept so the averages can be interpolated with the
+/// fractional part of the smooth iteration count, which avoids banding at step boundaries.
+#[derive(Default)]
+struct OrbitAverages {
+    count: u32,
+    stripe: f64,
+    triangle: f64,
+    last_stripe: f64,
+    last_triangle: f64,
+}
+
+impl OrbitAverages {
+    fn add(&mut self, stripe: f64, triangle: f64) {
+        self.last_stripe = stripe;
+        self.last_triangle = triangle;
+        self.stripe += stripe;
+        self.triangle += triangle;
+        self.count += 1;
+    }
+
+    /// The stripe and triangle averages, blended from the average without the last step at
+    /// `t = 0` to the full average at `t = 1`.
+    fn finish(&self, t: f64) -> (f64, f64) {
+        let average = |sum: f64, last: f64| {
+            if self.count < 2 {
+                return sum;
+            }
+            let n = self.count as f64;
+            lerp((sum - last) / (n - 1.0), sum / n, t)
+        };
+
+        (
+            average(self.stripe, self.last_stripe),
+            average(self.triangle, self.last_triangle),
+        )
+    }
+}
+
+pub fn sample(input: (f64, f64), cfg: &MandelbrotConfig) -> Sample {
     let c0 = Complex64::new(input.0, input.1);
     let mut c = c0;
     let mut dc = Complex64::new(1.0, 0.0);
     let mut dc_sum = Complex64::new(0.0, 0.0);
+    // Derivative of z with respect to c0, for the distance estimate.
+    let mut dz = Complex64::new(1.0, 0.0);
+    let mut trap_distance = cfg.trap.map_or(f64::INFINITY, |trap| trap.distance(c0));
+    let mut averages = OrbitAverages::default();
+    let c0_norm = c0.norm();
+
+    let mut checkpoint = c0;
+    let mut checkpoint_n = 0;
+    let mut period = 0;
 
     for n in 1..cfg.max_steps {
+        let previous = abs_square(c);
+        dz = 2.0 * c * dz + 1.0;
         c = c.powf(2.0) + c0;
         dc = 2.0 * dc * c + 1.0;
         dc_sum += dc;
+        if let Some(trap) = &cfg.trap {
+            trap_distance = trap_distance.min(trap.distance(c));
+        }
+
+        if cfg.orbit_averages {
+            // |z^2 + c| lies between ||z|^2 - |c|| and |z|^2 + |c|.
+            let low = (previous - c0_norm).abs();
+            let high = previous + c0_norm;
+            averages.add(
+                0.5 * (cfg.stripe_density * c.arg()).sin() + 0.5,
+                if high > low {
+                    (c.norm() - low) / (high - low)
+                } else {
+                    0.0
+                },
+            );
+        }
 
         if abs_square(dc_sum) >= cfg.bailout_num {
-            return n;
+            let magnitude = c.norm();
+            let mut sample = Sample {
+                steps: n,
+                inside: false,
+                period: 0,
+                magnitude,
+                distance: distance_estimate(magnitude, dz),
+                trap_distance,
+                ..Default::default()
+            };
+            (sample.stripe, sample.triangle) = averages.finish(sample.smooth_steps().fract());
+            return sample;
+        }
+
+        // Brent's cycle detection, comparing against checkpoints taken at powers of two.
+        if period == 0 {
+            if abs_square(c - checkpoint) < PERIOD_EPSILON {
+                period = n - checkpoint_n;
+            } else if n.is_power_of_two() {
+                checkpoint = c;
+                checkpoint_n = n;
+            }
         }
     }
 
-    0
+    Sample {
+        steps: cfg.max_steps,
+        inside: true,
+        period,
+        magnitude: c.norm(),
+        distance: 0.0,
+        trap_distance,
+        stripe: averages.finish(1.0).0,
+        triangle: averages.finish(1.0).1,
+    }
 }
 
-pub fn choose_center(x: &mut f64, y: &mut f64, cfg: &MandelbrotConfig) -> u32 {
-    let mut steps = 0;
-    while !(cfg.min_steps..cfg.max_steps).contains(&steps) {
-        *x = rand_range(-1.5, 1.0);
-        *y = rand_range(0.0, 1.0);
-        steps = mandelbrot((*x, *y), cfg);
+/// Exterior distance estimate from the final |z| and its derivative.
+fn distance_estimate(magnitude: f64, dz: Complex64) -> f64 {
+    let derivative = dz.norm();
+    if magnitude <= 1.0 || !derivative.is_finite() {
+        return 0.0;
     }
-    steps
+
+    magnitude * magnitude.ln() / derivative
 }
 
-async fn gen_image(options: options::Options) -> Vec<u8> {
-    let cfg = MandelbrotConfig {
-        min_steps: options.step_limits[0],
-        max_steps: options.step_limits[1],
-        bailout_num: 1.0 * 10.0f64.powf(options.bailout_num),
-    };
+/// Steps taken for a point to escape, or `0` if it never does.
+pub fn mandelbrot(input: (f64, f64), cfg: &MandelbrotConfig) -> u32 {
+    let sample = sample(input, cfg);
+    if sample.inside {
+        0
+    } else {
+        sample.steps
+    }
+}
 
-    let seed = options.rng_seed.unwrap_or_else(fastrand::get_seed);
+const PROBE_SIZE: usize = 16;
+const PROBE_CANDIDATES: usize = 8;
 
-    fastrand::seed(seed);
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    NoInterestingPoint {
+        attempts: u32,
+    },
+    /// The random colormap settings leave no colormap to draw from.
+    NoColormap,
+}
 
-    let (width, height) = (options.dimensions[0], options.dimensions[1]);
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoInterestingPoint { attempts } => write!(
+                f,
+                "No interesting point found in the search region after {} attempts",
+                attempts
+            ),
+            Error::NoColormap => write!(f, "No colormap matches the random colormap settings"),
+        }
+    }
+}
 
-    let palette = if let Some(colormap) = options.colormap {
-        colormap.to_colormap()
-    } else {
-        let choice = fastrand::usize(0..options::COLORMAP_CHOICES.len() - 1);
+impl std::error::Error for Error {}
 
-        options::COLORMAP_CHOICES[choice].to_colormap()
-    };
+pub struct CenterCandidate {
+    pub viewport: Viewport,
+    pub steps: u32,
+    pub score: f64,
+}
 
-    let steps;
+pub fn random_scale(rng: &mut fastrand::Rng, steps: u32) -> f64 {
+    (steps as f64).powf(rand_range(rng, -2.5, -1.0))
+}
 
-    fastrand::seed(seed);
+/// Scores a view by rendering a low resolution probe of it.
+///
+/// Views with many iteration boundaries and a wide spread of iteration counts score higher,
+/// views that are a single flat color score zero.
+pub fn score_view(viewport: &Viewport, cfg: &MandelbrotConfig) -> f64 {
+    let step = |i: usize| i as f64 / (PROBE_SIZE as f64 - 1.0);
+
+    let probe: Vec<u32> = (0..PROBE_SIZE)
+        .flat_map(|y| (0..PROBE_SIZE).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let point = viewport.point_at(step(x), step(y));
+            mandelbrot((point.re, point.im), cfg)
+        })
+        .collect();
+    let mut edges = 0;
+    for y in 0..PROBE_SIZE {
+        for x in 0..PROBE_SIZE {
+            let value = probe[y * PROBE_SIZE + x];
+            if x + 1 < PROBE_SIZE && probe[y * PROBE_SIZE + x + 1] != value {
+                edges += 1;
+            }
+            if y + 1 < PROBE_SIZE && probe[(y + 1) * PROBE_SIZE + x] != value {
+                edges += 1;
+            }
+        }
+    }
+    let edge_density = edges as f64 / (2 * PROBE_SIZE * (PROBE_SIZE - 1)) as f64;
 
-    let center = match options.image_center {
-        Some(v) => {
-            let point = (v[0], v[1]);
-            steps = mandelbrot(point, &cfg);
-            point
+    let mean = probe.iter().map(|&n| n as f64).sum::<f64>() / probe.len() as f64;
+    let variance = probe
+        .iter()
+        .map(|&n| (n as f64 - mean).powi(2))
+        .sum::<f64>()
+        / probe.len() as f64;
+    let spread = variance.sqrt() / cfg.max_steps as f64;
+
+    edge_density * spread
+}
+
+/// Searches the options' search region for a center whose step count is within the configured
+/// limits, keeping the best scoring of the first few candidates found.
+pub fn find_center(
+    rng: &mut fastrand::Rng,
+    cfg: &MandelbrotConfig,
+    options: &options::Options,
+) -> Result<CenterCandidate, Error> {
+    let region = options.search_region;
+    let mut best: Option<CenterCandidate> = None;
+    let mut candidates = 0;
+
+    for _ in 0..options.search_attempts {
+        let center = Complex64::new(
+            rand_range(rng, region[0], region[1]),
+            rand_range(rng, region[2], region[3]),
+        );
+
+        let steps = mandelbrot((center.re, center.im), cfg);
+        if !(cfg.min_steps..cfg.max_steps).contains(&steps) {
+            continue;
         }
-        None => {
-            let (mut x, mut y) = (0.0, 0.0);
-            steps = choose_center(&mut x, &mut y, &cfg);
-            (x, y)
+
+        let scale = options
+            .view_size
+            .map(|size| size / 2.0)
+            .unwrap_or_else(|| random_scale(rng, steps));
+        let viewport = Viewport::new(
+            center,
+            scale,
+            options.rotation.to_radians(),
+            options.dimensions,
+        );
+
+        let score = score_view(&viewport, cfg);
+        if score <= 0.0 {
+            continue;
+        }
+
+        if best.as_ref().is_none_or(|b| score > b.score) {
+            best = Some(CenterCandidate {
+                viewport,
+                steps,
+                score,
+            });
+        }
+
+        candidates += 1;
+        if candidates >= PROBE_CANDIDATES {
+            break;
+        }
+    }
+
+    best.ok_or(Error::NoInterestingPoint {
+        attempts: options.search_attempts,
+    })
+}
+
+#[derive(Clone, PartialEq)]
+pub struct RenderedImage {
+    pub data: String,
+    pub options: options::Options,
+    pub iterations: Arc<IterationBuffer>,
+}
+
+impl From<&options::Options> for MandelbrotConfig {
+    fn from(options: &options::Options) -> Self {
+        Self {
+            min_steps: options.step_limits[0],
+            max_steps: options.step_limits[1],
+            bailout_num: 1.0 * 10.0f64.powf(options.bailout_num),
+            trap: (options.exterior_coloring == ExteriorColoring::OrbitTrap
+                || options.interior_coloring == InteriorColoring::OrbitTrap)
+                .then(|| {
+                    OrbitTrap::new(
+                        options.trap_shape,
+                        Complex64::new(options.trap_center[0], options.trap_center[1]),
+                        options.trap_angle.to_radians(),
+                        options.trap_radius,
+                    )
+                }),
+            stripe_density: options.stripe_density,
+            orbit_averages: matches!(
+                options.exterior_coloring,
+                ExteriorColoring::StripeAverage | ExteriorColoring::TriangleInequality
+            ),
+        }
+    }
+}
+
+/// The options' colormap, or the one drawn for `seed` when it is left random.
+pub fn resolve_colormap(options: &options::Options, seed: u64) -> Result<options::Colormap, Error> {
+    if let Some(colormap) = options.colormap {
+        return Ok(colormap);
+    }
+
+    let mut rng = fastrand::Rng::with_seed(seed);
+    options::random_colormap(&mut rng, &options.random_colormaps, options.colorblind_safe)
+        .ok_or(Error::NoColormap)
+}
+
+/// Picks values for every randomized option, returning the fully resolved options and their view.
+pub fn resolve(options: &options::Options) -> Result<(options::Options, Viewport), Error> {
+    let cfg = MandelbrotConfig::from(options);
+
+    let seed = options.rng_seed.unwrap_or_else(|| fastrand::u64(..));
+    let colormap = resolve_colormap(options, seed)?;
+
+    let mut rng = fastrand::Rng::with_seed(seed);
+
+    let viewport = match options.image_center {
+        Some(v) => {
+            let center = Complex64::new(v[0], v[1]);
+            let scale = options.view_size.map(|size| size / 2.0).unwrap_or_else(|| {
+                random_scale(&mut rng, mandelbrot((center.re, center.im), &cfg))
+            });
+            Viewport::new(
+                center,
+                scale,
+                options.rotation.to_radians(),
+                options.dimensions,
+            )
         }
+        None => find_center(&mut rng, &cfg, options)?.viewport,
     };
 
-    fastrand::seed(seed);
+    let resolved = options::Options {
+        image_center: Some([viewport.center.re, viewport.center.im]),
+        view_size: Some(viewport.scale * 2.0),
+        colormap: Some(colormap),
+        rng_seed: Some(seed),
+        ..options.clone()
+    };
 
-    let dx;
-    let dy;
+    Ok((resolved, viewport))
+}
 
-    if let Some(size) = options.view_size {
-        dx = size[0] / 2.0;
-        dy = size[1] / 2.0;
-    } else {
-        dx = (steps as f64).powf(rand_range(-2.5, -1.0));
-        dy = dx * height as f64 / width as f64;
+#[derive(Clone, PartialEq, Debug)]
+pub struct IterationBuffer {
+    pub width: u32,
+    pub height: u32,
+    /// Width of a pixel in the complex plane.
+    pub pixel_size: f64,
+    pub samples: Vec<Sample>,
+}
+
+impl IterationBuffer {
+    pub fn get(&self, x: u32, y: u32) -> Sample {
+        self.samples[(y * self.width + x) as usize]
     }
+}
+
+/// Iterates every pixel of the view, keeping the raw iteration counts so they can be colored
+/// again without recomputing.
+pub async fn compute(options: &options::Options, viewport: Viewport) -> IterationBuffer {
+    let cfg = MandelbrotConfig::from(options);
 
-    let (xmin, xmax) = (center.0 - dx, center.0 + dx);
-    let (ymin, ymax) = (center.1 - dy, center.1 + dy);
+    let (width, height) = (viewport.dimensions[0], viewport.dimensions[1]);
 
-    let mut image = RgbaImage::new(width, height);
+    let mut buffer = IterationBuffer {
+        width,
+        height,
+        pixel_size: viewport.pixel_size(),
+        samples: vec![Sample::default(); (width * height) as usize],
+    };
 
     let (tx, rx) = mpsc::channel(100);
 
@@ -144,37 +501,39 @@ async fn gen_image(options: options::Options) -> Vec<u8> {
         let mut task_tx = tx.clone();
         task::spawn(async move {
             for pixel in group {
-                let scaled = (
-                    lerp(xmin, xmax, pixel.0 as f64 / (width as f64 - 1.0)),
-                    lerp(ymin, ymax, pixel.1 as f64 / (height as f64 - 1.0)),
-                );
-
-                let iteration = mandelbrot(scaled, &cfg);
-
-                let index = (3 * iteration as usize).clamp(0, palette.len() - 3);
+                let point = viewport.pixel_to_complex(pixel.0 as f64, pixel.1 as f64);
 
-                let sample = &palette[index..];
+                let sample = sample((point.re, point.im), &cfg);
 
-                task_tx
-                    .send((
-                        pixel.0,
-                        pixel.1,
-                        Rgba::from([sample[0], sample[1], sample[2], 0xFF]),
-                    ))
-                    .await
-                    .unwrap();
+                task_tx.send((pixel.0, pixel.1, sample)).await.unwrap();
             }
         })
     }
     tracing::info!("Tasks spawned");
 
     let mut values = rx.take((width * height) as usize);
-    while let Some((x, y, color)) = values.next().await {
-        image.put_pixel(x, y, color);
+    while let Some((x, y, sample)) = values.next().await {
+        buffer.samples[(y * width + x) as usize] = sample;
     }
 
     tracing::info!("Pixels recieved");
 
+    buffer
+}
+
+pub async fn render(
+    options: &options::Options,
+    viewport: Viewport,
+    palette: &[u8],
+    palette_offset: f64,
+) -> RgbaImage {
+    let buffer = compute(options, viewport).await;
+    let mut mapping = PaletteMapping::from(options);
+    mapping.offset += palette_offset;
+    colorize_buffer(&buffer, palette, &mapping)
+}
+
+pub fn encode_png(image: &RgbaImage) -> Vec<u8> {
     let mut bytes: Vec<u8> = Vec::new();
     image
         .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
@@ -185,7 +544,183 @@ async fn gen_image(options: options::Options) -> Vec<u8> {
     bytes
 }
 
-pub async fn encode_image(options: options::Options) -> String {
-    let bytes = gen_image(options).await;
-    GeneralPurpose::new(&STANDARD, GeneralPurposeConfig::new()).encode(&bytes)
+pub fn encode_base64(bytes: &[u8]) -> String {
+    GeneralPurpose::new(&STANDARD, GeneralPurposeConfig::new()).encode(bytes)
+}
+
+async fn gen_image(
+    options: options::Options,
+) -> Result<(Vec<u8>, options::Options, IterationBuffer), Error> {
+    let (resolved, viewport) = resolve(&options)?;
+    let palette = resolved
+        .palette()
+        .expect("resolved options always have a colormap");
+
+    let buffer = compute(&resolved, viewport).await;
+    let image = colorize_buffer(&buffer, &palette, &PaletteMapping::from(&resolved));
+
+    Ok((encode_png(&image), resolved, buffer))
+}
+
+pub async fn encode_image(options: options::Options) -> Result<RenderedImage, Error> {
+    let (bytes, options, iterations) = gen_image(options).await?;
+    Ok(RenderedImage {
+        data: encode_base64(&bytes),
+        options,
+        iterations: Arc::new(iterations),
+    })
+}
+
+impl RenderedImage {
+    /// Whether the retained iterations hold everything coloring with `options` reads, so it can
+    /// be recolored without iterating again.
+    pub fn covers(&self, options: &options::Options) -> bool {
+        MandelbrotConfig::from(&self.options).covers(&MandelbrotConfig::from(options))
+    }
+
+    /// Colors the retained iterations again with the coloring settings of `options`, shifting the
+    /// palette by an extra `palette_offset` entries. Returns the image as base64 encoded PNG.
+    pub fn recolor(&self, options: &options::Options, palette_offset: f64) -> String {
+        let palette = options
+            .palette()
+            .or_else(|| self.options.palette())
+            .expect("resolved options always have a colormap");
+
+        let mut mapping = PaletteMapping::from(options);
+        mapping.offset += palette_offset;
+
+        let image = colorize_buffer(&self.iterations, &palette, &mapping);
+        encode_base64(&encode_png(&image))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::task::{
+        any_spawner::{PinnedFuture, PinnedLocalFuture},
+        CustomExecutor, Executor,
+    };
+
+    struct ThreadExecutor;
+
+    impl CustomExecutor for ThreadExecutor {
+        fn spawn(&self, fut: PinnedFuture<()>) {
+            std::thread::spawn(move || futures::executor::block_on(fut));
+        }
+
+        fn spawn_local(&self, fut: PinnedLocalFuture<()>) {
+            futures::executor::block_on(fut);
+        }
+
+        fn poll_local(&self) {}
+    }
+
+    fn seeded_options(seed: u64) -> options::Options {
+        options::Options {
+            dimensions: [48, 32],
+            rng_seed: Some(seed),
+            chunk_size: 64,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn same_seed_renders_identically_alongside_other_renders() {
+        let _ = Executor::init_custom_executor(ThreadExecutor);
+
+        let (expected, _, _) = futures::executor::block_on(gen_image(seeded_options(42))).unwrap();
+
+        let renders: Vec<_> = (0..8)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    let seed = if i % 2 == 0 { 42 } else { i };
+                    (
+                        seed,
+                        futures::executor::block_on(gen_image(seeded_options(seed))),
+                    )
+                })
+            })
+            .collect();
+
+        for render in renders {
+            let (seed, result) = render.join().unwrap();
+            let (bytes, _, _) = result.unwrap();
+            if seed == 42 {
+                assert_eq!(bytes, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn unreachable_step_limits_fail_instead_of_looping() {
+        let cfg = MandelbrotConfig {
+            min_steps: 900,
+            max_steps: 1000,
+            ..Default::default()
+        };
+        let options = options::Options {
+            search_region: [2.0, 3.0, 2.0, 3.0],
+            search_attempts: 50,
+            ..Default::default()
+        };
+        let mut rng = fastrand::Rng::with_seed(7);
+
+        let result = find_center(&mut rng, &cfg, &options);
+
+        assert_eq!(
+            result.err(),
+            Some(Error::NoInterestingPoint { attempts: 50 })
+        );
+    }
+
+    #[test]
+    fn inside_points_report_their_period() {
+        let cfg = MandelbrotConfig::default();
+
+        let origin = sample((0.0, 0.0), &cfg);
+        assert!(origin.inside);
+        assert_eq!(origin.period, 1);
+
+        let bulb = sample((-1.0, 0.0), &cfg);
+        assert!(bulb.inside);
+        assert_eq!(bulb.period, 2);
+
+        assert!(!sample((1.0, 1.0), &cfg).inside);
+    }
+
+    #[test]
+    fn distance_estimate_brackets_the_true_distance() {
+        let cfg = MandelbrotConfig::default();
+
+        // The nearest point of the set to these is its tip on the real axis at -2.
+        for &(re, distance) in &[(-2.1, 0.1), (-3.0, 1.0)] {
+            let estimate = sample((re, 0.0), &cfg).distance;
+            assert!(
+                estimate > distance / 4.0 && estimate < distance * 4.0,
+                "estimated {} for a distance of {}",
+                estimate,
+                distance
+            );
+        }
+        assert_eq!(sample((0.0, 0.0), &cfg).distance, 0.0);
+    }
+
+    #[test]
+    fn orbit_averages_stay_within_their_range() {
+        let cfg = MandelbrotConfig {
+            orbit_averages: true,
+            ..Default::default()
+        };
+
+        for &point in &[(0.3, 0.0), (-0.75, 0.1), (-0.1, 0.9), (0.0, 0.0)] {
+            let sample = sample(point, &cfg);
+            assert!((0.0..=1.0).contains(&sample.stripe), "{:?}", sample);
+            assert!((0.0..=1.0).contains(&sample.triangle), "{:?}", sample);
+        }
+
+        let skipped = sample((-0.75, 0.1), &MandelbrotConfig::default());
+        assert_eq!((skipped.stripe, skipped.triangle), (0.0, 0.0));
+        assert_eq!(skipped.trap_distance, f64::INFINITY);
+    }
 }
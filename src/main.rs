@@ -1,5 +1,26 @@
-use leptos::{prelude::*, task};
-use webbrot::{encode_image, options::Options};
+use leptos::{ev, prelude::*, task};
+use std::time::Duration;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use webbrot::{
+    animation::{encode_animation, Animation, Keyframe, FRAMES_PER_KEYFRAME},
+    buddhabrot::{
+        encode_buddhabrot, Buddhabrot, DENSITY_COLORS, DENSITY_MODES, DENSITY_SAMPLES,
+        NEBULABROT_STEPS,
+    },
+    color::{COLOR_VISIONS, INTERPOLATIONS},
+    coloring::{
+        EXTERIOR_ALPHAS, EXTERIOR_COLORINGS, INTERIOR_COLORINGS, LIGHTINGS, PALETTE_SCALES,
+        PALETTE_WRAPS,
+    },
+    encode_image,
+    options::{
+        hex_color, parse_hex_color, random_pool, Colormap, Options, COLORMAP_CATEGORIES,
+        COLORMAP_CHOICES,
+    },
+    orbit_trap::TRAP_SHAPES,
+    palette::{GradientStop, Palette, PaletteFormat},
+};
 
 fn main() {
     tracing_wasm::set_as_global_default();
@@ -8,11 +29,133 @@ fn main() {
     mount_to_body(App);
 }
 
+const ZOOM_FACTOR: f64 = 2.0;
+const CYCLE_INTERVAL_MS: u64 = 50;
+/// Stops taken from a built-in colormap when the gradient editor starts from it.
+const EDITOR_STOPS: usize = 8;
+/// Stops drawn in each colormap picker swatch.
+const SWATCH_STOPS: usize = 16;
+
+fn options_from_url() -> Option<Options> {
+    let hash = window().location().hash().ok()?;
+    Options::decode(hash.strip_prefix('#').unwrap_or(&hash))
+}
+
+fn push_options_to_url(options: &Options) {
+    let url = format!("#{}", options.encode());
+    if let Ok(history) = window().history() {
+        if let Err(e) = history.push_state_with_url(&JsValue::NULL, "", Some(&url)) {
+            tracing::warn!("Failed to push history entry: {:?}", e);
+        }
+    }
+}
+
+fn replace_options_in_url(options: &Options) {
+    let url = format!("#{}", options.encode());
+    if let Ok(history) = window().history() {
+        if let Err(e) = history.replace_state_with_url(&JsValue::NULL, "", Some(&url)) {
+            tracing::warn!("Failed to replace history entry: {:?}", e);
+        }
+    }
+}
+
 #[component]
 fn App() -> impl IntoView {
-    let options = RwSignal::new(Options::default());
+    let shared = options_from_url();
+    let options = RwSignal::new(shared.clone().unwrap_or_default());
+    let form = RwSignal::new(options.get_untracked());
     let generate_image = Action::new(|o: &Options| encode_image(o.clone()));
     let image = generate_image.value();
+    let animation = RwSignal::new(Animation::default());
+    let generate_animation =
+        Action::new(|(o, a): &(Options, Animation)| encode_animation(o.clone(), a.clone()));
+    let palette_error = RwSignal::new(None::<String>);
+    // Whether the last render drew its colormap at random, so the picker can reveal it.
+    let random_draw = RwSignal::new(shared.as_ref().is_some_and(|o| o.colormap.is_none()));
+    let buddhabrot = RwSignal::new(Buddhabrot::default());
+    let generate_buddhabrot =
+        Action::new(|(o, b): &(Options, Buddhabrot)| encode_buddhabrot(o.clone(), b.clone()));
+
+    if let Some(o) = shared {
+        generate_image.dispatch(o);
+    }
+
+    let handle = window_event_listener(ev::popstate, move |_| {
+        let restored = options_from_url().unwrap_or_default();
+        options.set(restored.clone());
+        form.set(restored.clone());
+        generate_image.dispatch(restored);
+    });
+    on_cleanup(move || handle.remove());
+
+    let coloring = Memo::new(move |_| {
+        options.with(|o| {
+            let mut coloring = Options::default();
+            coloring.copy_coloring(o);
+            coloring
+        })
+    });
+    let cycling = RwSignal::new(false);
+    let cycle_offset = RwSignal::new(0.0);
+
+    let recolored = move || {
+        image.with(|i| match i {
+            Some(Ok(rendered)) => {
+                let mut recolored = rendered.options.clone();
+                recolored.copy_coloring(&coloring.get());
+                Some((rendered.clone(), recolored))
+            }
+            _ => None,
+        })
+    };
+
+    let displayed = Memo::new(move |_| {
+        let (rendered, recolored) = recolored()?;
+        let offset = cycle_offset.get();
+        // Iterating again for values the render skipped, show the old colors meanwhile.
+        if (recolored == rendered.options && offset == 0.0) || !rendered.covers(&recolored) {
+            Some(rendered.data)
+        } else {
+            Some(rendered.recolor(&recolored, offset))
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some((_, recolored)) = recolored() {
+            replace_options_in_url(&recolored);
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some((rendered, recolored)) = recolored() {
+            if !rendered.covers(&recolored) {
+                generate_image.dispatch(recolored);
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        if cycling.get() {
+            let handle = set_interval_with_handle(
+                move || cycle_offset.update(|o| *o += 1.0),
+                Duration::from_millis(CYCLE_INTERVAL_MS),
+            )
+            .ok();
+            on_cleanup(move || {
+                if let Some(handle) = handle {
+                    handle.clear();
+                }
+            });
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some(Ok(rendered)) = image.get() {
+            cycle_offset.set(0.0);
+            options.set(rendered.options.clone());
+            form.set(rendered.options);
+        }
+    });
 
     view! {
         <div class="w-screen h-screen flex items-center justify-center relative">
@@ -23,13 +166,45 @@ fn App() -> impl IntoView {
                 {move || {
                     image
                         .get()
-                        .map(|d| {
-                            view! {
-                                <img
-                                    class="border-gray-800"
-                                    src=move || format!("data:image/png;base64, {}", d)
-                                    alt="Mandelbrot fractal image"
-                                />
+                        .map(|result| match result {
+                            Ok(rendered) => {
+                                let viewport = rendered.options.viewport();
+                                view! {
+                                    <img
+                                        class="border-gray-800 cursor-zoom-in"
+                                        title="Click to zoom in on a point"
+                                        on:click=move |ev| {
+                                            let Some(viewport) = viewport else {
+                                                return;
+                                            };
+                                            let img = event_target::<web_sys::Element>(&ev);
+                                            let ratio = viewport.dimensions[0] as f64
+                                                / img.client_width().max(1) as f64;
+                                            let point = viewport
+                                                .pixel_to_complex(
+                                                    ev.offset_x() as f64 * ratio,
+                                                    ev.offset_y() as f64 * ratio,
+                                                );
+                                            let mut zoomed = options.get_untracked();
+                                            zoomed.image_center = Some([point.re, point.im]);
+                                            zoomed.view_size = Some(viewport.zoom(ZOOM_FACTOR).scale * 2.0);
+                                            push_options_to_url(&zoomed);
+                                            generate_image.dispatch(zoomed);
+                                        }
+                                        src=move || {
+                                            format!(
+                                                "data:image/png;base64, {}",
+                                                displayed.get().unwrap_or_default(),
+                                            )
+                                        }
+                                        alt="Mandelbrot fractal image"
+                                    />
+                                }
+                                    .into_any()
+                            }
+                            Err(e) => {
+                                view! { <p class="text-red-500 text-sm">{e.to_string()}</p> }
+                                    .into_any()
                             }
                         })
                 }}
@@ -44,7 +219,7 @@ fn App() -> impl IntoView {
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value="960"
+                        prop:value=move || form.with(|o| o.dimensions[0].to_string())
                         min="0"
                         on:input=move |ev| {
                             options
@@ -64,7 +239,7 @@ fn App() -> impl IntoView {
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value="540"
+                        prop:value=move || form.with(|o| o.dimensions[1].to_string())
                         min="0"
                         on:input=move |ev| {
                             options
@@ -84,7 +259,9 @@ fn App() -> impl IntoView {
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value=""
+                        prop:value=move || {
+                            form.with(|o| o.image_center.map(|c| c[0].to_string()).unwrap_or_default())
+                        }
                         min="0"
                         on:input=move |ev| {
                             options
@@ -110,7 +287,9 @@ fn App() -> impl IntoView {
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value=""
+                        prop:value=move || {
+                            form.with(|o| o.image_center.map(|c| c[1].to_string()).unwrap_or_default())
+                        }
                         min="0"
                         on:input=move |ev| {
                             options
@@ -132,25 +311,18 @@ fn App() -> impl IntoView {
                 </div>
 
                 <div class="items-center justify-center">
-                    <p class="text-white text-sm">View size X:</p>
+                    <p class="text-white text-sm">View width:</p>
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value=""
+                        prop:value=move || {
+                            form.with(|o| o.view_size.map(|v| v.to_string()).unwrap_or_default())
+                        }
                         min="0"
                         on:input=move |ev| {
                             options
                                 .update(|o| {
-                                    match event_target_value(&ev).parse::<f64>() {
-                                        Ok(n) => {
-                                            if let Some(ref mut c) = o.view_size {
-                                                c[0] = n;
-                                            } else {
-                                                o.view_size = Some([n, 0.0]);
-                                            }
-                                        }
-                                        Err(_) => o.view_size = None,
-                                    }
+                                    o.view_size = event_target_value(&ev).parse::<f64>().ok();
                                 })
                         }
                     />
@@ -158,25 +330,18 @@ fn App() -> impl IntoView {
                 </div>
 
                 <div class="items-center justify-center">
-                    <p class="text-white text-sm">View size Y:</p>
+                    <p class="text-white text-sm">Rotation (degrees):</p>
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value=""
-                        min="0"
+                        prop:value=move || form.with(|o| o.rotation.to_string())
                         on:input=move |ev| {
                             options
                                 .update(|o| {
-                                    match event_target_value(&ev).parse::<f64>() {
-                                        Ok(n) => {
-                                            if let Some(ref mut c) = o.view_size {
-                                                c[1] = n;
-                                            } else {
-                                                o.view_size = Some([0.0, n]);
-                                            }
-                                        }
-                                        Err(_) => o.view_size = None,
-                                    }
+                                    o
+                                        .rotation = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(0.0);
                                 })
                         }
                     />
@@ -188,7 +353,7 @@ fn App() -> impl IntoView {
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value="150"
+                        prop:value=move || form.with(|o| o.step_limits[0].to_string())
                         min="0"
                         on:input=move |ev| {
                             options
@@ -208,13 +373,13 @@ fn App() -> impl IntoView {
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value="1024"
+                        prop:value=move || form.with(|o| o.step_limits[1].to_string())
                         min="0"
                         on:input=move |ev| {
                             options
                                 .update(|o| {
                                     o
-                                        .step_limits[0] = event_target_value(&ev)
+                                        .step_limits[1] = event_target_value(&ev)
                                         .parse::<f64>()
                                         .unwrap_or(webbrot::MAX_STEPS as f64) as u32;
                                 })
@@ -228,15 +393,99 @@ fn App() -> impl IntoView {
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value=""
+                        prop:value=move || {
+                            form.with(|o| o.rng_seed.map(|r| r.to_string()).unwrap_or_default())
+                        }
                         min="0"
                         on:input=move |ev| {
                             options
                                 .update(|o| {
-                                    match event_target_value(&ev).parse::<f64>() {
-                                        Ok(n) => o.rng_seed = Some(n as u64),
-                                        Err(_) => o.rng_seed = None,
-                                    }
+                                    o.rng_seed = event_target_value(&ev).trim().parse().ok();
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Search region (x min, x max, y min, y max):</p>
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="X min"
+                        prop:value=move || form.with(|o| o.search_region[0].to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .search_region[0] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(webbrot::SEARCH_REGION[0]);
+                                })
+                        }
+                    />
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="X max"
+                        prop:value=move || form.with(|o| o.search_region[1].to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .search_region[1] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(webbrot::SEARCH_REGION[1]);
+                                })
+                        }
+                    />
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Y min"
+                        prop:value=move || form.with(|o| o.search_region[2].to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .search_region[2] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(webbrot::SEARCH_REGION[2]);
+                                })
+                        }
+                    />
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Y max"
+                        prop:value=move || form.with(|o| o.search_region[3].to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .search_region[3] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(webbrot::SEARCH_REGION[3]);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Search attempts</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        prop:value=move || form.with(|o| o.search_attempts.to_string())
+                        min="1"
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .search_attempts = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(webbrot::SEARCH_ATTEMPTS as f64) as u32;
                                 })
                         }
                     />
@@ -248,7 +497,7 @@ fn App() -> impl IntoView {
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value="15"
+                        prop:value=move || form.with(|o| o.bailout_num.to_string())
                         min="0"
                         on:input=move |ev| {
                             options
@@ -263,12 +512,123 @@ fn App() -> impl IntoView {
 
                 </div>
 
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Orbit trap (shape, center X/Y, angle, radius):</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || form.with(|o| format!("{:?}", o.trap_shape))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            options
+                                .update(|o| {
+                                    if let Some(choice) = TRAP_SHAPES
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        o.trap_shape = *choice;
+                                    }
+                                });
+                        }
+                    >
+                        {TRAP_SHAPES
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Trap center X"
+                        prop:value=move || form.with(|o| o.trap_center[0].to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .trap_center[0] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(0.0);
+                                })
+                        }
+                    />
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Trap center Y"
+                        prop:value=move || form.with(|o| o.trap_center[1].to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .trap_center[1] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(0.0);
+                                })
+                        }
+                    />
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Trap angle (degrees)"
+                        prop:value=move || form.with(|o| o.trap_angle.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .trap_angle = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(0.0);
+                                })
+                        }
+                    />
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Trap radius"
+                        prop:value=move || form.with(|o| o.trap_radius.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .trap_radius = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(0.5);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Stripe density</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        prop:value=move || form.with(|o| o.stripe_density.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .stripe_density = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(webbrot::STRIPE_DENSITY);
+                                })
+                        }
+                    />
+
+                </div>
+
                 <div class="items-center justify-center">
                     <p class="text-white text-sm">Chunk size</p>
                     <input
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
                         type="number"
-                        value="15"
+                        prop:value=move || form.with(|o| o.chunk_size.to_string())
                         min="1"
                         on:input=move |ev| {
                             options
@@ -283,56 +643,1221 @@ fn App() -> impl IntoView {
 
                 </div>
 
+                <ColormapPicker options=options form=form random_draw=random_draw />
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">
+                        {move || {
+                            options
+                                .with(|o| match &o.custom_palette {
+                                    Some(p) => format!("Custom palette ({} stops)", p.stops().len()),
+                                    None => "Custom palette: none".to_string(),
+                                })
+                        }}
+                    </p>
+                    <input
+                        class="text-gray-500 text-sm"
+                        type="file"
+                        accept=".ggr,.gpl,.csv,.txt"
+                        on:change=move |ev| {
+                            let input: web_sys::HtmlInputElement = event_target(&ev);
+                            let Some(file) = input.files().and_then(|f| f.get(0)) else {
+                                return;
+                            };
+                            task::spawn_local(async move {
+                                let text = JsFuture::from(file.text())
+                                    .await
+                                    .ok()
+                                    .and_then(|t| t.as_string())
+                                    .unwrap_or_default();
+                                let loaded = PaletteFormat::from_file_name(&file.name())
+                                    .ok_or_else(|| format!("Unsupported palette file {}", file.name()))
+                                    .and_then(|format| {
+                                        Palette::parse(format, &text).map_err(|e| e.to_string())
+                                    });
+                                match loaded {
+                                    Ok(palette) => {
+                                        options.update(|o| o.custom_palette = Some(palette));
+                                        palette_error.set(None);
+                                    }
+                                    Err(e) => palette_error.set(Some(e)),
+                                }
+                            });
+                        }
+                    />
+                    <button
+                        on:click=move |_| options.update(|o| o.custom_palette = None)
+                        class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
+                    >
+                        Clear custom palette
+                    </button>
+                    {move || {
+                        palette_error
+                            .get()
+                            .map(|e| view! { <p class="text-red-500 text-sm">{e}</p> })
+                    }}
+                </div>
+
+                <GradientEditor options=options />
+
                 <div class="items-center justify-center">
-                    <label class="text-white text-sm" for="colormap">
-                        Colormap:
+                    <label class="text-white text-sm">
+                        <input
+                            class="mr-1"
+                            type="checkbox"
+                            prop:checked=move || cycling.get()
+                            on:change=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                cycling.set(checked);
+                                if !checked {
+                                    // Keep the colors on screen, and in the URL, where cycling stopped.
+                                    let offset = cycle_offset.get_untracked();
+                                    options.update(|o| o.palette_offset += offset);
+                                    form.update(|o| o.palette_offset += offset);
+                                    cycle_offset.set(0.0);
+                                }
+                            }
+                        />
+                        Cycle palette
                     </label>
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Palette offset</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        prop:value=move || form.with(|o| o.palette_offset.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .palette_offset = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(0.0);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Palette density</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        step="0.1"
+                        prop:value=move || form.with(|o| o.palette_density.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .palette_density = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(1.0);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Palette wrap</p>
                     <select
                         class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
-                        name="colormap"
-                        id="colormap"
+                        prop:value=move || form.with(|o| format!("{:?}", o.palette_wrap))
                         on:change=move |ev| {
                             let selected_value = event_target_value(&ev);
                             options
                                 .update(|o| {
-                                    o
-                                        .colormap = webbrot::options::COLORMAP_CHOICES
+                                    if let Some(choice) = PALETTE_WRAPS
                                         .iter()
                                         .find(|c| format!("{:?}", c) == selected_value)
-                                        .copied();
+                                    {
+                                        o.palette_wrap = *choice;
+                                    }
                                 });
                         }
                     >
+                        {PALETTE_WRAPS
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
 
-                        <option value="none" class="text-white text-sm">
-                            None
-                        </option>
-                        {move || {
-                            webbrot::options::COLORMAP_CHOICES
-                                .iter()
-                                .map(|c| {
-                                    view! {
-                                        <option
-                                            value=move || { format!("{:?}", c) }
-                                            class="text-white text-sm"
-                                        >
-                                            {move || format!("{:?}", c)}
-                                        </option>
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Palette scale</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || form.with(|o| format!("{:?}", o.palette_scale))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            options
+                                .update(|o| {
+                                    if let Some(choice) = PALETTE_SCALES
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        o.palette_scale = *choice;
                                     }
-                                })
-                                .collect_view()
-                        }}
-
+                                });
+                        }
+                    >
+                        {PALETTE_SCALES
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
                     </select>
                 </div>
 
-                <button
-                    on:click=move |_| {generate_image.dispatch(options.get());}
-                    class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Palette interpolation</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || form.with(|o| format!("{:?}", o.palette_interpolation))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            options
+                                .update(|o| {
+                                    if let Some(choice) = INTERPOLATIONS
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        o.palette_interpolation = *choice;
+                                    }
+                                });
+                        }
+                    >
+                        {INTERPOLATIONS
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+
+                <div class="items-center justify-center">
+                    <label class="text-white text-sm">
+                        <input
+                            class="mr-1"
+                            type="checkbox"
+                            prop:checked=move || form.with(|o| o.palette_reverse)
+                            on:change=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                options.update(|o| o.palette_reverse = checked)
+                            }
+                        />
+                        Reverse palette
+                    </label>
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Exterior coloring</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || form.with(|o| format!("{:?}", o.exterior_coloring))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            options
+                                .update(|o| {
+                                    if let Some(choice) = EXTERIOR_COLORINGS
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        o.exterior_coloring = *choice;
+                                    }
+                                });
+                        }
+                    >
+                        {EXTERIOR_COLORINGS
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Line width (pixels)</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        step="0.5"
+                        prop:value=move || form.with(|o| o.line_width.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .line_width = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(1.0);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Exterior alpha</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || form.with(|o| format!("{:?}", o.exterior_alpha))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            options
+                                .update(|o| {
+                                    if let Some(choice) = EXTERIOR_ALPHAS
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        o.exterior_alpha = *choice;
+                                    }
+                                });
+                        }
+                    >
+                        {EXTERIOR_ALPHAS
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Interior coloring</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || form.with(|o| format!("{:?}", o.interior_coloring))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            options
+                                .update(|o| {
+                                    if let Some(choice) = INTERIOR_COLORINGS
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        o.interior_coloring = *choice;
+                                    }
+                                });
+                        }
+                    >
+                        {INTERIOR_COLORINGS
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                    <input
+                        class="ml-1 align-middle"
+                        type="color"
+                        title="Interior color"
+                        prop:value=move || form.with(|o| format!("#{}", hex_color(o.interior_color)))
+                        on:input=move |ev| {
+                            if let Some(color) = parse_hex_color(&event_target_value(&ev)) {
+                                options.update(|o| o.interior_color = color);
+                            }
+                        }
+                    />
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Lighting</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || form.with(|o| format!("{:?}", o.lighting))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            options
+                                .update(|o| {
+                                    if let Some(choice) = LIGHTINGS
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        o.lighting = *choice;
+                                    }
+                                });
+                        }
+                    >
+                        {LIGHTINGS
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Light angle (degrees)</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        step="15"
+                        prop:value=move || form.with(|o| o.light_angle.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .light_angle = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(135.0);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Light height (degrees)</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        step="5"
+                        prop:value=move || form.with(|o| o.light_height.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .light_height = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(45.0);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Light strength</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        step="0.05"
+                        prop:value=move || form.with(|o| o.light_strength.to_string())
+                        on:input=move |ev| {
+                            options
+                                .update(|o| {
+                                    o
+                                        .light_strength = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(0.75);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Random colormap from:</p>
+                    {COLORMAP_CATEGORIES
+                        .iter()
+                        .map(|&category| {
+                            view! {
+                                <label class="text-white text-sm mr-2">
+                                    <input
+                                        class="mr-1"
+                                        type="checkbox"
+                                        prop:checked=move || {
+                                            form.with(|o| o.random_colormaps.contains(&category))
+                                        }
+                                        on:change=move |ev| {
+                                            let checked = event_target_checked(&ev);
+                                            options
+                                                .update(|o| {
+                                                    o.random_colormaps.retain(|c| *c != category);
+                                                    if checked {
+                                                        o.random_colormaps.push(category);
+                                                    }
+                                                })
+                                        }
+                                    />
+                                    {category.to_string()}
+                                </label>
+                            }
+                        })
+                        .collect_view()}
+                    <label class="text-white text-sm mr-2">
+                        <input
+                            class="mr-1"
+                            type="checkbox"
+                            prop:checked=move || form.with(|o| o.colorblind_safe)
+                            on:change=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                options.update(|o| o.colorblind_safe = checked)
+                            }
+                        />
+                        Only colorblind safe
+                    </label>
+                    {move || {
+                        // Only warn when the colorblind filter is what left nothing to draw from.
+                        options
+                            .with(|o| {
+                                o.colorblind_safe
+                                    && random_pool(&o.random_colormaps, true).is_empty()
+                                    && !random_pool(&o.random_colormaps, false).is_empty()
+                            })
+                            .then(|| {
+                                view! {
+                                    <p class="text-red-500 text-sm">
+                                        "No colorblind safe colormap in these categories"
+                                    </p>
+                                }
+                            })
+                    }}
+                </div>
+
+                <button
+                    on:click=move |_| {
+                        let o = options.get();
+                        push_options_to_url(&o);
+                        random_draw.set(o.colormap.is_none());
+                        generate_image.dispatch(o);
+                    }
+                    class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
                 >
                     Generate
                 </button>
             </div>
+
+            <div class="flex flex-col items-center justify-center text-white rounded-lg p-4 space-y-2 border-gray-800 border">
+                <p class="text-white text-md">Zoom animation</p>
+
+                <p class="text-white text-sm">
+                    {move || format!("{} keyframes", animation.with(|a| a.keyframes.len()))}
+                </p>
+
+                <button
+                    on:click=move |_| {
+                        let viewport = image
+                            .with_untracked(|i| match i {
+                                Some(Ok(rendered)) => rendered.options.viewport(),
+                                _ => None,
+                            });
+                        if let Some(viewport) = viewport {
+                            animation
+                                .update(|a| {
+                                    a.keyframes
+                                        .push(Keyframe {
+                                            viewport,
+                                            palette_offset: 0.0,
+                                        })
+                                });
+                        }
+                    }
+                    class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
+                >
+                    Add current view as keyframe
+                </button>
+
+                <button
+                    on:click=move |_| animation.update(|a| a.keyframes.clear())
+                    class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
+                >
+                    Clear keyframes
+                </button>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Frames per keyframe</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        prop:value=move || animation.with(|a| a.frames_per_keyframe.to_string())
+                        min="1"
+                        on:input=move |ev| {
+                            animation
+                                .update(|a| {
+                                    a
+                                        .frames_per_keyframe = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(FRAMES_PER_KEYFRAME as f64) as u32;
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Palette cycling speed</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        prop:value=move || animation.with(|a| a.palette_speed.to_string())
+                        on:input=move |ev| {
+                            animation
+                                .update(|a| {
+                                    a
+                                        .palette_speed = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(0.0);
+                                })
+                        }
+                    />
+
+                </div>
+
+                <button
+                    on:click=move |_| {
+                        let o = image
+                            .with_untracked(|i| match i {
+                                Some(Ok(rendered)) => Some(rendered.options.clone()),
+                                _ => None,
+                            })
+                            .unwrap_or_else(|| options.get_untracked());
+                        generate_animation.dispatch((o, animation.get_untracked()));
+                    }
+                    disabled=move || animation.with(|a| a.keyframes.is_empty())
+                    class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
+                >
+                    Render animation
+                </button>
+
+                {move || {
+                    if generate_animation.pending().get() {
+                        return view! { <p class="text-white text-sm">Rendering frames...</p> }
+                            .into_any();
+                    }
+                    match generate_animation.value().get() {
+                        Some(Ok(data)) => {
+                            let src = format!("data:image/png;base64,{}", data);
+                            view! {
+                                <img
+                                    class="max-w-xs border-gray-800"
+                                    src=src.clone()
+                                    alt="Mandelbrot zoom animation"
+                                />
+                                <a class="text-white text-sm underline" href=src download="webbrot.png">
+                                    Download animation
+                                </a>
+                            }
+                                .into_any()
+                        }
+                        Some(Err(e)) => {
+                            view! { <p class="text-red-500 text-sm">{e.to_string()}</p> }.into_any()
+                        }
+                        None => ().into_any(),
+                    }
+                }}
+            </div>
+
+            <div class="flex flex-col items-center justify-center text-white rounded-lg p-4 space-y-2 border-gray-800 border">
+                <p class="text-white text-md">Buddhabrot</p>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Orbits</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || buddhabrot.with(|b| format!("{:?}", b.mode))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            buddhabrot
+                                .update(|b| {
+                                    if let Some(choice) = DENSITY_MODES
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        b.mode = *choice;
+                                    }
+                                });
+                        }
+                    >
+                        {DENSITY_MODES
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Color</p>
+                    <select
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        prop:value=move || buddhabrot.with(|b| format!("{:?}", b.color))
+                        on:change=move |ev| {
+                            let selected_value = event_target_value(&ev);
+                            buddhabrot
+                                .update(|b| {
+                                    if let Some(choice) = DENSITY_COLORS
+                                        .iter()
+                                        .find(|c| format!("{:?}", c) == selected_value)
+                                    {
+                                        b.color = *choice;
+                                    }
+                                });
+                        }
+                    >
+                        {DENSITY_COLORS
+                            .iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=format!("{:?}", c) class="text-white text-sm">
+                                        {format!("{:?}", c)}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Samples</p>
+                    <input
+                        class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        prop:value=move || buddhabrot.with(|b| b.samples.to_string())
+                        min="1"
+                        on:input=move |ev| {
+                            buddhabrot
+                                .update(|b| {
+                                    b
+                                        .samples = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(DENSITY_SAMPLES as f64) as u32;
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <p class="text-white text-sm">Channel step limits (red, green, blue):</p>
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Red"
+                        prop:value=move || buddhabrot.with(|b| b.channel_steps[0].to_string())
+                        min="1"
+                        on:input=move |ev| {
+                            buddhabrot
+                                .update(|b| {
+                                    b
+                                        .channel_steps[0] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(NEBULABROT_STEPS[0] as f64) as u32;
+                                })
+                        }
+                    />
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Green"
+                        prop:value=move || buddhabrot.with(|b| b.channel_steps[1].to_string())
+                        min="1"
+                        on:input=move |ev| {
+                            buddhabrot
+                                .update(|b| {
+                                    b
+                                        .channel_steps[1] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(NEBULABROT_STEPS[1] as f64) as u32;
+                                })
+                        }
+                    />
+                    <input
+                        class="w-20 text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                        type="number"
+                        title="Blue"
+                        prop:value=move || buddhabrot.with(|b| b.channel_steps[2].to_string())
+                        min="1"
+                        on:input=move |ev| {
+                            buddhabrot
+                                .update(|b| {
+                                    b
+                                        .channel_steps[2] = event_target_value(&ev)
+                                        .parse::<f64>()
+                                        .unwrap_or(NEBULABROT_STEPS[2] as f64) as u32;
+                                })
+                        }
+                    />
+
+                </div>
+
+                <div class="items-center justify-center">
+                    <label class="text-white text-sm">
+                        <input
+                            class="mr-1"
+                            type="checkbox"
+                            prop:checked=move || buddhabrot.with(|b| b.center.is_some())
+                            on:change=move |ev| {
+                                let view = event_target_checked(&ev)
+                                    .then(|| {
+                                        image
+                                            .with_untracked(|i| match i {
+                                                Some(Ok(rendered)) => {
+                                                    rendered.options.image_center.zip(rendered.options.view_size)
+                                                }
+                                                _ => None,
+                                            })
+                                    })
+                                    .flatten();
+                                buddhabrot
+                                    .update(|b| {
+                                        b.center = view.map(|(center, _)| center);
+                                        b.view_size = view.map(|(_, size)| size);
+                                    })
+                            }
+                        />
+                        "Use the image's view instead of the whole set"
+                    </label>
+                </div>
+
+                <button
+                    on:click=move |_| {
+                        generate_buddhabrot
+                            .dispatch((options.get_untracked(), buddhabrot.get_untracked()));
+                    }
+                    class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
+                >
+                    Render Buddhabrot
+                </button>
+
+                {move || {
+                    if generate_buddhabrot.pending().get() {
+                        return view! { <p class="text-white text-sm">Accumulating orbits...</p> }
+                            .into_any();
+                    }
+                    match generate_buddhabrot.value().get() {
+                        Some(Ok((data, resolved))) => {
+                            let src = format!("data:image/png;base64,{}", data);
+                            view! {
+                                <img class="max-w-xs border-gray-800" src=src.clone() alt="Buddhabrot" />
+                                <p class="text-white text-sm">
+                                    {format!("Seed {}", resolved.rng_seed.unwrap_or_default())}
+                                </p>
+                                <a class="text-white text-sm underline" href=src download="buddhabrot.png">
+                                    Download image
+                                </a>
+                            }
+                                .into_any()
+                        }
+                        Some(Err(e)) => {
+                            view! { <p class="text-red-500 text-sm">{e.to_string()}</p> }.into_any()
+                        }
+                        None => ().into_any(),
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// CSS gradient showing the stops left to right.
+fn css_gradient(stops: &[GradientStop]) -> String {
+    if let [stop] = stops {
+        return format!("background: #{}", hex_color(stop.color));
+    }
+
+    let stops: Vec<String> = stops
+        .iter()
+        .map(|s| format!("#{} {:.2}%", hex_color(s.color), s.position * 100.0))
+        .collect();
+    format!(
+        "background: linear-gradient(to right, {})",
+        stops.join(", ")
+    )
+}
+
+/// Evenly spaced stops sampled from a built-in colormap.
+fn colormap_stops(colormap: Colormap, count: usize) -> Vec<GradientStop> {
+    let table = colormap.to_colormap();
+    let entries = table.len() / 3;
+    (0..count)
+        .map(|i| {
+            let position = i as f64 / (count - 1) as f64;
+            let entry = 3 * (position * (entries - 1) as f64).round() as usize;
+            GradientStop {
+                position,
+                color: [table[entry], table[entry + 1], table[entry + 2]],
+            }
+        })
+        .collect()
+}
+
+/// Edits the custom palette as draggable stops over a live preview of the gradient.
+#[component]
+fn GradientEditor(options: RwSignal<Options>) -> impl IntoView {
+    let editing = Memo::new(move |_| {
+        options.with(|o| {
+            o.custom_palette
+                .as_ref()
+                .map(|p| p.stops().to_vec())
+                .unwrap_or_else(|| {
+                    colormap_stops(o.colormap.unwrap_or(Colormap::Batlow), EDITOR_STOPS)
+                })
+        })
+    });
+    // Stops keep their order while dragged, the palette sorts its own copy.
+    let stops = RwSignal::new(editing.get_untracked());
+    let selected = RwSignal::new(0usize);
+
+    // Palettes loaded from files, cleared or restored from history replace the stops being
+    // edited. The editor's own commits already match them and leave the order alone.
+    Effect::new(move |_| {
+        let external = editing.get();
+        let current = stops.with_untracked(|s| Palette::from_stops(s.clone()).ok());
+        if current.as_ref().map(|p| p.stops()) != Some(&external[..]) {
+            selected.set(0);
+            stops.set(external);
+        }
+    });
+    let dragging = RwSignal::new(false);
+    let bar = NodeRef::<leptos::html::Div>::new();
+
+    let commit = move || {
+        let palette = Palette::from_stops(stops.get_untracked()).ok();
+        options.update(|o| o.custom_palette = palette);
+    };
+
+    let move_handle = window_event_listener(ev::pointermove, move |ev| {
+        if !dragging.get_untracked() {
+            return;
+        }
+        let Some(bar) = bar.get_untracked() else {
+            return;
+        };
+        let rect = bar.get_bounding_client_rect();
+        let position =
+            ((ev.client_x() as f64 - rect.left()) / rect.width().max(1.0)).clamp(0.0, 1.0);
+        stops.update(|s| {
+            if let Some(stop) = s.get_mut(selected.get_untracked()) {
+                stop.position = position;
+            }
+        });
+    });
+    let up_handle = window_event_listener(ev::pointerup, move |_| {
+        if dragging.get_untracked() {
+            dragging.set(false);
+            commit();
+        }
+    });
+    on_cleanup(move || {
+        move_handle.remove();
+        up_handle.remove();
+    });
+
+    view! {
+        <div class="items-center justify-center space-y-1">
+            <p class="text-white text-sm">Gradient editor</p>
+            <div
+                node_ref=bar
+                class="relative w-64 h-6 border border-gray-800 rounded"
+                style=move || {
+                    let mut sorted = stops.get();
+                    sorted.sort_by(|a, b| a.position.total_cmp(&b.position));
+                    css_gradient(&sorted)
+                }
+            >
+                {move || {
+                    stops
+                        .with(|s| {
+                            s.iter()
+                                .enumerate()
+                                .map(|(i, stop)| {
+                                    let style = format!(
+                                        "left: calc({:.2}% - 6px); background: #{}",
+                                        stop.position * 100.0,
+                                        hex_color(stop.color),
+                                    );
+                                    view! {
+                                        <div
+                                            class=move || {
+                                                if selected.get() == i {
+                                                    "absolute -bottom-2 w-3 h-3 rounded-full border-2 border-white cursor-ew-resize"
+                                                } else {
+                                                    "absolute -bottom-2 w-3 h-3 rounded-full border border-gray-500 cursor-ew-resize"
+                                                }
+                                            }
+                                            style=style
+                                            on:pointerdown=move |ev| {
+                                                ev.prevent_default();
+                                                selected.set(i);
+                                                dragging.set(true);
+                                            }
+                                        ></div>
+                                    }
+                                })
+                                .collect_view()
+                        })
+                }}
+            </div>
+
+            <div class="pt-2">
+                <input
+                    class="align-middle"
+                    type="color"
+                    title="Stop color"
+                    prop:value=move || {
+                        stops
+                            .with(|s| {
+                                s.get(selected.get())
+                                    .map(|stop| format!("#{}", hex_color(stop.color)))
+                                    .unwrap_or_default()
+                            })
+                    }
+                    on:input=move |ev| {
+                        if let Some(color) = parse_hex_color(&event_target_value(&ev)) {
+                            stops
+                                .update(|s| {
+                                    if let Some(stop) = s.get_mut(selected.get_untracked()) {
+                                        stop.color = color;
+                                    }
+                                });
+                            commit();
+                        }
+                    }
+                />
+                <span class="text-white text-sm ml-1">
+                    {move || {
+                        stops
+                            .with(|s| {
+                                s.get(selected.get())
+                                    .map(|stop| format!("at {:.1}%", stop.position * 100.0))
+                                    .unwrap_or_default()
+                            })
+                    }}
+                </span>
+            </div>
+
+            <button
+                on:click=move |_| {
+                    let palette = Palette::from_stops(stops.get_untracked()).ok();
+                    let widest = stops
+                        .with_untracked(|s| {
+                            let mut positions: Vec<f64> = s.iter().map(|s| s.position).collect();
+                            positions.sort_by(f64::total_cmp);
+                            positions
+                                .windows(2)
+                                .max_by(|a, b| (a[1] - a[0]).total_cmp(&(b[1] - b[0])))
+                                .map(|w| (w[0] + w[1]) / 2.0)
+                                .unwrap_or(0.5)
+                        });
+                    let color = palette.map(|p| p.color_at(widest)).unwrap_or([255, 255, 255]);
+                    stops
+                        .update(|s| {
+                            s.push(GradientStop {
+                                position: widest,
+                                color,
+                            })
+                        });
+                    selected.set(stops.with_untracked(|s| s.len() - 1));
+                    commit();
+                }
+                class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
+            >
+                Add stop
+            </button>
+
+            <button
+                on:click=move |_| {
+                    stops
+                        .update(|s| {
+                            if s.len() > 2 {
+                                s.remove(selected.get_untracked().min(s.len() - 1));
+                            }
+                        });
+                    selected.set(0);
+                    commit();
+                }
+                disabled=move || stops.with(|s| s.len() <= 2)
+                class="border-gray-800 border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white focus-visible:ring-gray-300 hover:underline inline-flex items-center rounded-md border border-gray-200 px-4 py-2 text-sm font-medium shadow-sm transition-colors hover:bg-gray-100 hover:text-gray-900 focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-gray-950"
+            >
+                Remove stop
+            </button>
+
+            <div class="items-center justify-center">
+                <label class="text-white text-sm" for="gradient-start">
+                    Start from:
+                </label>
+                <select
+                    class="text-gray-500 text-sm border-gray-800 bg-gray-950 hover:bg-gray-900 hover:text-white"
+                    id="gradient-start"
+                    prop:value="none"
+                    on:change=move |ev| {
+                        let selected_value = event_target_value(&ev);
+                        if let Ok(colormap) = selected_value.parse() {
+                            stops.set(colormap_stops(colormap, EDITOR_STOPS));
+                            selected.set(0);
+                            commit();
+                        }
+                    }
+                >
+                    <option value="none" class="text-white text-sm">
+                        Choose a colormap
+                    </option>
+                    {COLORMAP_CHOICES
+                        .iter()
+                        .map(|c| {
+                            view! {
+                                <option value=c.to_string() class="text-white text-sm">
+                                    {c.to_string()}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </div>
+        </div>
+    }
+}
+
+/// Colormap choices drawn as gradient swatches, grouped by category, with a random choice.
+#[component]
+fn ColormapPicker(
+    options: RwSignal<Options>,
+    form: RwSignal<Options>,
+    random_draw: RwSignal<bool>,
+) -> impl IntoView {
+    let tile = |active: bool| {
+        if active {
+            "flex flex-col items-start p-1 rounded border border-white"
+        } else {
+            "flex flex-col items-start p-1 rounded border border-gray-800 hover:border-gray-500"
+        }
+    };
+
+    view! {
+        <div class="items-center justify-center space-y-1">
+            <p class="text-white text-sm">Colormap:</p>
+            <button
+                class=move || tile(options.with(|o| o.colormap.is_none()))
+                on:click=move |_| options.update(|o| o.colormap = None)
+            >
+                <span class="text-white text-xs">Random</span>
+                <span class="text-gray-500 text-xs">
+                    {move || {
+                        if random_draw.get() {
+                            form.with(|o| {
+                                o.colormap.map(|c| format!("Drew {}", c)).unwrap_or_default()
+                            })
+                        } else {
+                            String::new()
+                        }
+                    }}
+                </span>
+            </button>
+            {COLORMAP_CATEGORIES
+                .iter()
+                .filter_map(|&category| {
+                    let colormaps: Vec<Colormap> = COLORMAP_CHOICES
+                        .iter()
+                        .copied()
+                        .filter(|c| c.category() == category)
+                        .collect();
+                    if colormaps.is_empty() {
+                        return None;
+                    }
+                    Some(view! {
+                        <p class="text-gray-500 text-xs">{category.to_string()}</p>
+                        <div class="flex flex-wrap gap-1 w-80">
+                            {colormaps
+                                .into_iter()
+                                .map(|colormap| {
+                                    view! {
+                                        <button
+                                            class=move || {
+                                                tile(options.with(|o| o.colormap == Some(colormap)))
+                                            }
+                                            title=colormap.notes()
+                                            on:click=move |_| {
+                                                options.update(|o| o.colormap = Some(colormap))
+                                            }
+                                        >
+                                            <div
+                                                class="w-16 h-3 rounded-sm"
+                                                style=css_gradient(&colormap_stops(colormap, SWATCH_STOPS))
+                                            ></div>
+                                            <span class="text-white text-xs">
+                                                {colormap.to_string()}
+                                            </span>
+                                        </button>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                    })
+                })
+                .collect_view()}
+            <p class="text-gray-500 text-xs">Color vision preview</p>
+            {move || {
+                let colormap = options.with(|o| o.colormap).or_else(|| form.with(|o| o.colormap));
+                colormap
+                    .map(|colormap| {
+                        let stops = colormap_stops(colormap, SWATCH_STOPS);
+                        let safety = if colormap.colorblind_safe() {
+                            "Colorblind safe"
+                        } else {
+                            "Not designed for color vision deficiencies"
+                        };
+                        view! {
+                            {COLOR_VISIONS
+                                .iter()
+                                .map(|&vision| {
+                                    let simulated: Vec<GradientStop> = stops
+                                        .iter()
+                                        .map(|s| GradientStop {
+                                            color: vision.simulate(s.color),
+                                            ..*s
+                                        })
+                                        .collect();
+                                    view! {
+                                        <div class="flex items-center gap-2">
+                                            <div
+                                                class="w-32 h-3 rounded-sm"
+                                                style=css_gradient(&simulated)
+                                            ></div>
+                                            <span class="text-white text-xs">
+                                                {format!("{:?}", vision)}
+                                            </span>
+                                        </div>
+                                    }
+                                })
+                                .collect_view()}
+                            <p class="text-gray-500 text-xs">{safety}</p>
+                        }
+                    })
+            }}
         </div>
     }
 }
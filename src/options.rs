@@ -1,13 +1,70 @@
-use crate::colormaps;
+pub use crate::colormaps::{Colormap, COLORMAP_CHOICES};
+use crate::{
+    color::{resample, Interpolation, INTERPOLATIONS},
+    coloring::{
+        ExteriorAlpha, ExteriorColoring, InteriorColoring, Lighting, PaletteScale, PaletteWrap,
+        EXTERIOR_ALPHAS, EXTERIOR_COLORINGS, INTERIOR_COLORINGS, LIGHTINGS, PALETTE_SCALES,
+        PALETTE_WRAPS,
+    },
+    orbit_trap::{TrapShape, TRAP_SHAPES},
+    palette::{GradientStop, Palette, PALETTE_ENTRIES},
+    viewport::Viewport,
+};
+use base64::{
+    alphabet::URL_SAFE,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use num::complex::Complex64;
+
+const URL_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
 
 #[derive(Clone, PartialEq)]
 pub struct Options {
     pub dimensions: [u32; 2],
     pub image_center: Option<[f64; 2]>,
-    pub view_size: Option<[f64; 2]>,
+    pub view_size: Option<f64>,
+    pub rotation: f64,
     pub step_limits: [u32; 2],
     pub colormap: Option<Colormap>,
+    /// A user supplied gradient, used instead of the colormap when set.
+    pub custom_palette: Option<Palette>,
+    pub random_colormaps: Vec<ColormapCategory>,
+    /// Only draw colorblind safe colormaps when the colormap is random.
+    pub colorblind_safe: bool,
+    pub palette_offset: f64,
+    pub palette_density: f64,
+    pub palette_wrap: PaletteWrap,
+    pub palette_scale: PaletteScale,
+    pub palette_reverse: bool,
+    pub palette_interpolation: Interpolation,
+    pub exterior_coloring: ExteriorColoring,
+    /// Width in pixels of boundary lines and distance shading.
+    pub line_width: f64,
+    pub exterior_alpha: ExteriorAlpha,
+    pub interior_coloring: InteriorColoring,
+    pub interior_color: [u8; 3],
+    pub lighting: Lighting,
+    /// Direction the light comes from, in degrees counterclockwise from the right.
+    pub light_angle: f64,
+    /// Elevation of the light above the image, in degrees.
+    pub light_height: f64,
+    pub light_strength: f64,
+    pub trap_shape: TrapShape,
+    pub trap_center: [f64; 2],
+    /// Rotation of line and cross traps, in degrees.
+    pub trap_angle: f64,
+    pub trap_radius: f64,
+    /// Stripes per turn around the origin for stripe average coloring.
+    pub stripe_density: f64,
     pub rng_seed: Option<u64>,
+    pub search_region: [f64; 4],
+    pub search_attempts: u32,
     pub bailout_num: f64,
     pub chunk_size: usize,
 }
@@ -18,109 +75,574 @@ impl Default for Options {
             dimensions: [960, 540],
             image_center: None,
             view_size: None,
+            rotation: 0.0,
             step_limits: [super::MIN_STEPS, super::MAX_STEPS],
             colormap: None,
+            custom_palette: None,
+            random_colormaps: Vec::new(),
+            colorblind_safe: false,
+            palette_offset: 0.0,
+            palette_density: 1.0,
+            palette_wrap: PaletteWrap::Clamp,
+            palette_scale: PaletteScale::Linear,
+            palette_reverse: false,
+            palette_interpolation: Interpolation::Nearest,
+            exterior_coloring: ExteriorColoring::Iterations,
+            line_width: 1.0,
+            exterior_alpha: ExteriorAlpha::Opaque,
+            interior_coloring: InteriorColoring::Palette,
+            interior_color: [0, 0, 0],
+            lighting: Lighting::Off,
+            light_angle: 135.0,
+            light_height: 45.0,
+            light_strength: 0.75,
+            trap_shape: TrapShape::Point,
+            trap_center: [0.0, 0.0],
+            trap_angle: 0.0,
+            trap_radius: 0.5,
+            stripe_density: super::STRIPE_DENSITY,
             rng_seed: None,
+            search_region: super::SEARCH_REGION,
+            search_attempts: super::SEARCH_ATTEMPTS,
             bailout_num: super::BAILOUT_NUM,
             chunk_size: 15,
         }
     }
 }
 
+impl Options {
+    /// The view described by these options, if both its center and size are known.
+    pub fn viewport(&self) -> Option<Viewport> {
+        let center = self.image_center?;
+        Some(Viewport::new(
+            Complex64::new(center[0], center[1]),
+            self.view_size? / 2.0,
+            self.rotation.to_radians(),
+            self.dimensions,
+        ))
+    }
+
+    /// The RGB table colors are looked up in: the custom palette if there is one, otherwise the
+    /// colormap once it is chosen, either sampled to [`PALETTE_ENTRIES`] colors. Colormaps are
+    /// resampled with the palette interpolation, so blended lookups have no flat steps.
+    pub fn palette(&self) -> Option<Vec<u8>> {
+        match &self.custom_palette {
+            Some(palette) => Some(palette.to_table(PALETTE_ENTRIES)),
+            None => self
+                .colormap
+                .map(|c| resample(c.to_colormap(), PALETTE_ENTRIES, self.palette_interpolation)),
+        }
+    }
+
+    /// Copies the settings that only affect how iterations are colored, keeping this colormap
+    /// when `from` leaves it random.
+    pub fn copy_coloring(&mut self, from: &Options) {
+        self.colormap = from.colormap.or(self.colormap);
+        self.custom_palette = from.custom_palette.clone();
+        self.palette_offset = from.palette_offset;
+        self.palette_density = from.palette_density;
+        self.palette_wrap = from.palette_wrap;
+        self.palette_scale = from.palette_scale;
+        self.palette_reverse = from.palette_reverse;
+        self.palette_interpolation = from.palette_interpolation;
+        self.exterior_coloring = from.exterior_coloring;
+        self.line_width = from.line_width;
+        self.exterior_alpha = from.exterior_alpha;
+        self.interior_coloring = from.interior_coloring;
+        self.interior_color = from.interior_color;
+        self.lighting = from.lighting;
+        self.light_angle = from.light_angle;
+        self.light_height = from.light_height;
+        self.light_strength = from.light_strength;
+    }
+
+    /// Encodes the options as a compact, URL safe string.
+    ///
+    /// Fields left as `None` are omitted so they are randomized again when decoded.
+    pub fn encode(&self) -> String {
+        let mut fields = vec![
+            format!("d={},{}", self.dimensions[0], self.dimensions[1]),
+            format!("s={},{}", self.step_limits[0], self.step_limits[1]),
+            format!(
+                "g={},{},{},{}",
+                self.search_region[0],
+                self.search_region[1],
+                self.search_region[2],
+                self.search_region[3]
+            ),
+            format!("a={}", self.search_attempts),
+            format!("b={}", self.bailout_num),
+            format!("k={}", self.chunk_size),
+            format!("o={}", self.rotation),
+            format!("po={}", self.palette_offset),
+            format!("pd={}", self.palette_density),
+            format!("pw={:?}", self.palette_wrap),
+            format!("ps={:?}", self.palette_scale),
+            format!("pr={}", self.palette_reverse),
+            format!("pi={:?}", self.palette_interpolation),
+            format!("ec={:?}", self.exterior_coloring),
+            format!("lw={}", self.line_width),
+            format!("ea={:?}", self.exterior_alpha),
+            format!("ic={:?}", self.interior_coloring),
+            format!("il={}", hex_color(self.interior_color)),
+            format!("l={:?}", self.lighting),
+            format!("la={}", self.light_angle),
+            format!("lh={}", self.light_height),
+            format!("ls={}", self.light_strength),
+            format!("ts={:?}", self.trap_shape),
+            format!("tc={},{}", self.trap_center[0], self.trap_center[1]),
+            format!("ta={}", self.trap_angle),
+            format!("tr={}", self.trap_radius),
+            format!("sd={}", self.stripe_density),
+        ];
+
+        if let Some(c) = self.image_center {
+            fields.push(format!("c={},{}", c[0], c[1]));
+        }
+        if let Some(v) = self.view_size {
+            fields.push(format!("v={}", v));
+        }
+        if let Some(m) = self.colormap {
+            fields.push(format!("m={}", m));
+        }
+        if let Some(palette) = &self.custom_palette {
+            let stops: Vec<String> = palette
+                .stops()
+                .iter()
+                .map(|s| format!("{}@{}", hex_color(s.color), s.position))
+                .collect();
+            fields.push(format!("cp={}", stops.join(",")));
+        }
+        if !self.random_colormaps.is_empty() {
+            let categories: Vec<String> = self
+                .random_colormaps
+                .iter()
+                .map(|c| format!("{:?}", c))
+                .collect();
+            fields.push(format!("p={}", categories.join(",")));
+        }
+        if self.colorblind_safe {
+            fields.push("cs=true".to_string());
+        }
+        if let Some(r) = self.rng_seed {
+            fields.push(format!("r={}", r));
+        }
+
+        URL_ENGINE.encode(fields.join("&"))
+    }
+
+    /// Decodes options produced by [`Options::encode`].
+    ///
+    /// Missing fields fall back to their defaults, unknown fields are ignored. Options that could
+    /// not be rendered, like an empty image or a number that is not finite, fail to decode.
+    pub fn decode(encoded: &str) -> Option<Self> {
+        let bytes = URL_ENGINE.decode(encoded).ok()?;
+        let text = String::from_utf8(bytes).ok()?;
+
+        let mut options = Self::default();
+
+        for field in text.split('&').filter(|f| !f.is_empty()) {
+            let (key, value) = field.split_once('=')?;
+
+            match key {
+                "d" => options.dimensions = parse_array(value)?,
+                "s" => options.step_limits = parse_array(value)?,
+                "g" => options.search_region = parse_floats(value)?,
+                "a" => options.search_attempts = value.parse().ok()?,
+                "b" => options.bailout_num = parse_float(value)?,
+                "k" => options.chunk_size = value.parse().ok()?,
+                "c" => options.image_center = Some(parse_floats(value)?),
+                "v" => options.view_size = Some(parse_float(value)?),
+                "o" => options.rotation = parse_float(value)?,
+                "m" => options.colormap = Some(value.parse().ok()?),
+                "cp" => {
+                    let stops = value
+                        .split(',')
+                        .map(|stop| {
+                            let (color, position) = stop.split_once('@')?;
+                            Some(GradientStop {
+                                position: parse_float(position)?,
+                                color: parse_hex_color(color)?,
+                            })
+                        })
+                        .collect::<Option<_>>()?;
+                    options.custom_palette = Some(Palette::from_stops(stops).ok()?);
+                }
+                "p" => {
+                    options.random_colormaps = value
+                        .split(',')
+                        .map(|name| parse_choice(COLORMAP_CATEGORIES, name))
+                        .collect::<Option<_>>()?
+                }
+                "cs" => options.colorblind_safe = value.parse().ok()?,
+                "po" => options.palette_offset = parse_float(value)?,
+                "pd" => options.palette_density = parse_float(value)?,
+                "pw" => options.palette_wrap = parse_choice(PALETTE_WRAPS, value)?,
+                "ps" => options.palette_scale = parse_choice(PALETTE_SCALES, value)?,
+                "pr" => options.palette_reverse = value.parse().ok()?,
+                "pi" => options.palette_interpolation = parse_choice(INTERPOLATIONS, value)?,
+                "ec" => options.exterior_coloring = parse_choice(EXTERIOR_COLORINGS, value)?,
+                "lw" => options.line_width = parse_float(value)?,
+                "ea" => options.exterior_alpha = parse_choice(EXTERIOR_ALPHAS, value)?,
+                "ic" => options.interior_coloring = parse_choice(INTERIOR_COLORINGS, value)?,
+                "il" => options.interior_color = parse_hex_color(value)?,
+                "l" => options.lighting = parse_choice(LIGHTINGS, value)?,
+                "la" => options.light_angle = parse_float(value)?,
+                "lh" => options.light_height = parse_float(value)?,
+                "ls" => options.light_strength = parse_float(value)?,
+                "ts" => options.trap_shape = parse_choice(TRAP_SHAPES, value)?,
+                "tc" => options.trap_center = parse_floats(value)?,
+                "ta" => options.trap_angle = parse_float(value)?,
+                "tr" => options.trap_radius = parse_float(value)?,
+                "sd" => options.stripe_density = parse_float(value)?,
+                "r" => options.rng_seed = Some(value.parse().ok()?),
+                _ => {}
+            }
+        }
+
+        let [width, height] = options.dimensions;
+        let [min_steps, max_steps] = options.step_limits;
+        if width == 0 || height == 0 || min_steps > max_steps || options.chunk_size == 0 {
+            return None;
+        }
+
+        Some(options)
+    }
+}
+
+/// Formats a color as `rrggbb` hex.
+pub fn hex_color(color: [u8; 3]) -> String {
+    format!("{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+/// Parses `rrggbb` hex, with or without a leading `#`.
+pub fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let value = value.strip_prefix('#').unwrap_or(value);
+    if value.len() != 6 || !value.is_ascii() {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&value[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+fn parse_choice<T: Copy + std::fmt::Debug>(choices: &[T], name: &str) -> Option<T> {
+    choices.iter().find(|c| format!("{:?}", c) == name).copied()
+}
+
+fn parse_float(value: &str) -> Option<f64> {
+    value.parse().ok().filter(|v: &f64| v.is_finite())
+}
+
+fn parse_floats<const N: usize>(value: &str) -> Option<[f64; N]> {
+    parse_array(value).filter(|values: &[f64; N]| values.iter().all(|v| v.is_finite()))
+}
+
+fn parse_array<T: std::str::FromStr, const N: usize>(value: &str) -> Option<[T; N]> {
+    let parsed: Vec<T> = value
+        .split(',')
+        .map(|v| v.parse().ok())
+        .collect::<Option<_>>()?;
+    parsed.try_into().ok()
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
-pub enum Colormap {
-    Acton,
-    Bamako,
-    Batlow,
-    Berlin,
-    Bilbao,
-    Broc,
-    Broco,
-    Buda,
-    Cork,
-    Corko,
-    Davos,
-    Devon,
-    Grayc,
-    Hawaii,
-    Imola,
-    Lajolla,
-    Lapaz,
-    Lisbon,
-    Nuuk,
-    Oleron,
-    Oslo,
-    Roma,
-    Romao,
-    Tofino,
-    Tokyo,
-    Turku,
-    Vik,
-    Viko,
-}
-
-pub const COLORMAP_CHOICES: &[Colormap] = &[
-    Colormap::Acton,
-    Colormap::Bamako,
-    Colormap::Batlow,
-    Colormap::Berlin,
-    Colormap::Bilbao,
-    Colormap::Broc,
-    Colormap::Broco,
-    Colormap::Buda,
-    Colormap::Cork,
-    Colormap::Corko,
-    Colormap::Davos,
-    Colormap::Devon,
-    Colormap::Grayc,
-    Colormap::Hawaii,
-    Colormap::Imola,
-    Colormap::Lajolla,
-    Colormap::Lapaz,
-    Colormap::Lisbon,
-    Colormap::Nuuk,
-    Colormap::Oleron,
-    Colormap::Oslo,
-    Colormap::Roma,
-    Colormap::Romao,
-    Colormap::Tofino,
-    Colormap::Tokyo,
-    Colormap::Turku,
-    Colormap::Vik,
-    Colormap::Viko,
+pub enum ColormapCategory {
+    Sequential,
+    MultiSequential,
+    Diverging,
+    Cyclic,
+}
+
+pub const COLORMAP_CATEGORIES: &[ColormapCategory] = &[
+    ColormapCategory::Sequential,
+    ColormapCategory::MultiSequential,
+    ColormapCategory::Diverging,
+    ColormapCategory::Cyclic,
 ];
 
+/// Colormaps a random colormap is drawn from: those in the given categories, or every colormap
+/// if none are given, keeping only colorblind safe ones with `colorblind_safe`.
+pub fn random_pool(categories: &[ColormapCategory], colorblind_safe: bool) -> Vec<Colormap> {
+    COLORMAP_CHOICES
+        .iter()
+        .filter(|c| categories.is_empty() || categories.contains(&c.category()))
+        .filter(|c| !colorblind_safe || c.colorblind_safe())
+        .copied()
+        .collect()
+}
+
+/// Picks a random colormap from the [`random_pool`], or `None` when it is empty.
+pub fn random_colormap(
+    rng: &mut fastrand::Rng,
+    categories: &[ColormapCategory],
+    colorblind_safe: bool,
+) -> Option<Colormap> {
+    let pool = random_pool(categories, colorblind_safe);
+    (!pool.is_empty()).then(|| pool[rng.usize(0..pool.len())])
+}
+
+impl std::fmt::Display for ColormapCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ColormapCategory::Sequential => "Sequential",
+            ColormapCategory::MultiSequential => "Multi-sequential",
+            ColormapCategory::Diverging => "Diverging",
+            ColormapCategory::Cyclic => "Cyclic",
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseColormapError;
+
+impl std::fmt::Display for ParseColormapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown colormap")
+    }
+}
+
+impl std::error::Error for ParseColormapError {}
+
+/// Parses a colormap by name, ignoring case.
+impl std::str::FromStr for Colormap {
+    type Err = ParseColormapError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        COLORMAP_CHOICES
+            .iter()
+            .find(|c| c.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or(ParseColormapError)
+    }
+}
+
+impl std::fmt::Display for Colormap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 impl Colormap {
-    pub fn to_colormap(self) -> &'static [u8] {
-        match self {
-            Colormap::Acton => colormaps::ACTON,
-            Colormap::Bamako => colormaps::BAMAKO,
-            Colormap::Batlow => colormaps::BATLOW,
-            Colormap::Berlin => colormaps::BERLIN,
-            Colormap::Bilbao => colormaps::BILBAO,
-            Colormap::Broc => colormaps::BROC,
-            Colormap::Broco => colormaps::BROCO,
-            Colormap::Buda => colormaps::BUDA,
-            Colormap::Cork => colormaps::CORK,
-            Colormap::Corko => colormaps::CORKO,
-            Colormap::Davos => colormaps::DAVOS,
-            Colormap::Devon => colormaps::DEVON,
-            Colormap::Grayc => colormaps::GRAYC,
-            Colormap::Hawaii => colormaps::HAWAII,
-            Colormap::Imola => colormaps::IMOLA,
-            Colormap::Lajolla => colormaps::LAJOLLA,
-            Colormap::Lapaz => colormaps::LAPAZ,
-            Colormap::Lisbon => colormaps::LISBON,
-            Colormap::Nuuk => colormaps::NUUK,
-            Colormap::Oleron => colormaps::OLERON,
-            Colormap::Oslo => colormaps::OSLO,
-            Colormap::Roma => colormaps::ROMA,
-            Colormap::Romao => colormaps::ROMAO,
-            Colormap::Tofino => colormaps::TOFINO,
-            Colormap::Tokyo => colormaps::TOKYO,
-            Colormap::Turku => colormaps::TURKU,
-            Colormap::Vik => colormaps::VIK,
-            Colormap::Viko => colormaps::VIKO,
+    /// Number of colors in the colormap's table.
+    pub fn entries(self) -> usize {
+        self.to_colormap().len() / 3
+    }
+
+    /// How the colormap's lightness behaves and what it suits.
+    pub fn notes(self) -> &'static str {
+        if let Some(notes) = self.file_notes() {
+            return notes;
+        }
+
+        match self.category() {
+            ColormapCategory::Sequential => {
+                "Perceptually uniform with lightness changing in one direction, readable in \
+                 grayscale and suited to iteration counts and distances."
+            }
+            ColormapCategory::MultiSequential => {
+                "Two perceptually uniform sequential halves with a sharp break between them, \
+                 suited to data with a meaningful threshold."
+            }
+            ColormapCategory::Diverging => {
+                "Perceptually uniform with lightness mirrored around the center, so the middle \
+                 and both ends stand apart."
+            }
+            ColormapCategory::Cyclic => {
+                "Perceptually uniform and starting and ending on the same color, so repeating \
+                 and cycling palettes have no seam."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_field_survives_encoding() {
+        let options = Options {
+            dimensions: [640, 480],
+            image_center: Some([-0.743643887037151, 0.131825904205330]),
+            view_size: Some(1.5e-9),
+            rotation: 33.5,
+            step_limits: [64, 4096],
+            colormap: Some(Colormap::Romao),
+            custom_palette: Some(
+                Palette::from_stops(vec![
+                    GradientStop {
+                        position: 0.0,
+                        color: [0x12, 0x34, 0x56],
+                    },
+                    GradientStop {
+                        position: 0.25,
+                        color: [0xff, 0x80, 0x00],
+                    },
+                    GradientStop {
+                        position: 1.0,
+                        color: [0xfe, 0xdc, 0xba],
+                    },
+                ])
+                .unwrap(),
+            ),
+            random_colormaps: vec![ColormapCategory::Cyclic, ColormapCategory::Diverging],
+            colorblind_safe: true,
+            palette_offset: 12.5,
+            palette_density: 0.3,
+            palette_wrap: PaletteWrap::Mirror,
+            palette_scale: PaletteScale::Histogram,
+            palette_reverse: true,
+            palette_interpolation: Interpolation::Cielab,
+            exterior_coloring: ExteriorColoring::StripeAverage,
+            line_width: 2.5,
+            exterior_alpha: ExteriorAlpha::EscapeSpeed,
+            interior_coloring: InteriorColoring::Period,
+            interior_color: [0x10, 0x20, 0x30],
+            lighting: Lighting::Distance,
+            light_angle: 200.0,
+            light_height: 30.0,
+            light_strength: 0.4,
+            trap_shape: TrapShape::Cross,
+            trap_center: [0.5, -0.25],
+            trap_angle: 15.0,
+            trap_radius: 0.75,
+            stripe_density: 7.0,
+            rng_seed: Some(u64::MAX - 7),
+            search_region: [-1.5, 0.5, -0.75, 0.75],
+            search_attempts: 99,
+            bailout_num: 1e6,
+            chunk_size: 7,
+        };
+
+        let decoded = Options::decode(&options.encode()).unwrap();
+
+        assert!(decoded == options);
+        assert!(Options::decode(&Options::default().encode()).unwrap() == Options::default());
+
+        let unrenderable = [
+            Options {
+                chunk_size: 0,
+                ..options.clone()
+            },
+            Options {
+                dimensions: [0, 480],
+                ..options.clone()
+            },
+            Options {
+                dimensions: [640, 0],
+                ..options.clone()
+            },
+            Options {
+                step_limits: [4096, 64],
+                ..options.clone()
+            },
+            Options {
+                view_size: Some(f64::INFINITY),
+                ..options.clone()
+            },
+            Options {
+                image_center: Some([f64::NAN, 0.0]),
+                ..options.clone()
+            },
+            Options {
+                palette_density: f64::NEG_INFINITY,
+                ..options.clone()
+            },
+            Options {
+                bailout_num: f64::NAN,
+                ..options.clone()
+            },
+        ];
+        for invalid in &unrenderable {
+            assert!(Options::decode(&invalid.encode()).is_none());
+        }
+    }
+
+    #[test]
+    fn interpolated_colormaps_move_between_source_colors() {
+        let options = Options {
+            colormap: Some(Colormap::Batlow),
+            palette_interpolation: Interpolation::Srgb,
+            ..Options::default()
+        };
+        let source = Colormap::Batlow.to_colormap();
+        let table = options.palette().unwrap();
+        let step = (source.len() / 3 - 1) as f64 / (PALETTE_ENTRIES - 1) as f64;
+        let color = |table: &[u8], i: usize| [table[3 * i], table[3 * i + 1], table[3 * i + 2]];
+
+        let mut strictly_between = 0;
+        for i in 0..PALETTE_ENTRIES - 1 {
+            let position = i as f64 * step;
+            let (low, high) = (
+                color(source, position as usize),
+                color(source, position as usize + 1),
+            );
+            let entry = color(&table, i);
+            for c in 0..3 {
+                let (min, max) = (low[c].min(high[c]), low[c].max(high[c]));
+                assert!(entry[c] >= min && entry[c] <= max);
+
+                // Near the middle of a step wide enough not to round onto either end, the blend
+                // has to land strictly inside it.
+                if (0.4..=0.6).contains(&position.fract()) && max - min >= 2 {
+                    assert!(
+                        entry[c] > min && entry[c] < max,
+                        "entry {} channel {}",
+                        i,
+                        c
+                    );
+                    strictly_between += 1;
+                }
+            }
+        }
+        assert!(strictly_between > 0);
+    }
+
+    #[test]
+    fn random_draws_reach_every_colormap() {
+        let mut rng = fastrand::Rng::with_seed(7);
+        let mut drawn = Vec::new();
+        for _ in 0..10_000 {
+            let colormap = random_colormap(&mut rng, &[], false).unwrap();
+            if !drawn.contains(&colormap) {
+                drawn.push(colormap);
+            }
+        }
+
+        assert_eq!(drawn.len(), COLORMAP_CHOICES.len());
+        assert!(drawn.contains(COLORMAP_CHOICES.last().unwrap()));
+    }
+
+    #[test]
+    fn colormap_names_round_trip() {
+        for &colormap in COLORMAP_CHOICES {
+            assert_eq!(colormap.to_string().parse(), Ok(colormap));
+            assert_eq!(colormap.entries(), 256);
+        }
+
+        // Links shared before colormaps had display names used the variant names.
+        assert_eq!("Romao".parse(), Ok(Colormap::Romao));
+        assert_eq!("nope".parse::<Colormap>(), Err(ParseColormapError));
+
+        // Descriptions come from the palette files' headers.
+        assert_eq!(Colormap::Broco.name(), "brocO");
+        assert_eq!(Colormap::Broco.category(), ColormapCategory::Cyclic);
+        assert!(!Colormap::Ultrafractal.perceptually_uniform());
+        assert!(Colormap::Ultrafractal.notes().contains("Ultra Fractal"));
+    }
+
+    #[test]
+    fn colorblind_safe_draws_skip_unsafe_colormaps() {
+        assert!(Colormap::Batlow.colorblind_safe());
+        assert!(!Colormap::Ultrafractal.colorblind_safe());
+        assert!(!random_pool(&[ColormapCategory::Cyclic], true).contains(&Colormap::Ultrafractal));
+
+        let mut rng = fastrand::Rng::with_seed(1);
+        for _ in 0..100 {
+            assert!(random_colormap(&mut rng, &[], true)
+                .unwrap()
+                .colorblind_safe());
         }
     }
 }
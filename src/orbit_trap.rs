@@ -0,0 +1,82 @@
+use num::complex::Complex64;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TrapShape {
+    Point,
+    /// A line through the trap center at the trap angle.
+    Line,
+    /// Two perpendicular lines crossing at the trap center, turned by the trap angle.
+    Cross,
+    /// A circle around the trap center with the trap radius.
+    Circle,
+}
+
+pub const TRAP_SHAPES: &[TrapShape] = &[
+    TrapShape::Point,
+    TrapShape::Line,
+    TrapShape::Cross,
+    TrapShape::Circle,
+];
+
+/// A shape the orbit of each point is measured against.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct OrbitTrap {
+    pub shape: TrapShape,
+    pub center: Complex64,
+    pub radius: f64,
+    /// Turns points by minus the trap's angle, so line and cross traps can be measured unrotated.
+    unrotate: Complex64,
+}
+
+impl Default for OrbitTrap {
+    fn default() -> Self {
+        Self::new(TrapShape::Point, Complex64::new(0.0, 0.0), 0.0, 0.5)
+    }
+}
+
+impl OrbitTrap {
+    /// A trap turned by `angle` radians, which only affects line and cross traps.
+    pub fn new(shape: TrapShape, center: Complex64, angle: f64, radius: f64) -> Self {
+        Self {
+            shape,
+            center,
+            radius,
+            unrotate: Complex64::from_polar(1.0, -angle),
+        }
+    }
+
+    /// Distance from `z` to the trap.
+    pub fn distance(&self, z: Complex64) -> f64 {
+        let local = (z - self.center) * self.unrotate;
+
+        match self.shape {
+            TrapShape::Point => local.norm(),
+            TrapShape::Line => local.im.abs(),
+            TrapShape::Cross => local.re.abs().min(local.im.abs()),
+            TrapShape::Circle => (local.norm() - self.radius).abs(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances_follow_the_trap_geometry() {
+        let trap = |shape| {
+            OrbitTrap::new(
+                shape,
+                Complex64::new(1.0, 0.0),
+                std::f64::consts::FRAC_PI_2,
+                0.5,
+            )
+        };
+        let z = Complex64::new(1.3, 0.4);
+
+        assert!((trap(TrapShape::Point).distance(z) - 0.5).abs() < 1e-12);
+        assert!((trap(TrapShape::Line).distance(z) - 0.3).abs() < 1e-12);
+        assert!((trap(TrapShape::Cross).distance(z) - 0.3).abs() < 1e-12);
+        assert!(trap(TrapShape::Circle).distance(z).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,349 @@
+use crate::color::Interpolation;
+
+/// Entries in the lookup tables palettes and colormaps are sampled to before coloring, so one
+/// iteration advances the same distance through any of them.
+pub const PALETTE_ENTRIES: usize = 512;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct GradientStop {
+    /// Position along the gradient, from 0 to 1.
+    pub position: f64,
+    pub color: [u8; 3],
+}
+
+/// A gradient built at runtime from color stops.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Palette {
+    stops: Vec<GradientStop>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaletteError {
+    NoColors,
+    MissingHeader(&'static str),
+    InvalidLine(usize),
+}
+
+impl std::fmt::Display for PaletteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaletteError::NoColors => write!(f, "The palette has no colors"),
+            PaletteError::MissingHeader(header) => {
+                write!(f, "The file does not start with \"{}\"", header)
+            }
+            PaletteError::InvalidLine(line) => write!(f, "Could not read line {}", line),
+        }
+    }
+}
+
+impl std::error::Error for PaletteError {}
+
+/// File formats palettes can be loaded from.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PaletteFormat {
+    /// GIMP gradient, `.ggr`.
+    Ggr,
+    /// GIMP palette, `.gpl`.
+    Gpl,
+    /// One color per row as `r,g,b`, `position,r,g,b` or `#rrggbb`, `.csv`.
+    Csv,
+    /// Scientific Colour Maps text table of `r g b` floats, `.txt`.
+    Scm,
+}
+
+pub const PALETTE_FORMATS: &[PaletteFormat] = &[
+    PaletteFormat::Ggr,
+    PaletteFormat::Gpl,
+    PaletteFormat::Csv,
+    PaletteFormat::Scm,
+];
+
+impl PaletteFormat {
+    /// The format matching a file name's extension.
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        let (_, extension) = name.rsplit_once('.')?;
+        match extension.to_ascii_lowercase().as_str() {
+            "ggr" => Some(PaletteFormat::Ggr),
+            "gpl" => Some(PaletteFormat::Gpl),
+            "csv" => Some(PaletteFormat::Csv),
+            "txt" => Some(PaletteFormat::Scm),
+            _ => None,
+        }
+    }
+}
+
+impl Palette {
+    /// Builds a palette from stops in any order. Positions are clamped to `0.0..=1.0`.
+    pub fn from_stops(mut stops: Vec<GradientStop>) -> Result<Self, PaletteError> {
+        if stops.is_empty() {
+            return Err(PaletteError::NoColors);
+        }
+
+        for stop in &mut stops {
+            stop.position = stop.position.clamp(0.0, 1.0);
+        }
+        stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+
+        Ok(Self { stops })
+    }
+
+    /// Builds a palette with the colors spread evenly from start to end.
+    pub fn evenly_spaced(colors: &[[u8; 3]]) -> Result<Self, PaletteError> {
+        let last = (colors.len() as f64 - 1.0).max(1.0);
+        Self::from_stops(
+            colors
+                .iter()
+                .enumerate()
+                .map(|(i, &color)| GradientStop {
+                    position: i as f64 / last,
+                    color,
+                })
+                .collect(),
+        )
+    }
+
+    /// Builds a palette from a built-in colormap table.
+    pub fn from_table(table: &[u8]) -> Result<Self, PaletteError> {
+        let colors: Vec<[u8; 3]> = table.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect();
+        Self::evenly_spaced(&colors)
+    }
+
+    pub fn parse(format: PaletteFormat, text: &str) -> Result<Self, PaletteError> {
+        match format {
+            PaletteFormat::Ggr => parse_ggr(text),
+            PaletteFormat::Gpl => parse_gpl(text),
+            PaletteFormat::Csv => parse_csv(text),
+            PaletteFormat::Scm => parse_scm(text),
+        }
+    }
+
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// The color at `position` along the gradient, blended in OKLab between stops.
+    pub fn color_at(&self, position: f64) -> [u8; 3] {
+        let after = self.stops.partition_point(|s| s.position <= position);
+
+        let (a, b) = match after {
+            0 => return self.stops[0].color,
+            n if n == self.stops.len() => return self.stops[n - 1].color,
+            n => (self.stops[n - 1], self.stops[n]),
+        };
+
+        let t = (position - a.position) / (b.position - a.position);
+        Interpolation::Oklab.mix(a.color, b.color, t)
+    }
+
+    /// Samples the gradient into an RGB lookup table with `entries` colors.
+    pub fn to_table(&self, entries: usize) -> Vec<u8> {
+        let last = (entries as f64 - 1.0).max(1.0);
+        (0..entries)
+            .flat_map(|i| self.color_at(i as f64 / last))
+            .collect()
+    }
+}
+
+/// Lines with content, numbered from one, skipping blank lines and `#` comments.
+fn content_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn unit_to_byte(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn parse_gpl(text: &str) -> Result<Palette, PaletteError> {
+    let mut lines = content_lines(text);
+    if lines.next().map(|(_, l)| l) != Some("GIMP Palette") {
+        return Err(PaletteError::MissingHeader("GIMP Palette"));
+    }
+
+    let colors = lines
+        .filter(|(_, line)| !line.starts_with("Name:") && !line.starts_with("Columns:"))
+        .map(|(number, line)| {
+            let channels: Vec<u8> = line
+                .split_whitespace()
+                .take(3)
+                .map(|v| v.parse().ok())
+                .collect::<Option<_>>()
+                .ok_or(PaletteError::InvalidLine(number))?;
+            channels
+                .try_into()
+                .map_err(|_| PaletteError::InvalidLine(number))
+        })
+        .collect::<Result<Vec<[u8; 3]>, _>>()?;
+
+    Palette::evenly_spaced(&colors)
+}
+
+/// Reads GIMP gradient segments as stops at their ends. Segment midpoints and blending
+/// functions are approximated by linear blending.
+fn parse_ggr(text: &str) -> Result<Palette, PaletteError> {
+    let mut lines = content_lines(text);
+    if lines.next().map(|(_, l)| l) != Some("GIMP Gradient") {
+        return Err(PaletteError::MissingHeader("GIMP Gradient"));
+    }
+
+    let mut stops = Vec::new();
+    for (number, line) in lines.filter(|(_, line)| !line.starts_with("Name:")) {
+        let values: Vec<f64> = line
+            .split_whitespace()
+            .map(|v| v.parse().ok())
+            .collect::<Option<_>>()
+            .ok_or(PaletteError::InvalidLine(number))?;
+
+        match values.len() {
+            // The segment count.
+            1 => continue,
+            n if n >= 11 => {
+                let color = |i: usize| {
+                    [
+                        unit_to_byte(values[i]),
+                        unit_to_byte(values[i + 1]),
+                        unit_to_byte(values[i + 2]),
+                    ]
+                };
+                stops.push(GradientStop {
+                    position: values[0],
+                    color: color(3),
+                });
+                stops.push(GradientStop {
+                    position: values[2],
+                    color: color(7),
+                });
+            }
+            _ => return Err(PaletteError::InvalidLine(number)),
+        }
+    }
+
+    Palette::from_stops(stops)
+}
+
+/// Reads rows of `r,g,b`, `position,r,g,b` or a hex color. Channels are bytes unless every
+/// numeric channel in the file is at most 1, in which case they are read as fractions.
+fn parse_csv(text: &str) -> Result<Palette, PaletteError> {
+    let mut rows: Vec<(Option<f64>, [f64; 3], bool)> = Vec::new();
+
+    for (i, (number, line)) in content_lines(text).enumerate() {
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+
+        if let [hex] = fields[..] {
+            let color =
+                crate::options::parse_hex_color(hex).ok_or(PaletteError::InvalidLine(number))?;
+            rows.push((None, color.map(|c| c as f64), false));
+            continue;
+        }
+
+        let values: Option<Vec<f64>> = fields.iter().map(|v| v.parse().ok()).collect();
+        match (values.as_deref(), i) {
+            (Some(&[r, g, b]), _) => rows.push((None, [r, g, b], true)),
+            (Some(&[position, r, g, b]), _) => rows.push((Some(position), [r, g, b], true)),
+            // A header row.
+            (None, 0) => continue,
+            _ => return Err(PaletteError::InvalidLine(number)),
+        }
+    }
+
+    let fractions = rows
+        .iter()
+        .filter(|(_, _, numeric)| *numeric)
+        .all(|(_, color, _)| color.iter().all(|&c| c <= 1.0));
+    let last = (rows.len() as f64 - 1.0).max(1.0);
+
+    Palette::from_stops(
+        rows.iter()
+            .enumerate()
+            .map(|(i, &(position, color, numeric))| GradientStop {
+                position: position.unwrap_or(i as f64 / last),
+                color: color.map(|c| {
+                    if numeric && fractions {
+                        unit_to_byte(c)
+                    } else {
+                        c.clamp(0.0, 255.0).round() as u8
+                    }
+                }),
+            })
+            .collect(),
+    )
+}
+
+fn parse_scm(text: &str) -> Result<Palette, PaletteError> {
+    let colors = content_lines(text)
+        .map(|(number, line)| {
+            let values: Vec<f64> = line
+                .split_whitespace()
+                .take(3)
+                .map(|v| v.parse().ok())
+                .collect::<Option<_>>()
+                .filter(|v: &Vec<f64>| v.len() == 3)
+                .ok_or(PaletteError::InvalidLine(number))?;
+            Ok([
+                unit_to_byte(values[0]),
+                unit_to_byte(values[1]),
+                unit_to_byte(values[2]),
+            ])
+        })
+        .collect::<Result<Vec<[u8; 3]>, _>>()?;
+
+    Palette::evenly_spaced(&colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_interpolate_between_stops() {
+        let palette = Palette::from_stops(vec![
+            GradientStop {
+                position: 1.0,
+                color: [255, 255, 255],
+            },
+            GradientStop {
+                position: 0.0,
+                color: [0, 0, 0],
+            },
+        ])
+        .unwrap();
+
+        let table = palette.to_table(3);
+
+        assert_eq!(table, vec![0, 0, 0, 99, 99, 99, 255, 255, 255]);
+    }
+
+    #[test]
+    fn loads_every_format() {
+        let gpl = "GIMP Palette\nName: Test\nColumns: 2\n#\n255 0 0 Red\n0 0 255 Blue\n";
+        let ggr = "GIMP Gradient\nName: Test\n1\n\
+                   0 0.5 1 1 0 0 1 0 0 1 1 0 0\n";
+        let csv = "r,g,b\n1,0,0\n0,0,1\n";
+        let scm = "1.000000 0.000000 0.000000\n0.000000 0.000000 1.000000\n";
+
+        for (format, text) in [
+            (PaletteFormat::Gpl, gpl),
+            (PaletteFormat::Ggr, ggr),
+            (PaletteFormat::Csv, csv),
+            (PaletteFormat::Scm, scm),
+        ] {
+            let palette = Palette::parse(format, text).unwrap();
+            assert_eq!(palette.color_at(0.0), [255, 0, 0], "{:?}", format);
+            assert_eq!(palette.color_at(1.0), [0, 0, 255], "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn reports_the_line_that_failed() {
+        assert_eq!(
+            Palette::parse(PaletteFormat::Gpl, "GIMP Palette\n255 0 0\n12 oops\n"),
+            Err(PaletteError::InvalidLine(3))
+        );
+        assert_eq!(
+            Palette::parse(PaletteFormat::Ggr, "0 0 0"),
+            Err(PaletteError::MissingHeader("GIMP Gradient"))
+        );
+    }
+}
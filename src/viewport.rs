@@ -0,0 +1,102 @@
+use num::complex::Complex64;
+
+/// A rotated, aspect-correct window onto the complex plane.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Viewport {
+    pub center: Complex64,
+    /// Half the width of the view in the complex plane, the half height follows from the aspect.
+    pub scale: f64,
+    /// Rotation around the center in radians.
+    pub rotation: f64,
+    pub dimensions: [u32; 2],
+}
+
+impl Viewport {
+    pub fn new(center: Complex64, scale: f64, rotation: f64, dimensions: [u32; 2]) -> Self {
+        Self {
+            center,
+            scale,
+            rotation,
+            dimensions,
+        }
+    }
+
+    /// Height over width of the output image.
+    pub fn aspect(&self) -> f64 {
+        self.dimensions[1] as f64 / self.dimensions[0] as f64
+    }
+
+    pub fn half_size(&self) -> (f64, f64) {
+        (self.scale, self.scale * self.aspect())
+    }
+
+    /// Maps normalized view coordinates (`0.0..=1.0` on both axes) to the complex plane.
+    pub fn point_at(&self, u: f64, v: f64) -> Complex64 {
+        let (hx, hy) = self.half_size();
+        let local = Complex64::new((2.0 * u - 1.0) * hx, (2.0 * v - 1.0) * hy);
+        self.center + local * Complex64::from_polar(1.0, self.rotation)
+    }
+
+    pub fn pixel_to_complex(&self, x: f64, y: f64) -> Complex64 {
+        let (w, h) = self.pixel_span();
+        self.point_at(x / w, y / h)
+    }
+
+    pub fn complex_to_pixel(&self, point: Complex64) -> (f64, f64) {
+        let (hx, hy) = self.half_size();
+        let local = (point - self.center) * Complex64::from_polar(1.0, -self.rotation);
+        let (w, h) = self.pixel_span();
+        (
+            (local.re / hx + 1.0) / 2.0 * w,
+            (local.im / hy + 1.0) / 2.0 * h,
+        )
+    }
+
+    pub fn zoom(&self, factor: f64) -> Self {
+        Self {
+            scale: self.scale / factor,
+            ..*self
+        }
+    }
+
+    /// Width of a single pixel in the complex plane.
+    pub fn pixel_size(&self) -> f64 {
+        2.0 * self.scale / self.pixel_span().0
+    }
+
+    fn pixel_span(&self) -> (f64, f64) {
+        (
+            (self.dimensions[0] as f64 - 1.0).max(1.0),
+            (self.dimensions[1] as f64 - 1.0).max(1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixel_conversion_round_trips_with_rotation() {
+        let viewport = Viewport::new(Complex64::new(-0.75, 0.1), 0.5, 0.6, [320, 180]);
+
+        for &(x, y) in &[(0.0, 0.0), (319.0, 179.0), (100.5, 42.25)] {
+            let (px, py) = viewport.complex_to_pixel(viewport.pixel_to_complex(x, y));
+            assert!((px - x).abs() < 1e-9 && (py - y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn view_height_follows_aspect() {
+        let viewport = Viewport::new(Complex64::new(0.0, 0.0), 1.0, 0.0, [200, 100]);
+
+        assert_eq!(
+            viewport.pixel_to_complex(0.0, 0.0),
+            Complex64::new(-1.0, -0.5)
+        );
+        assert_eq!(
+            viewport.pixel_to_complex(199.0, 99.0),
+            Complex64::new(1.0, 0.5)
+        );
+    }
+}